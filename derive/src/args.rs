@@ -6,7 +6,8 @@ use darling::{
     FromDeriveInput, FromField, FromMeta, FromVariant,
 };
 use inflector::Inflector;
-use quote::format_ident;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use syn::{
     Attribute, Expr, GenericParam, Generics, Ident, Lit, LitBool, LitStr, Meta, Path, Type,
     Visibility,
@@ -124,6 +125,34 @@ pub struct ConcreteType {
     pub bounds: GenericParamList,
 }
 
+/// The scopes of the `@requiresScopes` directive, each string is a set of
+/// space-separated scopes that must all be granted.
+#[derive(Default, Clone)]
+pub struct RequiresScopes(pub Vec<Vec<String>>);
+
+impl FromMeta for RequiresScopes {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut res = Vec::new();
+        for item in items {
+            match item {
+                NestedMeta::Lit(Lit::Str(s)) => {
+                    let scopes = s
+                        .value()
+                        .split_whitespace()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>();
+                    if scopes.is_empty() {
+                        return Err(darling::Error::custom("Empty scope list").with_span(s));
+                    }
+                    res.push(scopes);
+                }
+                _ => return Err(darling::Error::custom("Invalid scope list")),
+            }
+        }
+        Ok(RequiresScopes(res))
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum Deprecation {
     #[default]
@@ -214,6 +243,10 @@ pub struct SimpleObjectField {
     #[darling(default)]
     pub override_from: Option<String>,
     #[darling(default)]
+    pub authenticated: bool,
+    #[darling(default)]
+    pub requires_scopes: Option<RequiresScopes>,
+    #[darling(default)]
     pub guard: Option<Expr>,
     #[darling(default)]
     pub visible: Option<Visible>,
@@ -231,10 +264,16 @@ pub struct SimpleObjectField {
     #[darling(default)]
     pub flatten: bool,
     #[darling(default)]
+    pub serial: bool,
+    #[darling(default)]
     pub secret: bool,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     pub complexity: Option<Expr>,
+    #[darling(default)]
+    pub cost: Option<usize>,
+    #[darling(default)]
+    pub list_size: Option<ListSize>,
 }
 
 #[derive(FromDeriveInput)]
@@ -277,6 +316,8 @@ pub struct SimpleObject {
     pub concretes: Vec<ConcreteType>,
     #[darling(default)]
     pub serial: bool,
+    #[darling(default)]
+    pub external_resolver: bool,
     #[darling(default, rename = "unresolvable")]
     pub resolvability: Resolvability,
     // for InputObject
@@ -307,6 +348,7 @@ pub struct Argument {
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     pub deprecation: Deprecation,
+    pub prepared: bool, // for complex object
 }
 
 #[derive(FromMeta, Default)]
@@ -353,16 +395,33 @@ pub struct ObjectField {
     #[darling(multiple, rename = "tag")]
     pub tags: Vec<String>,
     pub override_from: Option<String>,
+    pub authenticated: bool,
+    pub requires_scopes: Option<RequiresScopes>,
     pub guard: Option<Expr>,
     pub visible: Option<Visible>,
     pub complexity: Option<Expr>,
+    pub cost: Option<usize>,
+    pub list_size: Option<ListSize>,
     #[darling(default, multiple)]
     pub derived: Vec<DerivedField>,
     pub flatten: bool,
+    pub serial: bool,
+    pub cache_in_request: bool,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
 }
 
+#[derive(FromMeta, Default, Clone)]
+#[darling(default)]
+pub struct ListSize {
+    pub assumed_size: Option<usize>,
+    #[darling(multiple, rename = "slicing_argument")]
+    pub slicing_arguments: Vec<String>,
+    #[darling(multiple, rename = "sized_field")]
+    pub sized_fields: Vec<String>,
+    pub require_one_slicing_argument: Option<bool>,
+}
+
 #[derive(FromMeta, Default, Clone)]
 #[darling(default)]
 /// Derivied fields arguments: are used to generate derivied fields.
@@ -535,6 +594,8 @@ pub struct InputObject {
     pub concretes: Vec<ConcreteType>,
     #[darling(default)]
     pub validator: Option<Expr>,
+    #[darling(default)]
+    pub default: bool,
     // for SimpleObject
     #[darling(default)]
     pub complex: bool,
@@ -654,6 +715,10 @@ pub struct InterfaceField {
     pub shareable: bool,
     #[darling(default)]
     pub override_from: Option<String>,
+    #[darling(default)]
+    pub authenticated: bool,
+    #[darling(default)]
+    pub requires_scopes: Option<RequiresScopes>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
 }
@@ -756,6 +821,8 @@ pub struct SubscriptionField {
     pub guard: Option<Expr>,
     pub visible: Option<Visible>,
     pub complexity: Option<Expr>,
+    pub cost: Option<usize>,
+    pub list_size: Option<ListSize>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
 }
@@ -838,6 +905,8 @@ pub enum RenameRule {
     Snake,
     #[darling(rename = "SCREAMING_SNAKE_CASE")]
     ScreamingSnake,
+    #[darling(rename = "verbatim")]
+    Verbatim,
 }
 
 impl RenameRule {
@@ -849,6 +918,7 @@ impl RenameRule {
             Self::Camel => name.as_ref().to_camel_case(),
             Self::Snake => name.as_ref().to_snake_case(),
             Self::ScreamingSnake => name.as_ref().to_screaming_snake_case(),
+            Self::Verbatim => name.as_ref().to_string(),
         }
     }
 }
@@ -878,12 +948,46 @@ impl RenameTarget {
 
 pub trait RenameRuleExt {
     fn rename(&self, name: impl AsRef<str>, target: RenameTarget) -> String;
+
+    /// Generates the name of a field or an argument: an explicit name or the
+    /// name given by the rule is kept as it is, otherwise the name follows the
+    /// naming convention of the schema, read from the `__naming_convention`
+    /// variable of the generated code.
+    fn gen_name(
+        &self,
+        crate_name: &TokenStream,
+        explicit: Option<&str>,
+        name: impl AsRef<str>,
+        target: RenameTarget,
+    ) -> TokenStream;
 }
 
 impl RenameRuleExt for Option<RenameRule> {
     fn rename(&self, name: impl AsRef<str>, target: RenameTarget) -> String {
         self.unwrap_or(target.rule()).rename(name)
     }
+
+    fn gen_name(
+        &self,
+        crate_name: &TokenStream,
+        explicit: Option<&str>,
+        name: impl AsRef<str>,
+        target: RenameTarget,
+    ) -> TokenStream {
+        if let Some(name) = explicit {
+            return quote!(#name);
+        }
+        if let Some(rule) = self {
+            let name = rule.rename(name);
+            return quote!(#name);
+        }
+        let camel_case = target.rename(&name);
+        let snake_case = RenameRule::Snake.rename(&name);
+        let verbatim = name.as_ref();
+        quote! {
+            #crate_name::registry::NamingConvention::name(__naming_convention, #camel_case, #snake_case, #verbatim)
+        }
+    }
 }
 
 #[derive(FromDeriveInput)]
@@ -950,6 +1054,62 @@ pub struct NewType {
     pub specified_by_url: Option<String>,
 }
 
+#[derive(FromDeriveInput)]
+#[darling(attributes(graphql), forward_attrs(doc))]
+pub struct ScalarFromTryFrom {
+    pub ident: Ident,
+    pub generics: Generics,
+    pub attrs: Vec<Attribute>,
+
+    pub try_from: Type,
+    #[darling(default)]
+    pub internal: bool,
+    #[darling(default)]
+    pub name: Option<String>,
+    #[darling(default)]
+    pub visible: Option<Visible>,
+    #[darling(default)]
+    pub inaccessible: bool,
+    #[darling(default, multiple, rename = "tag")]
+    pub tags: Vec<String>,
+    #[darling(default)]
+    pub specified_by_url: Option<String>,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(graphql), forward_attrs(doc))]
+pub struct StringEnum {
+    pub ident: Ident,
+    pub generics: Generics,
+    pub attrs: Vec<Attribute>,
+    pub data: Data<StringEnumItem, Ignored>,
+
+    #[darling(default)]
+    pub internal: bool,
+    #[darling(default)]
+    pub name: Option<String>,
+    #[darling(default)]
+    pub rename_items: Option<RenameRule>,
+    #[darling(default)]
+    pub visible: Option<Visible>,
+    #[darling(default)]
+    pub inaccessible: bool,
+    #[darling(default, multiple, rename = "tag")]
+    pub tags: Vec<String>,
+    #[darling(default)]
+    pub specified_by_url: Option<String>,
+}
+
+#[derive(FromVariant)]
+#[darling(attributes(graphql))]
+pub struct StringEnumItem {
+    pub ident: Ident,
+    pub fields: Fields<syn::Type>,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
 #[derive(FromMeta, Default)]
 #[darling(default)]
 pub struct ComplexObject {
@@ -974,12 +1134,18 @@ pub struct ComplexObjectField {
     #[darling(multiple, rename = "tag")]
     pub tags: Vec<String>,
     pub override_from: Option<String>,
+    pub authenticated: bool,
+    pub requires_scopes: Option<RequiresScopes>,
     pub guard: Option<Expr>,
     pub visible: Option<Visible>,
     pub complexity: Option<Expr>,
+    pub cost: Option<usize>,
+    pub list_size: Option<ListSize>,
     #[darling(multiple)]
     pub derived: Vec<DerivedField>,
     pub flatten: bool,
+    pub serial: bool,
+    pub prepare: bool,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
 }
@@ -1014,6 +1180,7 @@ pub struct TypeDirective {
     pub name_type: bool,
     pub visible: Option<Visible>,
     pub repeatable: bool,
+    pub introspectable: bool,
     pub rename_args: Option<RenameRule>,
     #[darling(multiple, rename = "location")]
     pub locations: Vec<TypeDirectiveLocation>,
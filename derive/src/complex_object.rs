@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
     ext::IdentExt, punctuated::Punctuated, Block, Error, FnArg, ImplItem, ItemImpl, Pat,
     ReturnType, Token, Type, TypeReference,
@@ -12,10 +12,10 @@ use crate::{
     args::{self, RenameRuleExt, RenameTarget, TypeDirectiveLocation},
     output_type::OutputType,
     utils::{
-        extract_input_args, gen_boxed_trait, gen_deprecation, gen_directive_calls,
-        generate_default, generate_guards, get_cfg_attrs, get_crate_name, get_rustdoc,
-        get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
-        visible_fn, GeneratorResult,
+        extract_input_args, gen_boxed_trait, gen_cost, gen_deprecation, gen_directive_calls,
+        gen_list_size, gen_requires_scopes, generate_default, generate_guards, get_cfg_attrs,
+        get_crate_name, get_rustdoc, get_type_path_and_name, parse_complexity_expr,
+        parse_graphql_attrs, remove_graphql_attrs, visible_fn, GeneratorResult,
     },
 };
 
@@ -31,6 +31,7 @@ pub fn generate(
 
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut flattened_types = Vec::new();
 
     // Computation of the derivated fields
     let mut derived_impls = vec![];
@@ -117,6 +118,25 @@ pub fn generate(
     }
     item_impl.items.append(&mut derived_impls);
 
+    let mut prepare_method = None;
+    for item in &item_impl.items {
+        if let ImplItem::Fn(method) = item {
+            let method_args: args::ComplexObjectField =
+                parse_graphql_attrs(&method.attrs)?.unwrap_or_default();
+            if method_args.prepare {
+                if prepare_method.is_some() {
+                    return Err(Error::new_spanned(
+                        &method.sig.ident,
+                        "Only one method can be marked with `prepare`.",
+                    )
+                    .into());
+                }
+                prepare_method = Some(method.sig.ident.clone());
+            }
+        }
+    }
+    let mut prepared_fields = Vec::new();
+
     for item in &mut item_impl.items {
         if let ImplItem::Fn(method) = item {
             let method_args: args::ComplexObjectField =
@@ -127,6 +147,20 @@ pub fn generate(
             }
             let cfg_attrs = get_cfg_attrs(&method.attrs);
 
+            if method_args.prepare {
+                // Only used to inject the context placeholder if required.
+                let args = extract_input_args::<args::Argument>(&crate_name, method)?;
+                if let Some((ident, _, _)) = args.first() {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "The prepare method only takes the context.",
+                    )
+                    .into());
+                }
+                remove_graphql_attrs(&mut method.attrs);
+                continue;
+            }
+
             if method_args.flatten {
                 // Only used to inject the context placeholder if required.
                 extract_input_args::<args::Argument>(&crate_name, method)?;
@@ -159,16 +193,18 @@ pub fn generate(
                         return ::std::result::Result::Ok(std::option::Option::Some(value));
                     }
                 });
+                flattened_types.push(ty.clone());
 
                 remove_graphql_attrs(&mut method.attrs);
                 continue;
             }
 
-            let field_name = method_args.name.clone().unwrap_or_else(|| {
-                object_args
-                    .rename_fields
-                    .rename(method.sig.ident.unraw().to_string(), RenameTarget::Field)
-            });
+            let field_name = object_args.rename_fields.gen_name(
+                &crate_name,
+                method_args.name.as_deref(),
+                method.sig.ident.unraw().to_string(),
+                RenameTarget::Field,
+            );
             let field_desc = get_rustdoc(&method.attrs)?
                 .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
                 .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -222,6 +258,7 @@ pub fn generate(
             let mut schema_args = Vec::new();
             let mut use_params = Vec::new();
             let mut get_params = Vec::new();
+            let mut is_prepared = false;
 
             for (
                 ident,
@@ -239,15 +276,56 @@ pub fn generate(
                     secret,
                     directives,
                     deprecation,
+                    prepared,
                     ..
                 },
             ) in &args
             {
-                let name = name.clone().unwrap_or_else(|| {
-                    object_args
-                        .rename_args
-                        .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                });
+                if *prepared {
+                    let Some(prepare_ident) = &prepare_method else {
+                        return Err(Error::new_spanned(
+                            ident,
+                            "A prepared argument requires a method marked with `prepare`.",
+                        )
+                        .into());
+                    };
+                    let Type::Reference(TypeReference { elem, .. }) = ty else {
+                        return Err(Error::new_spanned(
+                            ty,
+                            "A prepared argument must be a reference.",
+                        )
+                        .into());
+                    };
+                    is_prepared = true;
+
+                    let param_ident = &ident.ident;
+                    let value_ident = format_ident!("__prepared_{}", param_ident);
+                    use_params.push(quote! { #param_ident });
+                    // Resolved through an interface or a union, the object
+                    // isn't prepared, so the field prepares it by itself.
+                    get_params.push(quote! {
+                        #[allow(non_snake_case)]
+                        let #value_ident;
+                        #[allow(non_snake_case)]
+                        let #param_ident: #ty = match ctx.prepared_value::<#elem>() {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                                #value_ident = self.#prepare_ident(ctx).await.map_err(|err| {
+                                    ::std::convert::Into::<#crate_name::Error>::into(err).into_server_error(ctx.item.pos)
+                                })?;
+                                &#value_ident
+                            }
+                        };
+                    });
+                    continue;
+                }
+
+                let name = object_args.rename_args.gen_name(
+                    &crate_name,
+                    name.as_deref(),
+                    ident.ident.unraw().to_string(),
+                    RenameTarget::Argument,
+                );
                 let desc = desc
                     .as_ref()
                     .map(|s| quote! {::std::option::Option::Some(::std::string::ToString::to_string(#s))})
@@ -322,6 +400,10 @@ pub fn generate(
                 });
             }
 
+            if is_prepared {
+                prepared_fields.push(field_name.clone());
+            }
+
             let ty = match &method.sig.output {
                 ReturnType::Type(_, ty) => OutputType::parse(ty)?,
                 ReturnType::Default => {
@@ -335,6 +417,10 @@ pub fn generate(
             let schema_ty = ty.value_type();
             let visible = visible_fn(&method_args.visible);
 
+            let cost = gen_cost(&method_args.cost);
+            let authenticated = method_args.authenticated;
+            let requires_scopes = gen_requires_scopes(&method_args.requires_scopes);
+            let list_size = gen_list_size(&crate_name, &method_args.list_size);
             let complexity = if let Some(complexity) = &method_args.complexity {
                 let (variables, expr) = parse_complexity_expr(complexity.clone())?;
                 let mut parse_args = Vec::new();
@@ -358,11 +444,12 @@ pub fn generate(
                             }
                             None => quote! { ::std::option::Option::None },
                         };
-                        let name = name.clone().unwrap_or_else(|| {
-                            object_args
-                                .rename_args
-                                .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                        });
+                        let name = object_args.rename_args.gen_name(
+                            &crate_name,
+                            name.as_deref(),
+                            ident.ident.unraw().to_string(),
+                            RenameTarget::Argument,
+                        );
                         parse_args.push(quote! {
                             let #ident: #ty = __ctx.param_value(__variables_definition, __field, #name, #default)?;
                         });
@@ -370,8 +457,9 @@ pub fn generate(
                 }
                 quote! {
                     Some(|__ctx, __variables_definition, __field, child_complexity| {
+                        let __naming_convention = __ctx.naming_convention();
                         #(#parse_args)*
-                        Ok(#expr)
+                        #crate_name::registry::complexity::eval(|| #expr)
                     })
                 }
             } else {
@@ -380,7 +468,7 @@ pub fn generate(
 
             schema_fields.push(quote! {
                 #(#cfg_attrs)*
-                fields.push((#field_name.to_string(), #crate_name::registry::MetaField {
+                fields.push((::std::string::ToString::to_string(#field_name), #crate_name::registry::MetaField {
                     name: ::std::borrow::ToOwned::to_owned(#field_name),
                     description: #field_desc,
                     args: {
@@ -398,8 +486,12 @@ pub fn generate(
                     inaccessible: #inaccessible,
                     tags: ::std::vec![ #(#tags),* ],
                     override_from: #override_from,
+                    authenticated: #authenticated,
+                    requires_scopes: #requires_scopes,
                     visible: #visible,
                     compute_complexity: #complexity,
+                    cost: #cost,
+                    list_size: #list_size,
                     directive_invocations: ::std::vec![ #(#directives),* ],
                 }));
             });
@@ -431,6 +523,9 @@ pub fn generate(
             let guard_map_err = quote! {
                 .map_err(|err| err.into_server_error(ctx.item.pos))
             };
+            let serial = method_args
+                .serial
+                .then(|| quote!(let ctx_obj = ctx_obj.with_serial();));
             let guard = match method_args.guard.as_ref().or(object_args.guard.as_ref()) {
                 Some(code) => Some(generate_guards(&crate_name, code, guard_map_err)?),
                 None => None,
@@ -442,10 +537,16 @@ pub fn generate(
                     let f = async move {
                         #(#get_params)*
                         #guard
-                        #resolve_obj
+                        if ctx.guard_mode() == #crate_name::GuardMode::CheckOnly {
+                            return ::std::result::Result::Ok(::std::option::Option::None);
+                        }
+                        ::std::result::Result::Ok(::std::option::Option::Some(#resolve_obj?))
+                    };
+                    let ::std::option::Option::Some(obj) = f.await.map_err(|err| ctx.set_error_path(err))? else {
+                        return ::std::result::Result::Ok(::std::option::Option::None);
                     };
-                    let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
                     let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                    #serial
                     return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
                 }
             });
@@ -454,6 +555,30 @@ pub fn generate(
         }
     }
 
+    let prepare = match &prepare_method {
+        Some(prepare_ident) if !prepared_fields.is_empty() => quote! {
+            async fn prepare(
+                &self,
+                ctx: &#crate_name::ContextSelectionSet<'_>,
+                field: &#crate_name::Positioned<#crate_name::parser::types::Field>,
+            ) -> #crate_name::ServerResult<::std::option::Option<::std::boxed::Box<dyn ::std::any::Any + ::std::marker::Send + ::std::marker::Sync>>> {
+                let __naming_convention = ctx.schema_env.registry.naming_convention;
+                let ctx = ctx.with_item(field);
+                let look_ahead = ctx.look_ahead();
+                if #(look_ahead.field(#prepared_fields).exists())||* {
+                    let value = self.#prepare_ident(&ctx).await.map_err(|err| {
+                        ctx.set_error_path(::std::convert::Into::<#crate_name::Error>::into(err).into_server_error(field.pos))
+                    })?;
+                    let value: ::std::boxed::Box<dyn ::std::any::Any + ::std::marker::Send + ::std::marker::Sync> = ::std::boxed::Box::new(value);
+                    ::std::result::Result::Ok(::std::option::Option::Some(value))
+                } else {
+                    ::std::result::Result::Ok(::std::option::Option::None)
+                }
+            }
+        },
+        _ => quote!(),
+    };
+
     let expanded = quote! {
         #item_impl
 
@@ -461,15 +586,23 @@ pub fn generate(
         #boxed_trait
         impl #generics #crate_name::ComplexObject for #self_ty #where_clause {
             fn fields(registry: &mut #crate_name::registry::Registry) -> ::std::vec::Vec<(::std::string::String, #crate_name::registry::MetaField)> {
+                let __naming_convention = registry.naming_convention;
                 let mut fields = ::std::vec::Vec::new();
                 #(#schema_fields)*
                 fields
             }
 
+            fn can_check_guards() -> bool {
+                true #(&& <#flattened_types as #crate_name::resolver_utils::ContainerType>::can_check_guards())*
+            }
+
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                let __naming_convention = ctx.schema_env.registry.naming_convention;
                 #(#resolvers)*
                 ::std::result::Result::Ok(::std::option::Option::None)
             }
+
+            #prepare
         }
     };
 
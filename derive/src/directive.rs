@@ -151,20 +151,19 @@ pub fn generate(
             }
 
             fn register(&self, registry: &mut #crate_name::registry::Registry) {
-                let meta = #crate_name::registry::MetaDirective {
-                    name: ::std::borrow::Cow::into_owned(#directive_name),
-                    description: #desc,
-                    locations: vec![#(#locations),*],
-                    args: {
-                        #[allow(unused_mut)]
-                        let mut args = #crate_name::indexmap::IndexMap::new();
-                        #(#schema_args)*
-                        args
-                    },
-                    is_repeatable: #repeatable,
-                    visible: #visible,
-                    composable: None,
+                let mut meta = #crate_name::registry::MetaDirective::new(
+                    ::std::borrow::Cow::into_owned(#directive_name),
+                    vec![#(#locations),*],
+                )
+                .repeatable(#repeatable);
+                meta.description = #desc;
+                meta.args = {
+                    #[allow(unused_mut)]
+                    let mut args = #crate_name::indexmap::IndexMap::new();
+                    #(#schema_args)*
+                    args
                 };
+                meta.visible = #visible;
                 registry.add_directive(meta);
             }
 
@@ -140,7 +140,13 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         let desc = get_rustdoc(&field.attrs)?
             .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
             .unwrap_or_else(|| quote! {::std::option::Option::None});
-        let default = generate_default(&field.default, &field.default_with)?;
+        let default = match generate_default(&field.default, &field.default_with)? {
+            Some(default) => Some(default),
+            None if object_args.default => {
+                Some(quote! { <Self as ::std::default::Default>::default().#ident })
+            }
+            None => None,
+        };
         let schema_default = default
             .as_ref()
             .map(|value| {
@@ -236,6 +242,10 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         }
     };
 
+    let default_bound = object_args
+        .default
+        .then(|| quote! { + ::std::default::Default });
+
     let obj_validator = object_args
         .validator
         .as_ref()
@@ -303,7 +313,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         code.push(quote! {
             #[allow(clippy::all, clippy::pedantic)]
             impl #impl_generics #ident #ty_generics #where_clause {
-                fn __internal_create_type_info_input_object(registry: &mut #crate_name::registry::Registry, name: &str) -> ::std::string::String where Self: #crate_name::InputType {
+                fn __internal_create_type_info_input_object(registry: &mut #crate_name::registry::Registry, name: &str) -> ::std::string::String where Self: #crate_name::InputType #default_bound {
                     registry.create_input_type::<Self, _>(#crate_name::registry::MetaTypeId::InputObject, |registry| #crate_name::registry::MetaType::InputObject {
                         name: ::std::borrow::ToOwned::to_owned(name),
                         description: #desc,
@@ -321,7 +331,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     })
                 }
 
-                fn __internal_parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> where Self: #crate_name::InputType {
+                fn __internal_parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> where Self: #crate_name::InputType #default_bound {
                     if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
                         #(#get_fields)*
                         let obj = Self { #(#fields),* };
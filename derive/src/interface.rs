@@ -13,8 +13,8 @@ use crate::{
     },
     output_type::OutputType,
     utils::{
-        gen_boxed_trait, gen_deprecation, gen_directive_calls, generate_default, get_crate_name,
-        get_rustdoc, visible_fn, GeneratorResult, RemoveLifetime,
+        gen_boxed_trait, gen_deprecation, gen_directive_calls, gen_requires_scopes,
+        generate_default, get_crate_name, get_rustdoc, visible_fn, GeneratorResult, RemoveLifetime,
     },
 };
 
@@ -159,17 +159,23 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         inaccessible,
         tags,
         override_from,
+        authenticated,
+        requires_scopes,
         directives,
     } in &interface_args.fields
     {
         let (name, method_name) = if let Some(method) = method {
-            (name.to_string(), Ident::new_raw(method, Span::call_site()))
+            let name = name.as_str();
+            (quote!(#name), Ident::new_raw(method, Span::call_site()))
         } else {
             let method_name = Ident::new_raw(name, Span::call_site());
             (
-                interface_args
-                    .rename_fields
-                    .rename(name.as_ref(), RenameTarget::Field),
+                interface_args.rename_fields.gen_name(
+                    &crate_name,
+                    None,
+                    name.as_str(),
+                    RenameTarget::Field,
+                ),
                 method_name,
             )
         };
@@ -196,6 +202,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             }
             None => quote! { ::std::option::Option::None },
         };
+        let requires_scopes = gen_requires_scopes(requires_scopes);
 
         decl_params.push(quote! { ctx: &'ctx #crate_name::Context<'ctx> });
         use_params.push(quote! { ctx });
@@ -218,9 +225,12 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         ) in args.iter().enumerate()
         {
             let ident = Ident::new(&format!("arg{}", i), Span::call_site());
-            let name = interface_args
-                .rename_args
-                .rename(name, RenameTarget::Argument);
+            let name = interface_args.rename_args.gen_name(
+                &crate_name,
+                None,
+                name,
+                RenameTarget::Argument,
+            );
             decl_params.push(quote! { #ident: #ty });
             use_params.push(quote! { #ident });
 
@@ -328,8 +338,12 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 inaccessible: #inaccessible,
                 tags: ::std::vec![ #(#tags),* ],
                 override_from: #override_from,
+                authenticated: #authenticated,
+                requires_scopes: #requires_scopes,
                 visible: #visible,
                 compute_complexity: ::std::option::Option::None,
+                cost: ::std::option::Option::None,
+                list_size: ::std::option::Option::None,
                 directive_invocations: ::std::vec![ #(#directives),* ],
             });
         });
@@ -372,6 +386,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         #boxed_trait
         impl #impl_generics #crate_name::resolver_utils::ContainerType for #ident #ty_generics #where_clause {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                let __naming_convention = ctx.schema_env.registry.naming_convention;
                 #(#resolvers)*
                 ::std::result::Result::Ok(::std::option::Option::None)
             }
@@ -395,6 +410,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             }
 
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
+                let __naming_convention = registry.naming_convention;
                 registry.create_output_type::<Self, _>(#crate_name::registry::MetaTypeId::Interface, |registry| {
                     #(#registry_types)*
 
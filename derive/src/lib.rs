@@ -19,7 +19,9 @@ mod object;
 mod oneof_object;
 mod output_type;
 mod scalar;
+mod scalar_from_try_from;
 mod simple_object;
+mod string_enum;
 mod subscription;
 mod type_directive;
 mod union;
@@ -156,6 +158,33 @@ pub fn Scalar(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(ScalarFromTryFrom, attributes(graphql))]
+pub fn derive_scalar_from_try_from(input: TokenStream) -> TokenStream {
+    let scalar_args =
+        match args::ScalarFromTryFrom::from_derive_input(&parse_macro_input!(input as DeriveInput))
+        {
+            Ok(scalar_args) => scalar_args,
+            Err(err) => return TokenStream::from(err.write_errors()),
+        };
+    match scalar_from_try_from::generate(&scalar_args) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(StringEnum, attributes(graphql))]
+pub fn derive_string_enum(input: TokenStream) -> TokenStream {
+    let enum_args =
+        match args::StringEnum::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+            Ok(enum_args) => enum_args,
+            Err(err) => return TokenStream::from(err.write_errors()),
+        };
+    match string_enum::generate(&enum_args) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(MergedObject, attributes(graphql))]
 pub fn derive_merged_object(input: TokenStream) -> TokenStream {
     let object_args =
@@ -12,10 +12,10 @@ use crate::{
     args::{self, RenameRuleExt, RenameTarget, Resolvability, TypeDirectiveLocation},
     output_type::OutputType,
     utils::{
-        extract_input_args, gen_boxed_trait, gen_deprecation, gen_directive_calls,
-        generate_default, generate_guards, get_cfg_attrs, get_crate_name, get_rustdoc,
-        get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
-        visible_fn, GeneratorResult,
+        extract_input_args, gen_boxed_trait, gen_cost, gen_deprecation, gen_directive_calls,
+        gen_list_size, gen_requires_scopes, generate_default, generate_guards, get_cfg_attrs,
+        get_crate_name, get_rustdoc, get_type_path_and_name, parse_complexity_expr,
+        parse_graphql_attrs, remove_graphql_attrs, visible_fn, GeneratorResult,
     },
     validators::Validators,
 };
@@ -61,6 +61,7 @@ pub fn generate(
     };
 
     let mut flattened_resolvers = Vec::new();
+    let mut flattened_types = Vec::new();
     let mut resolvers = Vec::new();
     let mut resolver_idents = Vec::new();
     let mut resolver_fns = Vec::new();
@@ -69,7 +70,7 @@ pub fn generate(
     let mut add_keys = Vec::new();
     let mut create_entity_types = Vec::new();
 
-    let mut unresolvable_key = String::new();
+    let mut unresolvable_key = Vec::new();
 
     // Computation of the derivated fields
     let mut derived_impls = vec![];
@@ -199,18 +200,19 @@ pub fn generate(
 
                 for (ident, ty, args::Argument { name, key, .. }) in &args {
                     let is_key = all_key || *key;
-                    let name = name.clone().unwrap_or_else(|| {
-                        object_args
-                            .rename_args
-                            .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                    });
+                    let name = object_args.rename_args.gen_name(
+                        &crate_name,
+                        name.as_deref(),
+                        ident.ident.unraw().to_string(),
+                        RenameTarget::Argument,
+                    );
 
                     if is_key {
                         get_federation_key.push(quote! {
                             if let Some(fields) = <#ty as #crate_name::InputType>::federation_fields() {
                                 key_str.push(format!("{} {}", #name, fields));
                             } else {
-                                key_str.push(#name.to_string());
+                                key_str.push(::std::string::ToString::to_string(#name));
                             }
                         });
 
@@ -318,16 +320,18 @@ pub fn generate(
                             return ::std::result::Result::Ok(std::option::Option::Some(value));
                         }
                     });
+                    flattened_types.push(ty.clone());
 
                     remove_graphql_attrs(&mut method.attrs);
                     continue;
                 }
 
-                let field_name = method_args.name.clone().unwrap_or_else(|| {
-                    object_args
-                        .rename_fields
-                        .rename(method.sig.ident.unraw().to_string(), RenameTarget::Field)
-                });
+                let field_name = object_args.rename_fields.gen_name(
+                    &crate_name,
+                    method_args.name.as_deref(),
+                    method.sig.ident.unraw().to_string(),
+                    RenameTarget::Field,
+                );
                 let field_desc = get_rustdoc(&method.attrs)?
                     .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -341,8 +345,7 @@ pub fn generate(
                     .map(|tag| quote!(::std::string::ToString::to_string(#tag)))
                     .collect::<Vec<_>>();
 
-                unresolvable_key.push_str(&field_name);
-                unresolvable_key.push(' ');
+                unresolvable_key.push(field_name.clone());
 
                 let directives = gen_directive_calls(
                     &method_args.directives,
@@ -406,11 +409,12 @@ pub fn generate(
                     },
                 ) in &args
                 {
-                    let name = name.clone().unwrap_or_else(|| {
-                        object_args
-                            .rename_args
-                            .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                    });
+                    let name = object_args.rename_args.gen_name(
+                        &crate_name,
+                        name.as_deref(),
+                        ident.ident.unraw().to_string(),
+                        RenameTarget::Argument,
+                    );
                     let desc = desc
                         .as_ref()
                         .map(|s| quote! {::std::option::Option::Some(::std::string::ToString::to_string(#s))})
@@ -474,6 +478,10 @@ pub fn generate(
                 let schema_ty = ty.value_type();
                 let visible = visible_fn(&method_args.visible);
 
+                let cost = gen_cost(&method_args.cost);
+                let authenticated = method_args.authenticated;
+                let requires_scopes = gen_requires_scopes(&method_args.requires_scopes);
+                let list_size = gen_list_size(&crate_name, &method_args.list_size);
                 let complexity = if let Some(complexity) = &method_args.complexity {
                     let (variables, expr) = parse_complexity_expr(complexity.clone())?;
                     let mut parse_args = Vec::new();
@@ -497,11 +505,12 @@ pub fn generate(
                                 }
                                 None => quote! { ::std::option::Option::None },
                             };
-                            let name = name.clone().unwrap_or_else(|| {
-                                object_args
-                                    .rename_args
-                                    .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                            });
+                            let name = object_args.rename_args.gen_name(
+                                &crate_name,
+                                name.as_deref(),
+                                ident.ident.unraw().to_string(),
+                                RenameTarget::Argument,
+                            );
                             parse_args.push(quote! {
                                 let #ident: #ty = __ctx.param_value(__variables_definition, __field, #name, #default)?;
                             });
@@ -509,8 +518,9 @@ pub fn generate(
                     }
                     quote! {
                         ::std::option::Option::Some(|__ctx, __variables_definition, __field, child_complexity| {
+                            let __naming_convention = __ctx.naming_convention();
                             #(#parse_args)*
-                            ::std::result::Result::Ok(#expr)
+                            #crate_name::registry::complexity::eval(|| #expr)
                         })
                     }
                 } else {
@@ -537,8 +547,12 @@ pub fn generate(
                         inaccessible: #inaccessible,
                         tags: ::std::vec![ #(#tags),* ],
                         override_from: #override_from,
+                        authenticated: #authenticated,
+                        requires_scopes: #requires_scopes,
                         visible: #visible,
                         compute_complexity: #complexity,
+                        cost: #cost,
+                        list_size: #list_size,
                         directive_invocations: ::std::vec![ #(#directives),* ]
                     });
                 });
@@ -618,10 +632,8 @@ pub fn generate(
             ::std::vec![ ::std::string::ToString::to_string(#key)]
         )),
         Resolvability::Unresolvable { key: None } => {
-            unresolvable_key.pop(); // need to remove the trailing space
-
             quote!(::std::option::Option::Some(
-                ::std::vec![ ::std::string::ToString::to_string(#unresolvable_key)]
+                ::std::vec![ [#(#unresolvable_key),*].join(" ")]
             ))
         }
     };
@@ -653,6 +665,10 @@ pub fn generate(
                 #[allow(unused_braces, unused_variables, unused_parens, unused_mut)]
                 #boxed_trait
                 impl #impl_generics #crate_name::resolver_utils::ContainerType for #self_ty #where_clause {
+                    fn can_check_guards() -> bool {
+                        true #(&& <#flattened_types as #crate_name::resolver_utils::ContainerType>::can_check_guards())*
+                    }
+
                     async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
                         #resolve_field_resolver_match
                         #(#flattened_resolvers)*
@@ -660,6 +676,7 @@ pub fn generate(
                     }
 
                     async fn find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                        let __naming_convention = ctx.schema_env.registry.naming_convention;
                         let params = match params {
                             #crate_name::Value::Object(params) => params,
                             _ => return ::std::result::Result::Ok(::std::option::Option::None),
@@ -684,6 +701,7 @@ pub fn generate(
                     }
 
                     fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
+                        let __naming_convention = registry.naming_convention;
                         let ty = registry.create_output_type::<Self, _>(#crate_name::registry::MetaTypeId::Object, |registry| #crate_name::registry::MetaType::Object {
                             name: ::std::borrow::Cow::into_owned(#gql_typename),
                             description: #desc,
@@ -737,6 +755,7 @@ pub fn generate(
                     #(#resolver_fns)*
 
                     fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> ::std::string::String  where Self: #crate_name::OutputType {
+                        let __naming_convention = registry.naming_convention;
                         let ty = registry.create_output_type::<Self, _>(#crate_name::registry::MetaTypeId::Object, |registry| #crate_name::registry::MetaType::Object {
                             name: ::std::borrow::ToOwned::to_owned(name),
                             description: #desc,
@@ -770,6 +789,7 @@ pub fn generate(
                     }
 
                     async fn __internal_find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                        let __naming_convention = ctx.schema_env.registry.naming_convention;
                         let params = match params {
                             #crate_name::Value::Object(params) => params,
                             _ => return ::std::result::Result::Ok(::std::option::Option::None),
@@ -788,6 +808,7 @@ pub fn generate(
             };
         });
 
+        let concrete_can_check_guards = flattened_types.is_empty();
         for concrete in &object_args.concretes {
             let gql_typename = &concrete.name;
             let params = &concrete.params.0;
@@ -810,6 +831,11 @@ pub fn generate(
             codes.push(quote! {
                 #boxed_trait
                 impl #def_bounds #crate_name::resolver_utils::ContainerType for #concrete_type {
+                    fn can_check_guards() -> bool {
+                        // The flattened types may depend on the generic parameters.
+                        #concrete_can_check_guards
+                    }
+
                     async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
                         self.__internal_resolve_field(ctx).await
                     }
@@ -856,7 +882,8 @@ fn generate_field_match(
     }
 
     Ok(quote! {
-        let __field = __FieldIdent::from_name(&ctx.item.node.name.node);
+        let __naming_convention = ctx.schema_env.registry.naming_convention;
+        let __field = __FieldIdent::from_name(&ctx.item.node.name.node, __naming_convention);
         match __field {
             #(#resolvers)*
             None => {}
@@ -866,7 +893,7 @@ fn generate_field_match(
 
 fn generate_fields_enum(
     crate_name: &proc_macro2::TokenStream,
-    fields: Vec<(String, Ident, Vec<Attribute>)>,
+    fields: Vec<(proc_macro2::TokenStream, Ident, Vec<Attribute>)>,
 ) -> GeneratorResult<proc_macro2::TokenStream> {
     // If there are no non-entity/flattened resolvers we can avoid the whole enum
     if fields.is_empty() {
@@ -883,7 +910,7 @@ fn generate_fields_enum(
     let matches = fields.iter().map(|(field_name, field_ident, cfg_attrs)| {
         quote! {
             #(#cfg_attrs)*
-            #field_name => ::std::option::Option::Some(__FieldIdent::#field_ident),
+            __name if __name == #field_name => ::std::option::Option::Some(__FieldIdent::#field_ident),
         }
     });
 
@@ -895,7 +922,10 @@ fn generate_fields_enum(
         }
 
         impl __FieldIdent {
-            fn from_name(__name: &#crate_name::Name) -> ::std::option::Option<__FieldIdent> {
+            fn from_name(
+                __name: &#crate_name::Name,
+                __naming_convention: #crate_name::registry::NamingConvention,
+            ) -> ::std::option::Option<__FieldIdent> {
                 match __name.as_str() {
                     #(#matches)*
                     _ => ::std::option::Option::None
@@ -917,6 +947,9 @@ fn generate_field_resolver_method(
         cfg_attrs,
     } = field;
 
+    let serial = method_args
+        .serial
+        .then(|| quote!(let ctx_obj = ctx_obj.with_serial();));
     let extract_params = params
         .iter()
         .map(|param| generate_parameter_extraction(crate_name, param))
@@ -940,19 +973,48 @@ fn generate_field_resolver_method(
         syn::parse_str::<Ident>(&format!("__{}_resolver", field.resolver_fn_ident.unraw()))?;
     resolve_fn_name.set_span(Span::call_site());
 
+    let resolve = quote! {
+        let res = self.#resolver_ident(ctx, #(#use_params),*).await;
+        res.map_err(|err| ::std::convert::Into::<#crate_name::Error>::into(err).into_server_error(ctx.item.pos))
+    };
+    let resolve = if method_args.cache_in_request {
+        let cache_key = params.iter().map(
+            |FieldResolverParameter {
+                 ident: PatIdent { ident, .. },
+                 ..
+             }| quote!(::std::clone::Clone::clone(&#ident)),
+        );
+        quote! {
+            ctx.cache_in_request(
+                ::std::any::type_name::<Self>(),
+                ::std::stringify!(#resolver_ident),
+                (#(#cache_key,)*),
+                async { #resolve },
+            ).await
+        }
+    } else {
+        resolve
+    };
+
     let function = quote! {
         #[doc(hidden)]
         #(#cfg_attrs)*
         #[allow(non_snake_case)]
         async fn #resolve_fn_name(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+            let __naming_convention = ctx.schema_env.registry.naming_convention;
             let f = async {
                 #(#extract_params)*
                 #guard
-                let res = self.#resolver_ident(ctx, #(#use_params),*).await;
-                res.map_err(|err| ::std::convert::Into::<#crate_name::Error>::into(err).into_server_error(ctx.item.pos))
+                if ctx.guard_mode() == #crate_name::GuardMode::CheckOnly {
+                    return ::std::result::Result::Ok(::std::option::Option::None);
+                }
+                ::std::result::Result::Ok(::std::option::Option::Some({ #resolve }?))
+            };
+            let ::std::option::Option::Some(obj) = f.await.map_err(|err| ctx.set_error_path(err))? else {
+                return ::std::result::Result::Ok(::std::option::Option::None);
             };
-            let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
             let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+            #serial
             return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
         }
     };
@@ -1018,6 +1080,6 @@ struct FieldResolverParameter<'a> {
     process_with: &'a Option<Expr>,
     validator: &'a Option<Validators>,
     ident: PatIdent,
-    name: String,
+    name: proc_macro2::TokenStream,
     default: Option<proc_macro2::TokenStream>,
 }
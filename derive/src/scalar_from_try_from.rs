@@ -0,0 +1,49 @@
+use proc_macro::TokenStream;
+use syn::{parse_quote, ItemImpl};
+
+use crate::{
+    args, scalar,
+    utils::{get_crate_name, GeneratorResult},
+};
+
+pub fn generate(args: &args::ScalarFromTryFrom) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_name(args.internal);
+    let ident = &args.ident;
+    let try_from = &args.try_from;
+    let attrs = &args.attrs;
+    let (impl_generics, ty_generics, where_clause) = args.generics.split_for_impl();
+
+    let mut item_impl: ItemImpl = parse_quote! {
+        #(#attrs)*
+        #[allow(clippy::all, clippy::pedantic)]
+        impl #impl_generics #crate_name::ScalarType for #ident #ty_generics #where_clause {
+            fn parse(value: #crate_name::Value) -> #crate_name::InputValueResult<Self> {
+                let value = <#try_from as #crate_name::ScalarType>::parse(value)
+                    .map_err(#crate_name::InputValueError::propagate)?;
+                <Self as ::std::convert::TryFrom<#try_from>>::try_from(value)
+                    .map_err(#crate_name::InputValueError::custom)
+            }
+
+            fn is_valid(value: &#crate_name::Value) -> bool {
+                <#try_from as #crate_name::ScalarType>::is_valid(value)
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                <#try_from as #crate_name::ScalarType>::to_value(
+                    &<#try_from as ::std::convert::From<Self>>::from(::std::clone::Clone::clone(self)),
+                )
+            }
+        }
+    };
+
+    let scalar_args = args::Scalar {
+        internal: args.internal,
+        name: args.name.clone(),
+        visible: args.visible.clone(),
+        inaccessible: args.inaccessible,
+        tags: args.tags.clone(),
+        specified_by_url: args.specified_by_url.clone(),
+        ..Default::default()
+    };
+    scalar::generate(&scalar_args, &mut item_impl)
+}
@@ -10,8 +10,9 @@ use crate::{
         self, RenameRuleExt, RenameTarget, Resolvability, SimpleObjectField, TypeDirectiveLocation,
     },
     utils::{
-        gen_boxed_trait, gen_deprecation, gen_directive_calls, generate_guards, get_crate_name,
-        get_rustdoc, parse_complexity_expr, visible_fn, GeneratorResult,
+        gen_boxed_trait, gen_cost, gen_deprecation, gen_directive_calls, gen_list_size,
+        gen_requires_scopes, generate_guards, get_crate_name, get_rustdoc, parse_complexity_expr,
+        visible_fn, GeneratorResult,
     },
 };
 
@@ -73,6 +74,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
     let mut getters = Vec::new();
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut flattened_types = Vec::new();
 
     let mut processed_fields: Vec<SimpleObjectFieldGenerator> = vec![];
 
@@ -130,11 +132,12 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             base_ident
         };
 
-        let field_name = field.name.clone().unwrap_or_else(|| {
-            object_args
-                .rename_fields
-                .rename(ident.unraw().to_string(), RenameTarget::Field)
-        });
+        let field_name = object_args.rename_fields.gen_name(
+            &crate_name,
+            field.name.as_deref(),
+            ident.unraw().to_string(),
+            RenameTarget::Field,
+        );
         let field_desc = get_rustdoc(&field.attrs)?
             .map(|s| quote! {::std::option::Option::Some(::std::string::ToString::to_string(#s))})
             .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -198,11 +201,15 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         let directives =
             gen_directive_calls(&field.directives, TypeDirectiveLocation::FieldDefinition);
 
+        let cost = gen_cost(&field.cost);
+        let authenticated = field.authenticated;
+        let requires_scopes = gen_requires_scopes(&field.requires_scopes);
+        let list_size = gen_list_size(&crate_name, &field.list_size);
         let complexity = if let Some(complexity) = &field.complexity {
             let (_, expr) = parse_complexity_expr(complexity.clone())?;
             quote! {
                 ::std::option::Option::Some(|__ctx, __variables_definition, __field, child_complexity| {
-                    ::std::result::Result::Ok(#expr)
+                    #crate_name::registry::complexity::eval(|| #expr)
                 })
             }
         } else {
@@ -225,8 +232,12 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     inaccessible: #inaccessible,
                     tags: ::std::vec![ #(#tags),* ],
                     override_from: #override_from,
+                    authenticated: #authenticated,
+                    requires_scopes: #requires_scopes,
                     visible: #visible,
                     compute_complexity: #complexity,
+                    cost: #cost,
+                    list_size: #list_size,
                     directive_invocations: ::std::vec![ #(#directives),* ],
                 });
             });
@@ -269,37 +280,65 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             (_, _) => block,
         };
 
+        let serial = field
+            .serial
+            .then(|| quote!(let ctx_obj = ctx_obj.with_serial();));
         let ty = match !owned {
             true => quote! { &#ty },
             false => quote! { #ty },
         };
 
         if !field.flatten {
-            getters.push(quote! {
-                 #[inline]
-                 #[allow(missing_docs)]
-                 #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#ty> {
-                     ::std::result::Result::Ok(#block)
-                 }
-            });
+            // The fields of an external resolver are resolved without getters.
+            if object_args.external_resolver {
+                resolvers.push(quote! {
+                    if ctx.item.node.name.node == #field_name {
+                        let f = async move {
+                            #guard
+                            if ctx.guard_mode() == #crate_name::GuardMode::CheckOnly {
+                                return ::std::result::Result::Ok(::std::option::Option::None);
+                            }
+                            #crate_name::resolver_utils::resolve_external_field(self, ctx)
+                                .await
+                                .map(::std::option::Option::Some)
+                        };
+                        return f.await.map_err(|err| ctx.set_error_path(err));
+                    }
+                });
+            } else {
+                getters.push(quote! {
+                     #[inline]
+                     #[allow(missing_docs)]
+                     #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#ty> {
+                         ::std::result::Result::Ok(#block)
+                     }
+                });
 
-            resolvers.push(quote! {
-                if ctx.item.node.name.node == #field_name {
-                    let f = async move {
-                        #guard
-                        self.#ident(ctx).await.map_err(|err| err.into_server_error(ctx.item.pos))
-                    };
-                    let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
-                    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                    return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
-                }
-            });
+                resolvers.push(quote! {
+                    if ctx.item.node.name.node == #field_name {
+                        let f = async move {
+                            #guard
+                            if ctx.guard_mode() == #crate_name::GuardMode::CheckOnly {
+                                return ::std::result::Result::Ok(::std::option::Option::None);
+                            }
+                            self.#ident(ctx).await.map(::std::option::Option::Some).map_err(|err| err.into_server_error(ctx.item.pos))
+                        };
+                        let ::std::option::Option::Some(obj) = f.await.map_err(|err| ctx.set_error_path(err))? else {
+                            return ::std::result::Result::Ok(::std::option::Option::None);
+                        };
+                        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                        #serial
+                        return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
+                    }
+                });
+            }
         } else {
             resolvers.push(quote! {
                 if let ::std::option::Option::Some(value) = #crate_name::ContainerType::resolve_field(&self.#ident, ctx).await? {
                     return ::std::result::Result::Ok(std::option::Option::Some(value));
                 }
             });
+            flattened_types.push(ty);
         }
     }
 
@@ -341,21 +380,16 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     } else {
                         generator.field.ident.as_ref().unwrap()
                     };
-                    generator.field.name.clone().unwrap_or_else(|| {
-                        object_args
-                            .rename_fields
-                            .rename(ident.unraw().to_string(), RenameTarget::Field)
-                    })
-                })
-                .reduce(|mut keys, key| {
-                    keys.push(' ');
-                    keys.push_str(&key);
-                    keys
-                })
-                .unwrap();
+                    object_args.rename_fields.gen_name(
+                        &crate_name,
+                        generator.field.name.as_deref(),
+                        ident.unraw().to_string(),
+                        RenameTarget::Field,
+                    )
+                });
 
             quote!(::std::option::Option::Some(
-                ::std::vec![ ::std::string::ToString::to_string(#keys) ]
+                ::std::vec![ [#(#keys),*].join(" ") ]
             ))
         }
     };
@@ -364,6 +398,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
 
     let mut concat_complex_fields = quote!();
     let mut complex_resolver = quote!();
+    let mut complex_can_check_guards = quote!();
 
     if object_args.complex {
         concat_complex_fields = quote! {
@@ -374,6 +409,9 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 return Ok(Some(value));
             }
         };
+        complex_can_check_guards = quote! {
+            && <Self as #crate_name::ComplexObject>::can_check_guards()
+        };
     }
 
     let resolve_container = if object_args.serial {
@@ -381,6 +419,15 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
     } else {
         quote! { #crate_name::resolver_utils::resolve_container(ctx, self).await }
     };
+    let resolve_container = if object_args.complex {
+        quote! {
+            let prepared = <Self as #crate_name::ComplexObject>::prepare(self, ctx, _field).await?;
+            let ctx = &ctx.with_prepared(prepared.as_deref());
+            #resolve_container
+        }
+    } else {
+        resolve_container
+    };
 
     let expanded = if object_args.concretes.is_empty() {
         quote! {
@@ -392,7 +439,12 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             #[allow(clippy::all, clippy::pedantic)]
             #boxed_trait
             impl #impl_generics #crate_name::resolver_utils::ContainerType for #ident #ty_generics #where_clause {
+                fn can_check_guards() -> bool {
+                    true #(&& <#flattened_types as #crate_name::resolver_utils::ContainerType>::can_check_guards())* #complex_can_check_guards
+                }
+
                 async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                    let __naming_convention = ctx.schema_env.registry.naming_convention;
                     #(#resolvers)*
                     #complex_resolver
                     ::std::result::Result::Ok(::std::option::Option::None)
@@ -407,6 +459,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 }
 
                 fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
+                    let __naming_convention = registry.naming_convention;
                     registry.create_output_type::<Self, _>(#crate_name::registry::MetaTypeId::Object, |registry| #crate_name::registry::MetaType::Object {
                         name: ::std::borrow::Cow::into_owned(#gql_typename),
                         description: #desc,
@@ -471,6 +524,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     name: &str,
                     complex_fields: #crate_name::indexmap::IndexMap<::std::string::String, #crate_name::registry::MetaField>,
                 ) -> ::std::string::String where Self: #crate_name::OutputType {
+                    let __naming_convention = registry.naming_convention;
                     registry.create_output_type::<Self, _>(#crate_name::registry::MetaTypeId::Object, |registry| #crate_name::registry::MetaType::Object {
                         name: ::std::borrow::ToOwned::to_owned(name),
                         description: #desc,
@@ -496,12 +550,14 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 }
 
                 async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> where Self: #crate_name::ContainerType {
+                    let __naming_convention = ctx.schema_env.registry.naming_convention;
                     #(#resolvers)*
                     ::std::result::Result::Ok(::std::option::Option::None)
                 }
             }
         });
 
+        let concrete_can_check_guards = flattened_types.is_empty();
         for concrete in &object_args.concretes {
             let gql_typename = &concrete.name;
             let params = &concrete.params.0;
@@ -521,6 +577,11 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 #[allow(clippy::all, clippy::pedantic)]
                 #boxed_trait
                 impl #def_bounds #crate_name::resolver_utils::ContainerType for #concrete_type {
+                    fn can_check_guards() -> bool {
+                        // The flattened types may depend on the generic parameters.
+                        #concrete_can_check_guards #complex_can_check_guards
+                    }
+
                     async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
                         #complex_resolver
                         self.__internal_resolve_field(ctx).await
@@ -0,0 +1,132 @@
+use darling::ast::{Data, Style};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse_quote, Error, ItemImpl};
+
+use crate::{
+    args::{self, RenameRuleExt, RenameTarget},
+    scalar,
+    utils::{get_crate_name, GeneratorResult},
+};
+
+pub fn generate(enum_args: &args::StringEnum) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_name(enum_args.internal);
+    let ident = &enum_args.ident;
+    let attrs = &enum_args.attrs;
+    let (impl_generics, ty_generics, where_clause) = enum_args.generics.split_for_impl();
+    let e = match &enum_args.data {
+        Data::Enum(e) => e,
+        _ => {
+            return Err(
+                Error::new_spanned(ident, "StringEnum can only be applied to an enum.").into(),
+            )
+        }
+    };
+
+    let mut item_idents = Vec::new();
+    let mut item_names = Vec::new();
+    let mut other = None;
+
+    for variant in e {
+        let item_ident = &variant.ident;
+        match variant.fields.style {
+            Style::Unit => {
+                let gql_item_name = variant.name.clone().unwrap_or_else(|| {
+                    enum_args
+                        .rename_items
+                        .rename(item_ident.unraw().to_string(), RenameTarget::EnumItem)
+                });
+                item_idents.push(item_ident);
+                item_names.push(gql_item_name);
+            }
+            Style::Tuple if variant.fields.len() == 1 && other.is_none() => {
+                other = Some(item_ident);
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    item_ident,
+                    format!(
+                        "Invalid enum variant {}.\nStringEnum may only contain unit variants and a single `Other(String)` variant.",
+                        item_ident
+                    ),
+                )
+                .into());
+            }
+        }
+    }
+
+    if item_idents.is_empty() && other.is_none() {
+        return Err(Error::new_spanned(
+            ident,
+            "A StringEnum must have at least one unit variant or an `Other(String)` variant.",
+        )
+        .into());
+    }
+
+    let (parse_other, is_valid) = match other {
+        Some(other) => (
+            quote! {
+                _ => ::std::result::Result::Ok(#ident::#other(::std::convert::From::from(value))),
+            },
+            quote! {
+                ::std::matches!(value, #crate_name::Value::String(_))
+            },
+        ),
+        None => (
+            quote! {
+                _ => ::std::result::Result::Err(#crate_name::InputValueError::custom(
+                    ::std::format!(r#"unknown value "{}""#, value),
+                )),
+            },
+            quote! {
+                ::std::matches!(
+                    value,
+                    #crate_name::Value::String(value) if ::std::matches!(value.as_str(), #(#item_names)|*)
+                )
+            },
+        ),
+    };
+    let other_to_value = other.map(|other| {
+        quote! {
+            #ident::#other(value) => ::std::string::ToString::to_string(value),
+        }
+    });
+
+    let mut item_impl: ItemImpl = parse_quote! {
+        #(#attrs)*
+        #[allow(clippy::all, clippy::pedantic)]
+        impl #impl_generics #crate_name::ScalarType for #ident #ty_generics #where_clause {
+            fn parse(value: #crate_name::Value) -> #crate_name::InputValueResult<Self> {
+                match value {
+                    #crate_name::Value::String(value) => match value.as_str() {
+                        #(#item_names => ::std::result::Result::Ok(#ident::#item_idents),)*
+                        #parse_other
+                    },
+                    value => ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &#crate_name::Value) -> bool {
+                #is_valid
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                #crate_name::Value::String(match self {
+                    #(#ident::#item_idents => ::std::string::ToString::to_string(#item_names),)*
+                    #other_to_value
+                })
+            }
+        }
+    };
+
+    let scalar_args = args::Scalar {
+        internal: enum_args.internal,
+        name: enum_args.name.clone(),
+        visible: enum_args.visible.clone(),
+        inaccessible: enum_args.inaccessible,
+        tags: enum_args.tags.clone(),
+        specified_by_url: enum_args.specified_by_url.clone(),
+        ..Default::default()
+    };
+    scalar::generate(&scalar_args, &mut item_impl)
+}
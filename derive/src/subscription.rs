@@ -9,10 +9,10 @@ use crate::{
     args::{self, RenameRuleExt, RenameTarget, SubscriptionField, TypeDirectiveLocation},
     output_type::OutputType,
     utils::{
-        extract_input_args, gen_deprecation, gen_directive_calls, generate_default,
-        generate_guards, get_cfg_attrs, get_crate_name, get_rustdoc, get_type_path_and_name,
-        parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs, visible_fn,
-        GeneratorResult,
+        extract_input_args, gen_cost, gen_deprecation, gen_directive_calls, gen_list_size,
+        generate_default, generate_guards, get_cfg_attrs, get_crate_name, get_rustdoc,
+        get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
+        visible_fn, GeneratorResult,
     },
 };
 
@@ -58,11 +58,12 @@ pub fn generate(
             }
 
             let ident = method.sig.ident.clone();
-            let field_name = field.name.clone().unwrap_or_else(|| {
-                subscription_args
-                    .rename_fields
-                    .rename(method.sig.ident.unraw().to_string(), RenameTarget::Field)
-            });
+            let field_name = subscription_args.rename_fields.gen_name(
+                &crate_name,
+                field.name.as_deref(),
+                method.sig.ident.unraw().to_string(),
+                RenameTarget::Field,
+            );
             let field_desc = get_rustdoc(&method.attrs)?
                 .map(|s| quote! {::std::option::Option::Some(::std::string::ToString::to_string(#s))})
                 .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -98,11 +99,12 @@ pub fn generate(
                 },
             ) in &args
             {
-                let name = name.clone().unwrap_or_else(|| {
-                    subscription_args
-                        .rename_args
-                        .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                });
+                let name = subscription_args.rename_args.gen_name(
+                    &crate_name,
+                    name.as_deref(),
+                    ident.ident.unraw().to_string(),
+                    RenameTarget::Argument,
+                );
                 let desc = desc
                     .as_ref()
                     .map(|s| quote! {::std::option::Option::Some(::std::string::ToString::to_string(#s))})
@@ -209,6 +211,8 @@ pub fn generate(
             }
 
             let visible = visible_fn(&field.visible);
+            let cost = gen_cost(&field.cost);
+            let list_size = gen_list_size(&crate_name, &field.list_size);
             let complexity = if let Some(complexity) = &field.complexity {
                 let (variables, expr) = parse_complexity_expr(complexity.clone())?;
                 let mut parse_args = Vec::new();
@@ -232,11 +236,12 @@ pub fn generate(
                             }
                             None => quote! { ::std::option::Option::None },
                         };
-                        let name = name.clone().unwrap_or_else(|| {
-                            subscription_args
-                                .rename_args
-                                .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
-                        });
+                        let name = subscription_args.rename_args.gen_name(
+                            &crate_name,
+                            name.as_deref(),
+                            ident.ident.unraw().to_string(),
+                            RenameTarget::Argument,
+                        );
                         parse_args.push(quote! {
                             let #ident: #ty = __ctx.param_value(__variables_definition, __field, #name, #default)?;
                         });
@@ -244,8 +249,9 @@ pub fn generate(
                 }
                 quote! {
                     Some(|__ctx, __variables_definition, __field, child_complexity| {
+                        let __naming_convention = __ctx.naming_convention();
                         #(#parse_args)*
-                        ::std::result::Result::Ok(#expr)
+                        #crate_name::registry::complexity::eval(|| #expr)
                     })
                 }
             } else {
@@ -273,10 +279,14 @@ pub fn generate(
                     provides: ::std::option::Option::None,
                     shareable: false,
                     override_from: ::std::option::Option::None,
+                    authenticated: false,
+                    requires_scopes: ::std::default::Default::default(),
                     visible: #visible,
                     inaccessible: false,
                     tags: ::std::default::Default::default(),
                     compute_complexity: #complexity,
+                    cost: #cost,
+                    list_size: #list_size,
                     directive_invocations: ::std::vec![ #(#directives),* ]
                 });
             });
@@ -362,6 +372,7 @@ pub fn generate(
 
                                     use ::std::iter::Extend;
                                     resp.errors.extend(::std::mem::take(&mut *query_env.errors.lock().unwrap()));
+                                    resp.downloads = query_env.take_downloads(&resp.data, ::std::option::Option::Some(field_name.as_str()));
                                     resp
                                 }
                             };
@@ -412,6 +423,7 @@ pub fn generate(
 
             #[allow(bare_trait_objects)]
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
+                let __naming_convention = registry.naming_convention;
                 registry.create_subscription_type::<Self, _>(|registry| #crate_name::registry::MetaType::Object {
                     name: ::std::borrow::Cow::into_owned(#gql_typename),
                     description: #desc,
@@ -439,6 +451,7 @@ pub fn generate(
                 &'__life self,
                 ctx: &'__life #crate_name::Context<'_>,
             ) -> ::std::option::Option<::std::pin::Pin<::std::boxed::Box<dyn #crate_name::futures_util::stream::Stream<Item = #crate_name::Response> + ::std::marker::Send + '__life>>> {
+                let __naming_convention = ctx.schema_env.registry.naming_convention;
                 #(#create_stream)*
                 ::std::option::Option::None
             }
@@ -31,6 +31,7 @@ pub fn generate(
         .unwrap_or_else(|| quote!(::std::option::Option::None));
     let visible = visible_fn(&directive_args.visible);
     let repeatable = directive_args.repeatable;
+    let introspectable = directive_args.introspectable;
 
     let composable = match directive_args.composable.as_ref() {
         Some(url) => quote!(::std::option::Option::Some(::std::string::ToString::to_string(#url))),
@@ -150,20 +151,21 @@ pub fn generate(
             }
 
             fn register(&self, registry: &mut #crate_name::registry::Registry) {
-                let meta = #crate_name::registry::MetaDirective {
-                    name: ::std::borrow::Cow::into_owned(#directive_name),
-                    description: #desc,
-                    locations: vec![#(#locations),*],
-                    args: {
-                        #[allow(unused_mut)]
-                        let mut args = #crate_name::indexmap::IndexMap::new();
-                        #(#schema_args)*
-                        args
-                    },
-                    is_repeatable: #repeatable,
-                    visible: #visible,
-                    composable: #composable,
+                let mut meta = #crate_name::registry::MetaDirective::new(
+                    ::std::borrow::Cow::into_owned(#directive_name),
+                    vec![#(#locations),*],
+                )
+                .repeatable(#repeatable)
+                .introspectable(#introspectable);
+                meta.description = #desc;
+                meta.args = {
+                    #[allow(unused_mut)]
+                    let mut args = #crate_name::indexmap::IndexMap::new();
+                    #(#schema_args)*
+                    args
                 };
+                meta.visible = #visible;
+                meta.composable = #composable;
                 registry.add_directive(meta);
             }
 
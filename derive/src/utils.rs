@@ -11,7 +11,7 @@ use syn::{
 };
 use thiserror::Error;
 
-use crate::args::{self, Deprecation, TypeDirectiveLocation, Visible};
+use crate::args::{self, Deprecation, ListSize, RequiresScopes, TypeDirectiveLocation, Visible};
 
 #[derive(Error, Debug)]
 pub enum GeneratorError {
@@ -55,7 +55,9 @@ pub fn generate_guards(
         #expr
     }};
     Ok(quote! {
-        #crate_name::Guard::check(&#code, &ctx).await #map_err ?;
+        if ctx.guard_mode() != #crate_name::GuardMode::Skip {
+            #crate_name::Guard::check(&#code, &ctx).await #map_err ?;
+        }
     })
 }
 
@@ -218,6 +220,47 @@ pub fn visible_fn(visible: &Option<Visible>) -> TokenStream {
     }
 }
 
+pub fn gen_cost(cost: &Option<usize>) -> TokenStream {
+    match cost {
+        Some(cost) => quote! { ::std::option::Option::Some(#cost) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+pub fn gen_list_size(crate_name: &TokenStream, list_size: &Option<ListSize>) -> TokenStream {
+    match list_size {
+        Some(list_size) => {
+            let assumed_size = match list_size.assumed_size {
+                Some(size) => quote! { ::std::option::Option::Some(#size) },
+                None => quote! { ::std::option::Option::None },
+            };
+            let slicing_arguments = &list_size.slicing_arguments;
+            let sized_fields = &list_size.sized_fields;
+            let require_one_slicing_argument =
+                list_size.require_one_slicing_argument.unwrap_or(true);
+            quote! {
+                ::std::option::Option::Some(#crate_name::registry::MetaListSize {
+                    assumed_size: #assumed_size,
+                    slicing_arguments: ::std::vec![ #(::std::string::ToString::to_string(#slicing_arguments)),* ],
+                    sized_fields: ::std::vec![ #(::std::string::ToString::to_string(#sized_fields)),* ],
+                    require_one_slicing_argument: #require_one_slicing_argument,
+                })
+            }
+        }
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+pub fn gen_requires_scopes(requires_scopes: &Option<RequiresScopes>) -> TokenStream {
+    let scopes = requires_scopes
+        .iter()
+        .flat_map(|requires_scopes| &requires_scopes.0)
+        .map(|scopes| {
+            quote! { ::std::vec![ #(::std::string::ToString::to_string(#scopes)),* ] }
+        });
+    quote! { ::std::vec![ #(#scopes),* ] }
+}
+
 pub fn parse_complexity_expr(expr: Expr) -> GeneratorResult<(HashSet<String>, Expr)> {
     #[derive(Default)]
     struct VisitComplexityExpr {
@@ -297,6 +340,7 @@ pub fn extract_input_args<T: FromMeta + Default>(
                                 pat.ty.as_ref().clone(),
                                 parse_graphql_attrs::<T>(&pat.attrs)?.unwrap_or_default(),
                             ));
+                            remove_graphql_attrs(&mut pat.attrs);
                         } else {
                             create_ctx = false;
                         }
@@ -13,7 +13,7 @@
 use std::fmt::{self, Display, Formatter};
 
 use async_graphql_value::Name;
-pub use parse::{parse_query, parse_schema};
+pub use parse::{parse_query, parse_query_recovering, parse_schema};
 use pest::{error::LineColLocation, RuleType};
 pub use pos::{Pos, Positioned};
 use serde::{Serialize, Serializer};
@@ -98,6 +98,113 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
     })
 }
 
+/// Parse a GraphQL query document, reporting every syntax error instead of
+/// only the first one.
+///
+/// The document is split at the end of its top-level definitions, each one is
+/// parsed on its own, so each definition reports at most one syntax error. A
+/// definition whose braces are not balanced extends to the end of the
+/// document, the rest of the document is not checked to avoid reporting errors
+/// caused by the first one. The other errors, such as duplicated operations,
+/// are only reported if the document has no syntax errors.
+///
+/// # Errors
+///
+/// Fails if the query is not a valid GraphQL document.
+pub fn parse_query_recovering<T: AsRef<str>>(
+    input: T,
+) -> std::result::Result<ExecutableDocument, Vec<Error>> {
+    let input = input.as_ref();
+    let err = match parse_query(input) {
+        Ok(doc) => return Ok(doc),
+        Err(err @ Error::Syntax { .. }) => err,
+        Err(err) => return Err(vec![err]),
+    };
+
+    let errors = split_definitions(input)
+        .into_iter()
+        .filter_map(|range| {
+            let err =
+                GraphQLParser::parse(Rule::executable_document, &input[range.clone()]).err()?;
+            // Move the error to the position of the definition in the whole
+            // document.
+            let err = match err.location {
+                InputLocation::Pos(pos) => pest::error::Error::new_from_pos(
+                    err.variant,
+                    pest::Position::new(input, range.start + pos)?,
+                ),
+                InputLocation::Span((start, end)) => pest::error::Error::new_from_span(
+                    err.variant,
+                    pest::Span::new(input, range.start + start, range.start + end)?,
+                ),
+            };
+            Some(Error::from(err))
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Err(vec![err])
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns the byte ranges of the top-level definitions of a document, a
+/// definition ends with the closing brace of its selection set.
+fn split_definitions(input: &str) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut significant = false;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if !matches!(c, '#' | ',' | '\u{feff}') && !c.is_whitespace() {
+            significant = true;
+        }
+        match c {
+            '#' => while chars.next_if(|(_, c)| *c != '\n' && *c != '\r').is_some() {},
+            '"' if input[idx..].starts_with("\"\"\"") => {
+                chars.nth(1);
+                while let Some((idx, c)) = chars.next() {
+                    if c == '\\' && input[idx..].starts_with("\\\"\"\"") {
+                        chars.nth(2);
+                    } else if c == '"' && input[idx..].starts_with("\"\"\"") {
+                        chars.nth(1);
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' | '\n' | '\r' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                if c == '}' && depth == 0 {
+                    ranges.push(start..idx + 1);
+                    start = idx + 1;
+                    significant = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if significant {
+        ranges.push(start..input.len());
+    }
+    ranges
+}
+
 fn parse_definition_items(
     pair: Pair<Rule>,
     pc: &mut PositionCalculator,
@@ -420,6 +527,7 @@ mod tests {
     use std::fs;
 
     use super::*;
+    use crate::Pos;
 
     #[test]
     fn test_parser() {
@@ -451,4 +559,52 @@ mod tests {
         assert!(parse_query(query_ok).is_ok());
         assert!(parse_query(query_overflow).is_ok());
     }
+
+    fn error_starts(errors: Vec<Error>) -> Vec<Pos> {
+        errors
+            .iter()
+            .map(|err| match err {
+                Error::Syntax { start, .. } => *start,
+                _ => panic!("unexpected error: {}", err),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_query_recovering() {
+        let query = r#"query A { a(x: ) }
+# "comment" {
+query B { b { c } }
+query C { d @ }
+fragment F on T { e(s: "}", f: { g: [1, 2] }) }
+"#;
+        assert_eq!(
+            error_starts(parse_query_recovering(query).unwrap_err()),
+            vec![
+                Pos {
+                    line: 1,
+                    column: 16
+                },
+                Pos {
+                    line: 4,
+                    column: 15
+                }
+            ]
+        );
+
+        // The messages quote the line of the whole document.
+        assert!(parse_query_recovering(query).unwrap_err()[1]
+            .to_string()
+            .contains("--> 4:15"));
+
+        // The unbalanced brace hides the rest of the document.
+        let query = "query A { a { b }\nquery B { c( }\nquery C { d }";
+        assert_eq!(parse_query_recovering(query).unwrap_err().len(), 1);
+
+        assert_eq!(
+            parse_query_recovering("query A { a } query A { b }").unwrap_err(),
+            vec![parse_query("query A { a } query A { b }").unwrap_err()]
+        );
+        assert!(parse_query_recovering("query A { a } # comment").is_ok());
+    }
 }
@@ -5,6 +5,7 @@
 use std::collections::{hash_map, HashMap};
 
 use pest::{
+    error::InputLocation,
     iterators::{Pair, Pairs},
     Parser,
 };
@@ -25,7 +26,7 @@ mod service;
 mod utils;
 
 use async_graphql_value::{ConstValue, Name, Number, Value};
-pub use executable::parse_query;
+pub use executable::{parse_query, parse_query_recovering};
 use generated::Rule;
 pub use service::parse_schema;
 
@@ -1,6 +1,7 @@
 #[cfg(not(feature = "boxed-trait"))]
 use std::future::Future;
 use std::{
+    any::Any,
     borrow::Cow,
     sync::{Arc, Weak},
 };
@@ -292,6 +293,11 @@ impl<T: OutputType + ?Sized> OutputType for Weak<T> {
 pub trait ComplexObject {
     fn fields(registry: &mut registry::Registry) -> Vec<(String, registry::MetaField)>;
 
+    /// See [`ContainerType::can_check_guards`].
+    fn can_check_guards() -> bool {
+        false
+    }
+
     #[cfg(feature = "boxed-trait")]
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>>;
 
@@ -300,4 +306,26 @@ pub trait ComplexObject {
         &self,
         ctx: &Context<'_>,
     ) -> impl Future<Output = ServerResult<Option<Value>>> + Send;
+
+    /// Runs the `#[graphql(prepare)]` method if one of the fields depending on
+    /// it is selected, the value is passed to these fields.
+    #[cfg(feature = "boxed-trait")]
+    async fn prepare(
+        &self,
+        _ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Option<Box<dyn Any + Send + Sync>>> {
+        Ok(None)
+    }
+
+    /// Runs the `#[graphql(prepare)]` method if one of the fields depending on
+    /// it is selected, the value is passed to these fields.
+    #[cfg(not(feature = "boxed-trait"))]
+    fn prepare(
+        &self,
+        _ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> impl Future<Output = ServerResult<Option<Box<dyn Any + Send + Sync>>>> + Send {
+        async { Ok(None) }
+    }
 }
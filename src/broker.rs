@@ -0,0 +1,170 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll, Waker},
+};
+
+use futures_util::Stream;
+
+struct Subscriber<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+struct BrokerInner<T> {
+    subscribers: Vec<Weak<Mutex<Subscriber<T>>>>,
+    replay: VecDeque<T>,
+    replay_capacity: usize,
+}
+
+/// An in-memory broker publishing messages to subscription streams.
+///
+/// Every stream returned by [`SimpleBroker::subscribe`] receives the messages
+/// published after it was created, a subscriber doesn't receive the past
+/// messages, unless the broker is created with a replay buffer. The broker is
+/// cheap to clone, the clones share the same subscribers, so it can be added
+/// to the data of the schema and used in the resolvers.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{futures_util::stream::Stream, *};
+///
+/// #[derive(Clone)]
+/// struct Event(i32);
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Subscription;
+///
+/// #[Subscription]
+/// impl Subscription {
+///     async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+///         use async_graphql::futures_util::StreamExt;
+///
+///         ctx.data_unchecked::<SimpleBroker<Event>>()
+///             .subscribe()
+///             .map(|event| event.0)
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// use async_graphql::futures_util::StreamExt;
+///
+/// let broker = SimpleBroker::<Event>::new();
+/// let schema = Schema::build(Query, EmptyMutation, Subscription)
+///     .data(broker.clone())
+///     .finish();
+/// let mut stream = schema.execute_stream("subscription { events }");
+///
+/// // Start the subscription before publishing.
+/// assert!(futures_util::poll!(stream.next()).is_pending());
+/// broker.publish(Event(1));
+/// assert_eq!(stream.next().await.unwrap().data, value!({ "events": 1 }));
+/// # });
+/// ```
+pub struct SimpleBroker<T>(Arc<Mutex<BrokerInner<T>>>);
+
+impl<T> Clone for SimpleBroker<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Clone> Default for SimpleBroker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> SimpleBroker<T> {
+    /// Create a broker without a replay buffer.
+    pub fn new() -> Self {
+        Self::with_replay(0)
+    }
+
+    /// Create a broker keeping the last `capacity` messages, which are sent
+    /// to the new subscribers before the messages published after they
+    /// subscribed.
+    pub fn with_replay(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(BrokerInner {
+            subscribers: Vec::new(),
+            replay: VecDeque::with_capacity(capacity),
+            replay_capacity: capacity,
+        })))
+    }
+
+    /// Publish a message to all the current subscribers.
+    pub fn publish(&self, msg: T) {
+        let mut inner = self.0.lock().unwrap();
+        inner.subscribers.retain(|subscriber| {
+            let Some(subscriber) = subscriber.upgrade() else {
+                return false;
+            };
+            let mut subscriber = subscriber.lock().unwrap();
+            subscriber.queue.push_back(msg.clone());
+            if let Some(waker) = subscriber.waker.take() {
+                waker.wake();
+            }
+            true
+        });
+
+        if inner.replay_capacity > 0 {
+            if inner.replay.len() == inner.replay_capacity {
+                inner.replay.pop_front();
+            }
+            inner.replay.push_back(msg);
+        }
+    }
+
+    /// Returns a stream of the messages published from now on, preceded by
+    /// the messages of the replay buffer.
+    ///
+    /// The subscriber is removed when the stream is dropped.
+    pub fn subscribe(&self) -> impl Stream<Item = T> + Send + 'static
+    where
+        T: Send + 'static,
+    {
+        let mut inner = self.0.lock().unwrap();
+        let subscriber = Arc::new(Mutex::new(Subscriber {
+            queue: inner.replay.clone(),
+            waker: None,
+        }));
+        inner.subscribers.push(Arc::downgrade(&subscriber));
+        BrokerStream(subscriber)
+    }
+
+    /// Returns the number of subscribers whose stream hasn't been dropped.
+    pub fn subscriber_count(&self) -> usize {
+        let mut inner = self.0.lock().unwrap();
+        inner
+            .subscribers
+            .retain(|subscriber| subscriber.strong_count() > 0);
+        inner.subscribers.len()
+    }
+}
+
+struct BrokerStream<T>(Arc<Mutex<Subscriber<T>>>);
+
+impl<T> Stream for BrokerStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut subscriber = self.0.lock().unwrap();
+        match subscriber.queue.pop_front() {
+            Some(msg) => Poll::Ready(Some(msg)),
+            None => {
+                subscriber.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
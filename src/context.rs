@@ -4,13 +4,17 @@ use std::{
     any::{Any, TypeId},
     collections::HashMap,
     fmt::{self, Debug, Display, Formatter},
+    future::Future,
+    hash::Hash,
     ops::Deref,
-    sync::{Arc, Mutex},
+    sync::{atomic::AtomicUsize, Arc, Mutex},
+    time::Instant,
 };
 
-use async_graphql_parser::types::ConstDirective;
-use async_graphql_value::{Value as InputValue, Variables};
+use async_graphql_parser::types::{ConstDirective, OperationType};
+use async_graphql_value::{Extensions as RequestExtensions, Value as InputValue, Variables};
 use fnv::FnvHashMap;
+use futures_util::future::BoxFuture;
 use serde::{
     ser::{SerializeSeq, Serializer},
     Serialize,
@@ -21,9 +25,11 @@ use crate::{
     parser::types::{
         Directive, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
     },
+    resolver_utils::LazyGate,
     schema::{IntrospectionMode, SchemaEnv},
-    Error, InputType, Lookahead, Name, OneofObjectType, PathSegment, Pos, Positioned, Result,
-    ServerError, ServerResult, UploadValue, Value,
+    validation::is_valid_input_value,
+    CacheControl, DownloadValue, Error, InputType, Lookahead, Name, OneofObjectType, PathSegment,
+    Pos, Positioned, Request, Response, Result, ServerError, ServerResult, UploadValue, Value,
 };
 
 /// Data related functions of the context.
@@ -81,6 +87,79 @@ impl Debug for Data {
     }
 }
 
+/// Default values of field arguments provided through the context data.
+///
+/// The defaults are keyed by the schema coordinate of the argument, e.g.
+/// `Query.items(limit:)`. When a field argument is omitted by the client, the
+/// value registered for it is used instead of the default declared in the
+/// schema, and is validated against the type of the argument. The defaults
+/// are looked up in the request data first and then in the schema data, so
+/// they can be set per request, for example from the current user.
+///
+/// Arguments that are required by the schema must still be provided by the
+/// client, validation rejects the query otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn items(&self, #[graphql(default = 10)] limit: i32) -> i32 {
+///         limit
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let request = Request::new("{ a: items b: items(limit: 3) }")
+///     .data(ArgumentDefaults::new().insert("Query.items(limit:)", 50));
+/// let res = schema.execute(request).await;
+/// assert_eq!(res.data, value!({ "a": 50, "b": 3 }));
+/// # });
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ArgumentDefaults(HashMap<String, Value>);
+
+impl ArgumentDefaults {
+    /// Create an empty set of argument defaults.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the default value of the argument at `coordinate`, e.g.
+    /// `Query.items(limit:)`.
+    #[must_use]
+    pub fn insert(mut self, coordinate: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.0.insert(coordinate.into(), value.into());
+        self
+    }
+
+    /// Returns the default value of the argument `argument` of the field
+    /// `field_name` of the type `type_name`.
+    pub fn get(&self, type_name: &str, field_name: &str, argument: &str) -> Option<&Value> {
+        self.0
+            .get(&format!("{}.{}({}:)", type_name, field_name, argument))
+    }
+}
+
+/// Where the value of a field argument comes from, returned by
+/// [`Context::argument_provenance`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ArgumentProvenance {
+    /// The argument is provided by the client, as a literal or a variable.
+    Client,
+    /// The argument is omitted and its value comes from the
+    /// [`ArgumentDefaults`] of the context.
+    ContextDefault,
+    /// The argument is omitted and its value is the default declared in the
+    /// schema, or `null` if it has none.
+    Default,
+}
+
 /// Context for `SelectionSet`
 pub type ContextSelectionSet<'a> = ContextBase<'a, &'a Positioned<SelectionSet>>;
 
@@ -243,8 +322,76 @@ pub struct ContextBase<'a, T> {
     pub query_env: &'a QueryEnv,
     #[doc(hidden)]
     pub execute_data: Option<&'a Data>,
+    /// The gate of the container being resolved, used by `Lazy`.
+    pub(crate) lazy_gate: Option<&'a LazyGate>,
+    /// The value prepared for the fields of the `ComplexObject` being
+    /// resolved.
+    pub(crate) prepared: Option<Prepared<'a>>,
+    /// How the guards of the top-level fields are run.
+    pub(crate) guard_mode: GuardMode,
+    /// If `true`, the fields and list items of the subtree are resolved
+    /// serially, see the `serial` attribute of the object fields.
+    pub(crate) serial: bool,
+    /// The name of the type whose fields are being resolved, used to look up
+    /// the [`ArgumentDefaults`] of their arguments.
+    pub(crate) parent_type: Option<&'a str>,
+}
+
+/// How the guards of the top-level fields of a mutation are run, see
+/// [`SchemaBuilder::check_mutation_guards_first`](crate::SchemaBuilder::check_mutation_guards_first).
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GuardMode {
+    /// The guards run before the fields are resolved.
+    Run,
+    /// Only the guards run, the fields are not resolved.
+    CheckOnly,
+    /// The guards have already been checked.
+    Skip,
+}
+
+/// The value returned by the `#[graphql(prepare)]` method of a
+/// `ComplexObject`, and the path of the object it was prepared for.
+#[derive(Clone, Copy)]
+pub(crate) struct Prepared<'a> {
+    path: Option<QueryPathNode<'a>>,
+    value: &'a (dyn Any + Send + Sync),
+}
+
+fn is_same_path<'p>(
+    mut a: Option<&'p QueryPathNode<'p>>,
+    mut b: Option<&'p QueryPathNode<'p>>,
+) -> bool {
+    loop {
+        match (a, b) {
+            (None, None) => return true,
+            (Some(node_a), Some(node_b)) => {
+                let is_same_segment = match (node_a.segment, node_b.segment) {
+                    (QueryPathSegment::Index(a), QueryPathSegment::Index(b)) => a == b,
+                    (QueryPathSegment::Name(a), QueryPathSegment::Name(b)) => a == b,
+                    _ => false,
+                };
+                if !is_same_segment {
+                    return false;
+                }
+                a = node_a.parent;
+                b = node_b.parent;
+            }
+            _ => return false,
+        }
+    }
 }
 
+/// A function that executes a child request of the given query environment
+/// against the same schema, used by [`ContextBase::execute_child`].
+#[doc(hidden)]
+pub type ExecuteChildFn =
+    Arc<dyn Fn(Request, QueryEnv) -> BoxFuture<'static, Response> + Send + Sync>;
+
+/// The parent path, type name and field name of a memoized field.
+#[doc(hidden)]
+pub type RequestCacheKey = (Vec<String>, &'static str, &'static str);
+
 #[doc(hidden)]
 pub struct QueryEnvInner {
     pub extensions: Extensions,
@@ -253,11 +400,22 @@ pub struct QueryEnvInner {
     pub operation: Positioned<OperationDefinition>,
     pub fragments: HashMap<Name, Positioned<FragmentDefinition>>,
     pub uploads: Vec<UploadValue>,
+    #[cfg(feature = "tempfile")]
+    pub upload_paths: Vec<Option<tempfile::TempPath>>,
     pub session_data: Arc<Data>,
     pub query_data: Arc<Data>,
+    pub request_extensions: Arc<RequestExtensions>,
     pub http_headers: Mutex<http::HeaderMap>,
+    pub downloads: Mutex<Vec<DownloadValue>>,
+    pub next_download_id: AtomicUsize,
     pub introspection_mode: IntrospectionMode,
+    pub deadline: Option<Instant>,
+    pub dry_run: bool,
     pub errors: Mutex<Vec<ServerError>>,
+    pub request_cache: Mutex<HashMap<RequestCacheKey, Box<dyn Any + Send + Sync>>>,
+    pub cache_control: Mutex<CacheControl>,
+    pub execute_child: Option<ExecuteChildFn>,
+    pub parent: Option<QueryEnv>,
 }
 
 #[doc(hidden)]
@@ -278,6 +436,13 @@ impl QueryEnv {
         QueryEnv(Arc::new(inner))
     }
 
+    /// Takes the downloads of the response data, see
+    /// [`Download`](crate::Download).
+    #[doc(hidden)]
+    pub fn take_downloads(&self, data: &Value, field: Option<&str>) -> Vec<DownloadValue> {
+        crate::types::take_downloads(&self.downloads, data, field)
+    }
+
     #[doc(hidden)]
     pub fn create_context<'a, T>(
         &'a self,
@@ -293,8 +458,25 @@ impl QueryEnv {
             schema_env,
             query_env: self,
             execute_data,
+            lazy_gate: None,
+            prepared: None,
+            guard_mode: GuardMode::Run,
+            serial: false,
+            parent_type: None,
         }
     }
+
+    /// Returns the number of parent requests of this request.
+    #[doc(hidden)]
+    pub fn child_depth(&self) -> usize {
+        std::iter::successors(self.parent.as_ref(), |env| env.parent.as_ref()).count()
+    }
+
+    fn query_data_opt(&self, type_id: &TypeId) -> Option<&(dyn Any + Send + Sync)> {
+        std::iter::successors(Some(self), |env| env.parent.as_ref())
+            .find_map(|env| env.query_data.0.get(type_id))
+            .map(AsRef::as_ref)
+    }
 }
 
 impl<'a, T> DataContext<'a> for ContextBase<'a, T> {
@@ -327,6 +509,11 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            lazy_gate: self.lazy_gate,
+            prepared: self.prepared,
+            guard_mode: self.guard_mode,
+            serial: self.serial,
+            parent_type: self.parent_type,
         }
     }
 
@@ -342,6 +529,11 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            lazy_gate: self.lazy_gate,
+            prepared: self.prepared,
+            guard_mode: self.guard_mode,
+            serial: self.serial,
+            parent_type: self.parent_type,
         }
     }
 
@@ -402,12 +594,64 @@ impl<'a, T> ContextBase<'a, T> {
         self.execute_data
             .as_ref()
             .and_then(|execute_data| execute_data.get(&TypeId::of::<D>()))
-            .or_else(|| self.query_env.query_data.0.get(&TypeId::of::<D>()))
-            .or_else(|| self.query_env.session_data.0.get(&TypeId::of::<D>()))
-            .or_else(|| self.schema_env.data.0.get(&TypeId::of::<D>()))
+            .map(AsRef::as_ref)
+            .or_else(|| self.query_env.query_data_opt(&TypeId::of::<D>()))
+            .or_else(|| {
+                self.query_env
+                    .session_data
+                    .0
+                    .get(&TypeId::of::<D>())
+                    .map(AsRef::as_ref)
+            })
+            .or_else(|| {
+                self.schema_env
+                    .data
+                    .0
+                    .get(&TypeId::of::<D>())
+                    .map(AsRef::as_ref)
+            })
             .and_then(|d| d.downcast_ref::<D>())
     }
 
+    /// Executes a child request against the schema that is executing the
+    /// current request, and returns its response so that it can be spliced
+    /// into the result of a resolver.
+    ///
+    /// The child request shares the session data and the query data of the
+    /// current request, the data attached to the child request takes
+    /// precedence.
+    ///
+    /// The nesting of child requests is limited by
+    /// [`SchemaBuilder::limit_child_depth`](crate::SchemaBuilder::limit_child_depth).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    ///
+    ///     async fn proxy(&self, ctx: &Context<'_>) -> Json<Value> {
+    ///         Json(ctx.execute_child("{ value }").await.data)
+    ///     }
+    /// }
+    /// ```
+    pub async fn execute_child(&self, request: impl Into<Request>) -> Response {
+        match &self.query_env.execute_child {
+            Some(execute_child) => execute_child(request.into(), self.query_env.clone()).await,
+            None => Response::from_errors(vec![ServerError::new(
+                "Child requests are not supported by this schema.",
+                None,
+            )]),
+        }
+    }
+
     /// Returns whether the HTTP header `key` is currently set on the response
     ///
     /// # Examples
@@ -549,6 +793,119 @@ impl<'a, T> ContextBase<'a, T> {
         }
     }
 
+    /// Lowers the cache control of the response.
+    ///
+    /// The value is merged with the cache control calculated from the schema
+    /// and with the values set by other resolvers: the smallest `max_age`
+    /// wins, and the response is private if any of them is private.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object(cache_control(max_age = 60))]
+    /// impl Query {
+    ///     async fn value(&self, ctx: &Context<'_>) -> i32 {
+    ///         ctx.set_cache_control(CacheControl {
+    ///             public: false,
+    ///             max_age: 10,
+    ///         });
+    ///         100
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// assert_eq!(
+    ///     schema.execute("{ value }").await.cache_control,
+    ///     CacheControl {
+    ///         public: false,
+    ///         max_age: 10
+    ///     }
+    /// );
+    /// # });
+    /// ```
+    pub fn set_cache_control(&self, cache_control: CacheControl) {
+        let mut current = self.query_env.cache_control.lock().unwrap();
+        *current = current.merge(&cache_control);
+    }
+
+    /// Returns the extensions of the current request.
+    ///
+    /// These are the values of the `extensions` field of the request body,
+    /// malformed extensions are ignored and seen as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn client(&self, ctx: &Context<'_>) -> Option<String> {
+    ///         match ctx.request_extensions().get("client") {
+    ///             Some(Value::String(client)) => Some(client.clone()),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let request: Request =
+    ///     serde_json::from_str(r#"{"query": "{ client }", "extensions": {"client": "web"}}"#)
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     schema.execute(request).await.data,
+    ///     value!({ "client": "web" })
+    /// );
+    /// # });
+    /// ```
+    pub fn request_extensions(&self) -> &'a RequestExtensions {
+        &self.query_env.request_extensions
+    }
+
+    /// Returns the deadline of the current request set with
+    /// [`Request::deadline`], or `None` if it has no deadline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn items(&self, ctx: &Context<'_>) -> Vec<i32> {
+    ///         let mut items = Vec::new();
+    ///         for i in 0..100 {
+    ///             if ctx.deadline().is_some_and(|deadline| Instant::now() >= deadline) {
+    ///                 break;
+    ///             }
+    ///             items.push(i);
+    ///         }
+    ///         items
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let request = Request::new("{ items }").deadline(Instant::now() + Duration::from_secs(1));
+    /// assert!(schema.execute(request).await.is_ok());
+    /// # });
+    /// ```
+    pub fn deadline(&self) -> Option<Instant> {
+        self.query_env.deadline
+    }
+
     fn var_value(&self, name: &str, pos: Pos) -> ServerResult<Value> {
         self.query_env
             .operation
@@ -617,20 +974,226 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            lazy_gate: self.lazy_gate,
+            prepared: self.prepared,
+            guard_mode: self.guard_mode,
+            serial: self.serial,
+            parent_type: self.parent_type,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_item<U>(&self, item: U) -> ContextBase<'a, U> {
+        ContextBase {
+            path_node: self.path_node,
+            is_for_introspection: self.is_for_introspection,
+            item,
+            schema_env: self.schema_env,
+            query_env: self.query_env,
+            execute_data: self.execute_data,
+            lazy_gate: self.lazy_gate,
+            prepared: self.prepared,
+            guard_mode: self.guard_mode,
+            serial: self.serial,
+            parent_type: self.parent_type,
+        }
+    }
+
+    /// Returns a context whose fields see the value returned by the
+    /// `#[graphql(prepare)]` method of the object being resolved.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_prepared<'b>(
+        &'b self,
+        value: Option<&'b (dyn Any + Send + Sync)>,
+    ) -> ContextBase<'b, T>
+    where
+        T: Copy,
+    {
+        ContextBase {
+            prepared: value.map(|value| Prepared {
+                path: self.path_node,
+                value,
+            }),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a context whose fields and list items are resolved serially,
+    /// used by the fields with the `serial` attribute.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_serial(&self) -> Self
+    where
+        T: Copy,
+    {
+        ContextBase {
+            serial: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns how the guard of the current field is run.
+    #[doc(hidden)]
+    pub fn guard_mode(&self) -> GuardMode {
+        match self.path_node {
+            Some(QueryPathNode { parent: None, .. }) => self.guard_mode,
+            _ => GuardMode::Run,
+        }
+    }
+
+    /// Returns the value prepared for the fields of the parent object, if
+    /// it was prepared and has the type `D`.
+    #[doc(hidden)]
+    pub fn prepared_value<D: Any + Send + Sync>(&self) -> Option<&'a D> {
+        let prepared = self.prepared?;
+        let parent = self.path_node.as_ref().and_then(|node| node.parent);
+        if is_same_path(parent, prepared.path.as_ref()) {
+            prepared.value.downcast_ref()
+        } else {
+            None
         }
     }
 }
 
 impl<'a> ContextBase<'a, &'a Positioned<Field>> {
+    /// Memoizes the result of `f` per parent object, field and arguments
+    /// within the request, used by `#[graphql(cache_in_request)]`.
+    ///
+    /// Concurrent resolutions of the same field wait for the first one, errors
+    /// are not cached.
+    #[doc(hidden)]
+    pub async fn cache_in_request<K, T, F>(
+        &self,
+        type_name: &'static str,
+        field_name: &'static str,
+        args: K,
+        f: F,
+    ) -> ServerResult<T>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = ServerResult<T>>,
+    {
+        type Entries<K, T> = HashMap<K, Arc<futures_util::lock::Mutex<Option<T>>>>;
+
+        // every event of a subscription must be resolved again
+        if self.query_env.operation.node.ty == OperationType::Subscription {
+            return f.await;
+        }
+
+        let parent = self
+            .path_node
+            .and_then(|node| node.parent)
+            .map(|node| node.to_string_vec())
+            .unwrap_or_default();
+        let entry = {
+            let mut cache = self.query_env.request_cache.lock().unwrap();
+            cache
+                .entry((parent, type_name, field_name))
+                .or_insert_with(|| Box::new(Entries::<K, T>::new()))
+                .downcast_mut::<Entries<K, T>>()
+                .map(|entries| entries.entry(args).or_default().clone())
+        };
+        let Some(entry) = entry else {
+            return f.await;
+        };
+
+        let mut value = entry.lock().await;
+        if let Some(value) = &*value {
+            return Ok(value.clone());
+        }
+        let res = f.await?;
+        *value = Some(res.clone());
+        Ok(res)
+    }
+
     #[doc(hidden)]
     pub fn param_value<T: InputType>(
         &self,
         name: &str,
         default: Option<fn() -> T>,
     ) -> ServerResult<(Pos, T)> {
+        if self.item.node.get_argument(name).is_none() {
+            if let Some(value) = self.context_argument_default(name) {
+                let registry = &self.schema_env.registry;
+                let ty = self
+                    .parent_type
+                    .and_then(|type_name| registry.types.get(type_name))
+                    .and_then(|ty| ty.field_by_name(&self.item.node.name.node))
+                    .and_then(|field| field.args.get(name))
+                    .map(|arg| arg.ty.as_str());
+                if let Some(reason) = ty.and_then(|ty| {
+                    is_valid_input_value(
+                        registry,
+                        ty,
+                        value,
+                        QueryPathNode {
+                            parent: None,
+                            segment: QueryPathSegment::Name(name),
+                        },
+                    )
+                }) {
+                    return Err(ServerError::new(
+                        format!("Invalid value for argument {}", reason),
+                        Some(self.item.pos),
+                    ));
+                }
+                return InputType::parse(Some(value.clone()))
+                    .map(|value| (self.item.pos, value))
+                    .map_err(|e| e.into_server_error(self.item.pos));
+            }
+        }
         self.get_param_value(&self.item.node.arguments, name, default)
     }
 
+    fn context_argument_default(&self, name: &str) -> Option<&'a Value> {
+        self.data_opt::<ArgumentDefaults>()?
+            .get(self.parent_type?, &self.item.node.name.node, name)
+    }
+
+    /// Returns where the value of the argument `name` of the current field
+    /// comes from.
+    ///
+    /// Arguments present in the query are provided by the client, even if
+    /// they reference a variable. Omitted arguments with a value in the
+    /// [`ArgumentDefaults`] of the context use the context default, other
+    /// omitted arguments use the default declared in the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn items(
+    ///         &self,
+    ///         ctx: &Context<'_>,
+    ///         #[graphql(default = 10)] limit: i32,
+    ///     ) -> String {
+    ///         format!("{:?}", ctx.argument_provenance("limit"))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let res = schema.execute("{ a: items b: items(limit: 3) }").await;
+    /// assert_eq!(res.data, value!({ "a": "Default", "b": "Client" }));
+    /// # });
+    /// ```
+    pub fn argument_provenance(&self, name: &str) -> ArgumentProvenance {
+        if self.item.node.get_argument(name).is_some() {
+            ArgumentProvenance::Client
+        } else if self.context_argument_default(name).is_some() {
+            ArgumentProvenance::ContextDefault
+        } else {
+            ArgumentProvenance::Default
+        }
+    }
+
     #[doc(hidden)]
     pub fn oneof_param_value<T: OneofObjectType>(&self) -> ServerResult<(Pos, T)> {
         use indexmap::IndexMap;
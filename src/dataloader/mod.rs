@@ -69,6 +69,7 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc, Mutex,
     },
+    task::Poll,
     time::Duration,
 };
 
@@ -82,6 +83,8 @@ use tracing::{info_span, instrument, Instrument};
 #[cfg(feature = "tracing")]
 use tracinglib as tracing;
 
+use crate::{extensions::ExecutionStats, Context};
+
 #[allow(clippy::type_complexity)]
 struct ResSender<K: Send + Sync + Hash + Eq + Clone + 'static, T: Loader<K>> {
     use_cache_values: HashMap<K, T::Value>,
@@ -188,6 +191,22 @@ impl<T> DataLoaderInner<T> {
     }
 }
 
+/// Yields once to the executor, so the other tasks of the current tick can
+/// request their keys.
+async fn yield_now() {
+    let mut yielded = false;
+    futures_util::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
 /// Data loader.
 ///
 /// Reference: <https://github.com/facebook/dataloader>
@@ -244,6 +263,10 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
     }
 
     /// Specify the delay time for loading data, the default is `1ms`.
+    ///
+    /// The keys requested during the delay are loaded in the same batch, a
+    /// longer delay makes larger batches at the cost of latency. With a delay
+    /// of zero the batch is loaded on the next tick.
     #[must_use]
     pub fn delay(self, delay: Duration) -> Self {
         Self { delay, ..self }
@@ -301,6 +324,10 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
     }
 
     /// Use this `DataLoader` to load some data.
+    ///
+    /// The keys requested by all the calls to [`load_one`](Self::load_one)
+    /// and `load_many` during the same delay are loaded together, and each
+    /// key is passed to [`Loader::load`] only once.
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     pub async fn load_many<K, I>(&self, keys: I) -> Result<HashMap<K, T::Value>, T::Error>
     where
@@ -340,6 +367,7 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
                 }
             }
 
+            ExecutionStats::record_cache_hits(use_cache_values.len());
             if !use_cache_values.is_empty() && keys_set.is_empty() {
                 return Ok(use_cache_values);
             } else if use_cache_values.is_empty() && keys_set.is_empty() {
@@ -370,6 +398,10 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
             }
         };
 
+        if !matches!(action, Action::Delay) {
+            ExecutionStats::record_dataloader_batch();
+        }
+
         match action {
             Action::ImmediateLoad(keys) => {
                 let inner = self.inner.clone();
@@ -388,7 +420,11 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
                 let delay = self.delay;
 
                 let task = async move {
-                    Delay::new(delay).await;
+                    if delay.is_zero() {
+                        yield_now().await;
+                    } else {
+                        Delay::new(delay).await;
+                    }
 
                     let keys = {
                         let mut request = inner.requests.lock().unwrap();
@@ -414,6 +450,89 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
         rx.await.unwrap()
     }
 
+    /// Use this `DataLoader` to load some data if the child field `field` is
+    /// selected in the current field, including selections made in
+    /// fragments.
+    ///
+    /// A resolver returning a list can call it with the keys of the list
+    /// items, so the resolvers of the child field get the values from the
+    /// cache instead of loading them again. Nothing is loaded if the child
+    /// field is not selected.
+    ///
+    /// **NOTE: If the cache type is [NoCache], the loaded values are not
+    /// kept. **
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashMap, convert::Infallible};
+    ///
+    /// use async_graphql::{dataloader::*, *};
+    ///
+    /// struct NameLoader;
+    ///
+    /// #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    /// impl Loader<i32> for NameLoader {
+    ///     type Value = String;
+    ///     type Error = Infallible;
+    ///
+    ///     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+    ///         Ok(keys.iter().map(|id| (*id, format!("user{}", id))).collect())
+    ///     }
+    /// }
+    ///
+    /// struct User(i32);
+    ///
+    /// #[Object]
+    /// impl User {
+    ///     async fn name(&self, ctx: &Context<'_>) -> Option<String> {
+    ///         let loader = ctx.data_unchecked::<DataLoader<NameLoader, HashMapCache>>();
+    ///         loader.load_one(self.0).await.unwrap()
+    ///     }
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn users(&self, ctx: &Context<'_>) -> Vec<User> {
+    ///         let ids = vec![1, 2, 3];
+    ///         let loader = ctx.data_unchecked::<DataLoader<NameLoader, HashMapCache>>();
+    ///         loader.prime_selected(ctx, "name", ids.clone()).await.unwrap();
+    ///         ids.into_iter().map(User).collect()
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let loader = DataLoader::with_cache(NameLoader, tokio::spawn, HashMapCache::default());
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .data(loader)
+    ///     .finish();
+    /// let res = schema.execute("{ users { name } }").await.into_result().unwrap();
+    /// assert_eq!(
+    ///     res.data,
+    ///     value!({ "users": [{ "name": "user1" }, { "name": "user2" }, { "name": "user3" }] })
+    /// );
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub async fn prime_selected<K, I>(
+        &self,
+        ctx: &Context<'_>,
+        field: &str,
+        keys: I,
+    ) -> Result<(), T::Error>
+    where
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+        I: IntoIterator<Item = K>,
+        T: Loader<K>,
+    {
+        if ctx.look_ahead().field(field).exists() {
+            self.load_many(keys).await?;
+        }
+        Ok(())
+    }
+
     /// Feed some data into the cache.
     ///
     /// **NOTE: If the cache type is [NoCache], this function will not take
@@ -575,6 +694,74 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_overlapping_keys() {
+        struct CountLoader(Mutex<Vec<Vec<i32>>>);
+
+        #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+        impl Loader<i32> for CountLoader {
+            type Value = i32;
+            type Error = ();
+
+            async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                let mut keys = keys.to_vec();
+                keys.sort_unstable();
+                self.0.lock().unwrap().push(keys.clone());
+                Ok(keys.into_iter().map(|k| (k, k)).collect())
+            }
+        }
+
+        let loader = DataLoader::new(CountLoader(Default::default()), tokio::spawn);
+        let (many, one2, one3, one4) = futures_util::future::try_join4(
+            loader.load_many([1, 2, 3]),
+            loader.load_one(2),
+            loader.load_one(3),
+            loader.load_one(4),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(many, [(1, 1), (2, 2), (3, 3)].into_iter().collect());
+        assert_eq!((one2, one3, one4), (Some(2), Some(3), Some(4)));
+        assert_eq!(*loader.loader().0.lock().unwrap(), vec![vec![1, 2, 3, 4]]);
+    }
+
+    #[tokio::test]
+    async fn test_dataloader_delay() {
+        struct BatchLoader(Mutex<Vec<usize>>);
+
+        #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+        impl Loader<i32> for BatchLoader {
+            type Value = i32;
+            type Error = ();
+
+            async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                self.0.lock().unwrap().push(keys.len());
+                Ok(keys.iter().copied().map(|k| (k, k)).collect())
+            }
+        }
+
+        async fn load_batches(delay: Duration) -> Vec<usize> {
+            let loader = Arc::new(
+                DataLoader::new(BatchLoader(Default::default()), tokio::spawn).delay(delay),
+            );
+            futures_util::future::try_join_all((0..5).map(|n| {
+                let loader = loader.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(n as u64 * 10)).await;
+                    loader.load_one(n).await
+                }
+            }))
+            .await
+            .unwrap();
+            let batches = loader.loader().0.lock().unwrap().clone();
+            batches
+        }
+
+        assert_eq!(load_batches(Duration::from_millis(200)).await, vec![5]);
+        assert!(load_batches(Duration::ZERO).await.len() > 1);
+    }
+
     #[tokio::test]
     async fn test_dataloader_load_empty() {
         let loader = DataLoader::new(MyLoader, tokio::spawn);
@@ -717,4 +904,96 @@ mod tests {
         handle.abort();
         loader.load_many(vec![4, 5, 6]).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_dataloader_prime_selected() {
+        use std::sync::atomic::AtomicUsize;
+
+        use crate::{value, EmptyMutation, EmptySubscription, Object, Request, Schema};
+
+        struct CountLoader(Arc<AtomicUsize>);
+
+        #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+        impl Loader<i32> for CountLoader {
+            type Value = i32;
+            type Error = ();
+
+            async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(keys.iter().copied().map(|k| (k, k * 10)).collect())
+            }
+        }
+
+        type CountDataLoader = DataLoader<CountLoader, HashMapCache>;
+
+        struct Item(i32);
+
+        #[Object(internal)]
+        impl Item {
+            async fn id(&self) -> i32 {
+                self.0
+            }
+
+            async fn value(&self, ctx: &Context<'_>) -> Option<i32> {
+                // Resolve the items one after another, so they are not batched
+                // by the loader.
+                tokio::time::sleep(Duration::from_millis(10 * self.0 as u64)).await;
+                let loader = ctx.data_unchecked::<CountDataLoader>();
+                loader.load_one(self.0).await.unwrap()
+            }
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn items(&self, ctx: &Context<'_>) -> Vec<Item> {
+                let ids = vec![1, 2, 3];
+                let loader = ctx.data_unchecked::<CountDataLoader>();
+                loader
+                    .prime_selected(ctx, "value", ids.clone())
+                    .await
+                    .unwrap();
+                ids.into_iter().map(Item).collect()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let execute = |query: &'static str| {
+            let count = Arc::new(AtomicUsize::new(0));
+            let loader = DataLoader::with_cache(
+                CountLoader(count.clone()),
+                tokio::spawn,
+                HashMapCache::default(),
+            );
+            let schema = schema.clone();
+            async move {
+                let data = schema
+                    .execute(Request::new(query).data(loader))
+                    .await
+                    .into_result()
+                    .unwrap()
+                    .data;
+                (data, count.load(Ordering::SeqCst))
+            }
+        };
+
+        let items = value!({ "items": [{ "value": 10 }, { "value": 20 }, { "value": 30 }] });
+        assert_eq!(execute("{ items { value } }").await, (items.clone(), 1));
+        assert_eq!(
+            execute("{ items { ...F } } fragment F on Item { value }").await,
+            (items.clone(), 1)
+        );
+        assert_eq!(
+            execute("{ items { ... on Item { value } } }").await,
+            (items, 1)
+        );
+        assert_eq!(
+            execute("{ items { id } }").await,
+            (
+                value!({ "items": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] }),
+                0
+            )
+        );
+    }
 }
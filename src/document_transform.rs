@@ -0,0 +1,126 @@
+use crate::{
+    parser::types::{
+        DocumentOperations, ExecutableDocument, Field, FragmentDefinition, FragmentSpread,
+        InlineFragment, OperationDefinition, Selection, SelectionSet,
+    },
+    Name, Positioned,
+};
+
+/// A visitor rewriting the parsed document of every request, see
+/// [`SchemaBuilder::add_document_visitor`](crate::SchemaBuilder::add_document_visitor).
+///
+/// The document is walked from the operations and the fragment definitions
+/// down to the fields, each method is called before the children of its node
+/// are visited, so the nodes it adds are visited too.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{parser::types::Field, *};
+///
+/// struct RenameField;
+///
+/// impl DocumentVisitor for RenameField {
+///     fn enter_field(&self, field: &mut Positioned<Field>) {
+///         if field.node.name.node == "a" {
+///             field.node.name.node = Name::new("b");
+///         }
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn a(&self) -> i32 {
+///         1
+///     }
+///
+///     async fn b(&self) -> i32 {
+///         2
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .add_document_visitor(RenameField)
+///     .finish();
+/// let res = schema.execute("{ a }").await.into_result().unwrap().data;
+/// assert_eq!(res, value!({ "b": 2 }));
+/// # });
+/// ```
+pub trait DocumentVisitor: Send + Sync + 'static {
+    /// Called for every operation, with its name if the document has several
+    /// operations.
+    fn enter_operation_definition(
+        &self,
+        _name: Option<&Name>,
+        _operation: &mut Positioned<OperationDefinition>,
+    ) {
+    }
+
+    /// Called for every fragment definition.
+    fn enter_fragment_definition(
+        &self,
+        _name: &Name,
+        _fragment: &mut Positioned<FragmentDefinition>,
+    ) {
+    }
+
+    /// Called for every selection set.
+    fn enter_selection_set(&self, _selection_set: &mut Positioned<SelectionSet>) {}
+
+    /// Called for every field.
+    fn enter_field(&self, _field: &mut Positioned<Field>) {}
+
+    /// Called for every fragment spread.
+    fn enter_fragment_spread(&self, _fragment_spread: &mut Positioned<FragmentSpread>) {}
+
+    /// Called for every inline fragment.
+    fn enter_inline_fragment(&self, _inline_fragment: &mut Positioned<InlineFragment>) {}
+}
+
+pub(crate) fn visit_document<V: DocumentVisitor + ?Sized>(
+    visitor: &V,
+    doc: &mut ExecutableDocument,
+) {
+    match &mut doc.operations {
+        DocumentOperations::Single(operation) => {
+            visitor.enter_operation_definition(None, operation);
+            visit_selection_set(visitor, &mut operation.node.selection_set);
+        }
+        DocumentOperations::Multiple(operations) => {
+            for (name, operation) in operations.iter_mut() {
+                visitor.enter_operation_definition(Some(name), operation);
+                visit_selection_set(visitor, &mut operation.node.selection_set);
+            }
+        }
+    }
+
+    for (name, fragment) in doc.fragments.iter_mut() {
+        visitor.enter_fragment_definition(name, fragment);
+        visit_selection_set(visitor, &mut fragment.node.selection_set);
+    }
+}
+
+fn visit_selection_set<V: DocumentVisitor + ?Sized>(
+    visitor: &V,
+    selection_set: &mut Positioned<SelectionSet>,
+) {
+    visitor.enter_selection_set(selection_set);
+    for selection in &mut selection_set.node.items {
+        match &mut selection.node {
+            Selection::Field(field) => {
+                visitor.enter_field(field);
+                visit_selection_set(visitor, &mut field.node.selection_set);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                visitor.enter_fragment_spread(fragment_spread);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                visitor.enter_inline_fragment(inline_fragment);
+                visit_selection_set(visitor, &mut inline_fragment.node.selection_set);
+            }
+        }
+    }
+}
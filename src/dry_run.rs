@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use futures_util::{future::BoxFuture, FutureExt, TryFutureExt};
+
+use crate::{
+    parser::types::{Field, OperationType, Selection, SelectionSet},
+    registry::{MetaType, MetaTypeName},
+    resolver_utils::{apply_custom_directives, create_value_object},
+    types::resolve_introspection_field,
+    Context, ContextSelectionSet, Name, Positioned, ServerError, ServerResult, Value,
+};
+
+/// Walks the selection set of an operation with the types of the registry,
+/// and returns a response of the same shape with placeholder values, without
+/// calling the resolvers.
+///
+/// A list has a single item. A scalar is `0`, `0.0`, `false` or `""` if it
+/// is a built-in scalar, the value registered with
+/// [`SchemaBuilder::dry_run_placeholder`](crate::SchemaBuilder::dry_run_placeholder)
+/// for a custom scalar, or `null` if none is registered and the scalar is
+/// nullable. An enum is its first value, and an interface or a union is its
+/// first possible type.
+///
+/// The skipped fields are left out like in a real request, the custom
+/// directives applied on a field are called with its placeholder, and the
+/// `__schema` and `__type` fields are resolved from the schema.
+pub(crate) struct DryRun<'a> {
+    pub(crate) placeholders: &'a HashMap<String, Value>,
+}
+
+impl DryRun<'_> {
+    pub(crate) async fn execute(&self, ctx: &ContextSelectionSet<'_>) -> ServerResult<Value> {
+        let registry = &ctx.schema_env.registry;
+        let type_name = match ctx.query_env.operation.node.ty {
+            OperationType::Query => &registry.query_type,
+            OperationType::Mutation => match &registry.mutation_type {
+                Some(type_name) => type_name,
+                None => return Ok(Value::Null),
+            },
+            OperationType::Subscription => {
+                return Err(ServerError::new(
+                    "Subscriptions are not supported on this transport.",
+                    None,
+                ))
+            }
+        };
+        self.resolve_selection_set(ctx, type_name).await
+    }
+
+    fn resolve_selection_set<'b>(
+        &'b self,
+        ctx: &'b ContextSelectionSet<'b>,
+        type_name: &'b str,
+    ) -> BoxFuture<'b, ServerResult<Value>> {
+        async move {
+            let mut fields = Vec::new();
+            self.collect_fields(ctx, type_name, &ctx.item.node, &mut fields);
+            let mut values = Vec::new();
+            for field in fields {
+                let value = self
+                    .resolve_field(&ctx.with_field(field), type_name)
+                    .await?;
+                values.push((field.node.response_key().node.clone(), value));
+            }
+            Ok(create_value_object(values))
+        }
+        .boxed()
+    }
+
+    fn collect_fields<'b>(
+        &self,
+        ctx: &ContextSelectionSet<'b>,
+        type_name: &str,
+        selection_set: &'b SelectionSet,
+        fields: &mut Vec<&'b Positioned<Field>>,
+    ) {
+        for selection in &selection_set.items {
+            match &selection.node {
+                Selection::Field(field) => fields.push(field),
+                Selection::FragmentSpread(spread) => {
+                    if let Some(fragment) =
+                        ctx.query_env.fragments.get(&spread.node.fragment_name.node)
+                    {
+                        if self.applies(ctx, type_name, &fragment.node.type_condition.node.on.node)
+                        {
+                            self.collect_fields(
+                                ctx,
+                                type_name,
+                                &fragment.node.selection_set.node,
+                                fields,
+                            );
+                        }
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    let applies = fragment
+                        .node
+                        .type_condition
+                        .as_ref()
+                        .is_none_or(|condition| {
+                            self.applies(ctx, type_name, &condition.node.on.node)
+                        });
+                    if applies {
+                        self.collect_fields(
+                            ctx,
+                            type_name,
+                            &fragment.node.selection_set.node,
+                            fields,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves a field to its placeholder, through the custom directives
+    /// applied on it. The `__schema` and `__type` fields of the query root are
+    /// resolved like in a real request.
+    async fn resolve_field(&self, ctx: &Context<'_>, type_name: &str) -> ServerResult<Value> {
+        let registry = &ctx.schema_env.registry;
+        if ctx.item.node.name.node == "__typename" {
+            return Ok(Value::String(type_name.to_string()));
+        }
+        if type_name == registry.query_type {
+            if let Some(value) = resolve_introspection_field(ctx).await? {
+                return Ok(value);
+            }
+        }
+
+        // The operation has been validated, so the field exists.
+        let meta_field = registry.types[type_name]
+            .field_by_name(&ctx.item.node.name.node)
+            .expect("the operation has been validated");
+        let resolve_fut = self.resolve_type(ctx, &meta_field.ty).map_ok(Some).boxed();
+        Ok(apply_custom_directives(ctx, resolve_fut)?
+            .await?
+            .unwrap_or_default())
+    }
+
+    fn applies(&self, ctx: &ContextSelectionSet<'_>, type_name: &str, condition: &str) -> bool {
+        ctx.schema_env
+            .registry
+            .types
+            .get(condition)
+            .is_some_and(|ty| ty.is_possible_type(type_name))
+    }
+
+    fn resolve_type<'b>(
+        &'b self,
+        ctx: &'b Context<'b>,
+        ty: &'b str,
+    ) -> BoxFuture<'b, ServerResult<Value>> {
+        async move {
+            match MetaTypeName::create(ty) {
+                MetaTypeName::List(ty) => Ok(Value::List(vec![self.resolve_type(ctx, ty).await?])),
+                MetaTypeName::NonNull(ty) => match self.resolve_type(ctx, ty).await? {
+                    Value::Null => Err(ServerError::new(
+                        format!(
+                            r#"No placeholder for the scalar "{}" in a dry run."#,
+                            MetaTypeName::concrete_typename(ty)
+                        ),
+                        Some(ctx.item.pos),
+                    )),
+                    value => Ok(value),
+                },
+                MetaTypeName::Named(type_name) => {
+                    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                    match &ctx.schema_env.registry.types[type_name] {
+                        MetaType::Scalar { name, .. } => Ok(self.scalar_placeholder(name)),
+                        MetaType::Enum { enum_values, .. } => Ok(enum_values
+                            .keys()
+                            .next()
+                            .map(|value| Value::Enum(Name::new(value)))
+                            .unwrap_or_default()),
+                        MetaType::Object { .. } => {
+                            self.resolve_selection_set(&ctx_obj, type_name).await
+                        }
+                        MetaType::Interface { possible_types, .. }
+                        | MetaType::Union { possible_types, .. } => match possible_types.first() {
+                            Some(type_name) => {
+                                self.resolve_selection_set(&ctx_obj, type_name).await
+                            }
+                            None => Ok(Value::Null),
+                        },
+                        MetaType::InputObject { .. } => Ok(Value::Null),
+                    }
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn scalar_placeholder(&self, name: &str) -> Value {
+        if let Some(value) = self.placeholders.get(name) {
+            return value.clone();
+        }
+        match name {
+            "Int" => Value::from(0),
+            "Float" => Value::from(0.0),
+            "Boolean" => Value::Boolean(false),
+            "String" | "ID" => Value::String(String::new()),
+            _ => Value::Null,
+        }
+    }
+}
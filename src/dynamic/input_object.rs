@@ -149,7 +149,7 @@ impl InputObject {
 
 #[cfg(test)]
 mod tests {
-    use crate::{dynamic::*, value, Pos, ServerError, Value};
+    use crate::{dynamic::*, value, ErrorCode, Pos, ServerError, Value};
 
     #[tokio::test]
     async fn input_object() {
@@ -254,13 +254,11 @@ mod tests {
                 .await
                 .into_result()
                 .unwrap_err(),
-            vec![ServerError {
-                message: "Invalid value for argument \"input\", Oneof input objects requires have exactly one field".to_owned(),
-                source: None,
-                locations: vec![Pos { column: 9, line: 1 }],
-                path: vec![],
-                extensions: None,
-            }]
+            vec![ServerError::new(
+                "Invalid value for argument \"input\", Oneof input objects requires have exactly one field",
+                Some(Pos { column: 9, line: 1 })
+            )
+            .with_code(ErrorCode::ValidationFailed)]
         );
 
         assert_eq!(
@@ -269,13 +267,11 @@ mod tests {
                 .await
                 .into_result()
                 .unwrap_err(),
-            vec![ServerError {
-                message: "Invalid value for argument \"input\", Oneof input objects requires have exactly one field".to_owned(),
-                source: None,
-                locations: vec![Pos { column: 9, line: 1 }],
-                path: vec![],
-                extensions: None,
-            }]
+            vec![ServerError::new(
+                "Invalid value for argument \"input\", Oneof input objects requires have exactly one field",
+                Some(Pos { column: 9, line: 1 })
+            )
+            .with_code(ErrorCode::ValidationFailed)]
         );
     }
 
@@ -1,8 +1,13 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use indexmap::{IndexMap, IndexSet};
 
 use super::{directive::to_meta_directive_invocation, Directive};
 use crate::{
-    dynamic::{InputValue, SchemaError, TypeRef},
+    dynamic::{FieldValue, InputValue, SchemaError, TypeRef},
     registry::{Deprecation, MetaField, MetaType, Registry},
 };
 
@@ -138,6 +143,19 @@ impl InterfaceField {
     }
 }
 
+type TypeResolverFn = dyn Fn(&FieldValue<'_>) -> Option<String> + Send + Sync;
+
+/// A resolver for the concrete object type of a value of an interface or
+/// union.
+#[derive(Clone)]
+pub(crate) struct TypeResolver(pub(crate) Arc<TypeResolverFn>);
+
+impl Debug for TypeResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypeResolver")
+    }
+}
+
 /// A GraphQL interface type
 #[derive(Debug)]
 pub struct Interface {
@@ -145,6 +163,7 @@ pub struct Interface {
     pub(crate) description: Option<String>,
     pub(crate) fields: IndexMap<String, InterfaceField>,
     pub(crate) implements: IndexSet<String>,
+    pub(crate) type_resolver: Option<TypeResolver>,
     keys: Vec<String>,
     extends: bool,
     inaccessible: bool,
@@ -161,6 +180,7 @@ impl Interface {
             description: None,
             fields: Default::default(),
             implements: Default::default(),
+            type_resolver: None,
             keys: Vec::new(),
             extends: false,
             inaccessible: false,
@@ -200,6 +220,85 @@ impl Interface {
         self
     }
 
+    /// Set a resolver for the object type of the values of this interface
+    ///
+    /// The resolver is used for the values not created with
+    /// [`FieldValue::with_type`], and returns the name of the object type.
+    /// Returning `None` or a name of an object that does not implement this
+    /// interface is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::{dynamic::*, value, Value};
+    ///
+    /// let obj_a = Object::new("MyObjA")
+    ///     .implement("MyInterface")
+    ///     .field(Field::new("a", TypeRef::named_nn(TypeRef::INT), |ctx| {
+    ///         FieldFuture::new(async move {
+    ///             Ok(Some(ctx.parent_value.try_to_value()?.clone()))
+    ///         })
+    ///     }));
+    ///
+    /// let obj_b = Object::new("MyObjB")
+    ///     .implement("MyInterface")
+    ///     .field(Field::new("a", TypeRef::named_nn(TypeRef::INT), |ctx| {
+    ///         FieldFuture::new(async move {
+    ///             Ok(Some(ctx.parent_value.try_to_value()?.clone()))
+    ///         })
+    ///     }));
+    ///
+    /// let interface = Interface::new("MyInterface")
+    ///     .field(InterfaceField::new("a", TypeRef::named_nn(TypeRef::INT)))
+    ///     .type_resolver(|value| match value.as_value()? {
+    ///         Value::Number(n) if n.as_i64()? % 2 == 0 => Some("MyObjA".to_string()),
+    ///         _ => Some("MyObjB".to_string()),
+    ///     });
+    ///
+    /// let query = Object::new("Query")
+    ///     .field(Field::new("values", TypeRef::named_nn_list_nn(interface.type_name()), |_| {
+    ///         FieldFuture::new(async {
+    ///             Ok(Some(FieldValue::list([1, 2].into_iter().map(FieldValue::value))))
+    ///         })
+    ///     }));
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    ///
+    /// let schema = Schema::build(query.type_name(), None, None)
+    ///     .register(obj_a)
+    ///     .register(obj_b)
+    ///     .register(interface)
+    ///     .register(query)
+    ///     .finish()?;
+    ///
+    /// assert_eq!(
+    ///    schema
+    ///        .execute("{ values { __typename a } }")
+    ///        .await
+    ///        .into_result()
+    ///        .unwrap()
+    ///        .data,
+    ///    value!({
+    ///        "values": [
+    ///            { "__typename": "MyObjB", "a": 1 },
+    ///            { "__typename": "MyObjA", "a": 2 },
+    ///        ]
+    ///    })
+    /// );
+    ///
+    /// # Ok::<_, SchemaError>(())
+    /// # }).unwrap();
+    /// ```
+    pub fn type_resolver(
+        self,
+        resolver: impl Fn(&FieldValue<'_>) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            type_resolver: Some(TypeResolver(Arc::new(resolver))),
+            ..self
+        }
+    }
+
     /// Add an entity key
     ///
     /// See also: [`Object::key`](crate::dynamic::Object::key)
@@ -246,7 +345,11 @@ impl Interface {
                     inaccessible: field.inaccessible,
                     tags: field.tags.clone(),
                     override_from: field.override_from.clone(),
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                 },
             );
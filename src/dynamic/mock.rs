@@ -0,0 +1,826 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql_parser::{
+    parse_schema,
+    types::{
+        BaseType, ConstDirective, FieldDefinition, InputValueDefinition, Type as ParsedType,
+        TypeDefinition, TypeKind, TypeSystemDefinition,
+    },
+    Positioned,
+};
+use futures_util::stream;
+use indexmap::IndexMap;
+
+use crate::{
+    dynamic::{
+        Enum, EnumItem, Field, FieldFuture, FieldValue, InputObject, InputValue, Interface,
+        InterfaceField, Object, Scalar, Schema, SchemaError, Subscription, SubscriptionField,
+        SubscriptionFieldFuture, TypeRef, Union,
+    },
+    Error, Value,
+};
+
+type MockFn = Arc<dyn Fn() -> Value + Send + Sync>;
+
+/// A builder for creating an executable [`Schema`] from SDL, where every
+/// field is resolved with a mock value.
+///
+/// The mock values are deterministic and depend only on the type of the
+/// field:
+///
+/// | Type       | Mock value                          |
+/// |------------|-------------------------------------|
+/// | `Int`      | `42`                                |
+/// | `Float`    | `4.2`                               |
+/// | `String`   | `"Hello World"`                     |
+/// | `Boolean`  | `true`                              |
+/// | `ID`       | `"1"`                               |
+/// | Enum       | The first enum value                |
+/// | Interface  | The first object implementing it    |
+/// | Union      | The first possible type             |
+/// | List       | `list_length` mock items            |
+///
+/// Custom scalars have no sensible default, so a mock generator must be
+/// registered for each of them with [`MockBuilder::mock_type`]. Generators can
+/// also replace the defaults of the built-in scalars, enums and objects.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::{dynamic::*, value, Value};
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+///
+/// let schema = MockBuilder::new(
+///     r#"
+///     scalar DateTime
+///
+///     type Query {
+///         name: String!
+///         createdAt: DateTime!
+///         tags: [String!]!
+///     }
+///     "#,
+/// )
+/// .list_length(3)
+/// .mock_type("DateTime", || Value::from("2020-01-01T00:00:00Z"))
+/// .finish()?;
+///
+/// assert_eq!(
+///    schema
+///        .execute("{ name createdAt tags }")
+///        .await
+///        .into_result()
+///        .unwrap()
+///        .data,
+///    value!({
+///        "name": "Hello World",
+///        "createdAt": "2020-01-01T00:00:00Z",
+///        "tags": ["Hello World", "Hello World", "Hello World"],
+///    })
+/// );
+///
+/// # Ok::<_, SchemaError>(())
+/// # }).unwrap();
+/// ```
+pub struct MockBuilder {
+    sdl: String,
+    list_length: usize,
+    generators: HashMap<String, MockFn>,
+}
+
+impl MockBuilder {
+    /// Create a mock schema builder from SDL
+    pub fn new(sdl: impl Into<String>) -> Self {
+        Self {
+            sdl: sdl.into(),
+            list_length: 2,
+            generators: Default::default(),
+        }
+    }
+
+    /// Set the number of items returned by list fields, default is `2`.
+    #[must_use]
+    pub fn list_length(self, list_length: usize) -> Self {
+        Self {
+            list_length,
+            ..self
+        }
+    }
+
+    /// Register a mock generator for a scalar, enum or object type.
+    ///
+    /// The generator is called for every value of the type. For objects, the
+    /// generator returns an object value and its entries are used for the
+    /// fields of the same name, other fields keep their mock values.
+    ///
+    /// For list fields, the generator is called once per item, so it is not
+    /// called for empty lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// use async_graphql::{dynamic::*, value, Value};
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    ///
+    /// let counter = AtomicUsize::new(0);
+    /// let schema = MockBuilder::new("scalar Email type Query { emails: [Email!]! }")
+    ///     .mock_type("Email", move || {
+    ///         let n = counter.fetch_add(1, Ordering::Relaxed);
+    ///         Value::from(format!("user{}@example.com", n))
+    ///     })
+    ///     .finish()?;
+    ///
+    /// assert_eq!(
+    ///    schema
+    ///        .execute("{ emails }")
+    ///        .await
+    ///        .into_result()
+    ///        .unwrap()
+    ///        .data,
+    ///    value!({ "emails": ["user0@example.com", "user1@example.com"] })
+    /// );
+    ///
+    /// # Ok::<_, SchemaError>(())
+    /// # }).unwrap();
+    /// ```
+    #[must_use]
+    pub fn mock_type(
+        mut self,
+        name: impl Into<String>,
+        f: impl Fn() -> Value + Send + Sync + 'static,
+    ) -> Self {
+        self.generators.insert(name.into(), Arc::new(f));
+        self
+    }
+
+    /// Build the mock schema.
+    pub fn finish(self) -> Result<Schema, SchemaError> {
+        let doc = parse_schema(&self.sdl).map_err(|err| SchemaError(err.to_string()))?;
+
+        let mut query = None;
+        let mut mutation = None;
+        let mut subscription = None;
+        let mut types: IndexMap<String, TypeDefinition> = IndexMap::new();
+
+        for definition in doc.definitions {
+            match definition {
+                TypeSystemDefinition::Schema(schema) => {
+                    let schema = schema.node;
+                    query = schema.query.map(|name| name.node.to_string()).or(query);
+                    mutation = schema
+                        .mutation
+                        .map(|name| name.node.to_string())
+                        .or(mutation);
+                    subscription = schema
+                        .subscription
+                        .map(|name| name.node.to_string())
+                        .or(subscription);
+                }
+                TypeSystemDefinition::Type(ty) => {
+                    let ty = ty.node;
+                    match types.get_mut(ty.name.node.as_str()) {
+                        // the extension is defined before the type
+                        Some(existing) if existing.extend && !ty.extend => {
+                            let extension = std::mem::replace(existing, ty);
+                            merge_type(existing, extension.kind);
+                        }
+                        Some(existing) => merge_type(existing, ty.kind),
+                        None => {
+                            types.insert(ty.name.node.to_string(), ty);
+                        }
+                    }
+                }
+                TypeSystemDefinition::Directive(_) => {}
+            }
+        }
+
+        let query = query.unwrap_or_else(|| "Query".to_string());
+        let mutation = mutation.or_else(|| {
+            types
+                .contains_key("Mutation")
+                .then(|| "Mutation".to_string())
+        });
+        let subscription = subscription.or_else(|| {
+            types
+                .contains_key("Subscription")
+                .then(|| "Subscription".to_string())
+        });
+
+        let mut kinds: HashMap<String, Mock> = HashMap::new();
+        let builtin_scalars: [(&str, Value); 5] = [
+            (TypeRef::INT, Value::from(42)),
+            (TypeRef::FLOAT, Value::from(4.2)),
+            (TypeRef::STRING, Value::from("Hello World")),
+            (TypeRef::BOOLEAN, Value::from(true)),
+            (TypeRef::ID, Value::from("1")),
+        ];
+        for (name, value) in builtin_scalars {
+            kinds.insert(
+                name.to_string(),
+                Mock::Leaf(Arc::new(move || value.clone())),
+            );
+        }
+        for (name, f) in &self.generators {
+            match types.get(name).map(|ty| &ty.kind) {
+                Some(TypeKind::Scalar | TypeKind::Enum(_) | TypeKind::Object(_)) => {}
+                Some(_) => {
+                    return Err(format!(
+                        "Mock generators are only supported for scalars, enums and objects, but \"{}\" is not",
+                        name
+                    )
+                    .into())
+                }
+                None if kinds.contains_key(name) => {}
+                None => {
+                    return Err(format!("Type \"{}\" of the mock generator not found", name).into())
+                }
+            }
+            kinds.insert(name.clone(), Mock::Leaf(f.clone()));
+        }
+        for (name, ty) in &types {
+            if self.generators.contains_key(name) {
+                continue;
+            }
+            let mock = match &ty.kind {
+                TypeKind::Scalar => {
+                    return Err(
+                        format!("No mock generator registered for the scalar \"{}\"", name).into(),
+                    )
+                }
+                TypeKind::Enum(enum_type) => match enum_type.values.first() {
+                    Some(item) => {
+                        let value = Value::Enum(item.node.value.node.clone());
+                        Mock::Leaf(Arc::new(move || value.clone()))
+                    }
+                    None => Mock::Null,
+                },
+                TypeKind::Object(_) => Mock::Object,
+                TypeKind::Interface(_) => types
+                    .iter()
+                    .find(|(_, ty)| match &ty.kind {
+                        TypeKind::Object(obj) => {
+                            obj.implements.iter().any(|i| i.node.as_str() == name)
+                        }
+                        _ => false,
+                    })
+                    .map(|(object, _)| Mock::Abstract(object.clone()))
+                    .unwrap_or(Mock::Null),
+                TypeKind::Union(union) => union
+                    .members
+                    .first()
+                    .map(|member| Mock::Abstract(member.node.to_string()))
+                    .unwrap_or(Mock::Null),
+                TypeKind::InputObject(_) => continue,
+            };
+            kinds.insert(name.clone(), mock);
+        }
+
+        let ctx = MockContext {
+            kinds,
+            list_length: self.list_length,
+        };
+        let mut builder = Schema::build(&query, mutation.as_deref(), subscription.as_deref());
+
+        for (name, ty) in types {
+            let description = ty.description.map(|description| description.node);
+            builder = match ty.kind {
+                TypeKind::Scalar => {
+                    builder.register(with_description(Scalar::new(name), description))
+                }
+                TypeKind::Object(obj) if Some(&name) == subscription.as_ref() => {
+                    let mut subscription = Subscription::new(name);
+                    for field in obj.fields {
+                        subscription = subscription.field(ctx.subscription_field(field.node));
+                    }
+                    builder.register(with_description(subscription, description))
+                }
+                TypeKind::Object(obj) => {
+                    let mut object = Object::new(name);
+                    for interface in obj.implements {
+                        object = object.implement(interface.node.to_string());
+                    }
+                    for field in obj.fields {
+                        object = object.field(ctx.field(field.node));
+                    }
+                    builder.register(with_description(object, description))
+                }
+                TypeKind::Interface(interface_type) => {
+                    let mut interface = Interface::new(name);
+                    for parent in interface_type.implements {
+                        interface = interface.implement(parent.node.to_string());
+                    }
+                    for field in interface_type.fields {
+                        interface = interface.field(interface_field(field.node));
+                    }
+                    builder.register(with_description(interface, description))
+                }
+                TypeKind::Union(union_type) => {
+                    let mut union = Union::new(name);
+                    for member in union_type.members {
+                        union = union.possible_type(member.node.to_string());
+                    }
+                    builder.register(with_description(union, description))
+                }
+                TypeKind::Enum(enum_type) => {
+                    let mut enum_ = Enum::new(name);
+                    for value in enum_type.values {
+                        let value = value.node;
+                        let mut item = EnumItem::new(value.value.node.to_string());
+                        if let Some(description) = value.description {
+                            item = item.description(description.node);
+                        }
+                        if let Some(reason) = deprecation(&value.directives) {
+                            item = item.deprecation(reason.as_deref());
+                        }
+                        enum_ = enum_.item(item);
+                    }
+                    builder.register(with_description(enum_, description))
+                }
+                TypeKind::InputObject(input_object_type) => {
+                    let mut input_object = InputObject::new(name);
+                    for field in input_object_type.fields {
+                        input_object = input_object.field(input_value(field.node));
+                    }
+                    builder.register(with_description(input_object, description))
+                }
+            };
+        }
+
+        builder.finish()
+    }
+}
+
+impl Schema {
+    /// Create an executable schema from SDL, where every field returns a
+    /// deterministic mock value based on its type.
+    ///
+    /// This is a shortcut for `MockBuilder::new(sdl).finish()`, see
+    /// [`MockBuilder`] for the generated values.
+    pub fn from_sdl_mocked(sdl: impl Into<String>) -> Result<Schema, SchemaError> {
+        MockBuilder::new(sdl).finish()
+    }
+}
+
+#[derive(Clone)]
+enum Mock {
+    Leaf(MockFn),
+    Object,
+    Abstract(String),
+    List(Box<Mock>),
+    Null,
+}
+
+impl Mock {
+    fn generate(&self, list_length: usize) -> Option<FieldValue<'static>> {
+        match self {
+            Mock::Leaf(f) => Some(FieldValue::value(f())),
+            Mock::Object => Some(FieldValue::NULL),
+            Mock::Abstract(ty) => Some(FieldValue::NULL.with_type(ty.clone())),
+            Mock::List(mock) => Some(FieldValue::list(
+                (0..list_length).filter_map(|_| mock.generate(list_length)),
+            )),
+            Mock::Null => None,
+        }
+    }
+}
+
+struct MockContext {
+    kinds: HashMap<String, Mock>,
+    list_length: usize,
+}
+
+impl MockContext {
+    fn mock(&self, ty: &ParsedType) -> Mock {
+        match &ty.base {
+            BaseType::Named(name) => self.kinds.get(name.as_str()).cloned().unwrap_or(Mock::Null),
+            BaseType::List(ty) => Mock::List(Box::new(self.mock(ty))),
+        }
+    }
+
+    fn field(&self, definition: FieldDefinition) -> Field {
+        let mock = self.mock(&definition.ty.node);
+        let list_length = self.list_length;
+        let name = definition.name.node.to_string();
+        let mut field = Field::new(name.clone(), type_ref(&definition.ty.node), move |ctx| {
+            // values of objects returned by a custom generator
+            if let Some(Value::Object(object)) = ctx.parent_value.as_value() {
+                if let Some(value) = object.get(name.as_str()) {
+                    return FieldFuture::Value(Some(FieldValue::value(value.clone())));
+                }
+            }
+            FieldFuture::Value(mock.generate(list_length))
+        });
+        if let Some(description) = definition.description {
+            field = field.description(description.node);
+        }
+        if let Some(reason) = deprecation(&definition.directives) {
+            field = field.deprecation(reason.as_deref());
+        }
+        for argument in definition.arguments {
+            field = field.argument(input_value(argument.node));
+        }
+        field
+    }
+
+    fn subscription_field(&self, definition: FieldDefinition) -> SubscriptionField {
+        let mock = self.mock(&definition.ty.node);
+        let list_length = self.list_length;
+        let mut field = SubscriptionField::new(
+            definition.name.node.to_string(),
+            type_ref(&definition.ty.node),
+            move |_| {
+                let value = mock.generate(list_length);
+                SubscriptionFieldFuture::new(
+                    async move { Ok(stream::iter(value.map(Ok::<_, Error>))) },
+                )
+            },
+        );
+        if let Some(description) = definition.description {
+            field = field.description(description.node);
+        }
+        if let Some(reason) = deprecation(&definition.directives) {
+            field = field.deprecation(reason.as_deref());
+        }
+        for argument in definition.arguments {
+            field = field.argument(input_value(argument.node));
+        }
+        field
+    }
+}
+
+fn merge_type(ty: &mut TypeDefinition, kind: TypeKind) {
+    match (&mut ty.kind, kind) {
+        (TypeKind::Object(ty), TypeKind::Object(ext)) => {
+            ty.implements.extend(ext.implements);
+            ty.fields.extend(ext.fields);
+        }
+        (TypeKind::Interface(ty), TypeKind::Interface(ext)) => {
+            ty.implements.extend(ext.implements);
+            ty.fields.extend(ext.fields);
+        }
+        (TypeKind::Union(ty), TypeKind::Union(ext)) => ty.members.extend(ext.members),
+        (TypeKind::Enum(ty), TypeKind::Enum(ext)) => ty.values.extend(ext.values),
+        (TypeKind::InputObject(ty), TypeKind::InputObject(ext)) => ty.fields.extend(ext.fields),
+        _ => {}
+    }
+}
+
+fn type_ref(ty: &ParsedType) -> TypeRef {
+    let base = match &ty.base {
+        BaseType::Named(name) => TypeRef::named(name.as_str()),
+        BaseType::List(ty) => TypeRef::List(Box::new(type_ref(ty))),
+    };
+    if ty.nullable {
+        base
+    } else {
+        TypeRef::NonNull(Box::new(base))
+    }
+}
+
+fn deprecation(directives: &[Positioned<ConstDirective>]) -> Option<Option<String>> {
+    directives
+        .iter()
+        .find(|directive| directive.node.name.node == "deprecated")
+        .map(|directive| match directive.node.get_argument("reason") {
+            Some(value) => match &value.node {
+                Value::String(reason) => Some(reason.clone()),
+                _ => None,
+            },
+            None => None,
+        })
+}
+
+fn interface_field(definition: FieldDefinition) -> InterfaceField {
+    let mut field = InterfaceField::new(
+        definition.name.node.to_string(),
+        type_ref(&definition.ty.node),
+    );
+    if let Some(description) = definition.description {
+        field = field.description(description.node);
+    }
+    if let Some(reason) = deprecation(&definition.directives) {
+        field = field.deprecation(reason.as_deref());
+    }
+    for argument in definition.arguments {
+        field = field.argument(input_value(argument.node));
+    }
+    field
+}
+
+fn input_value(definition: InputValueDefinition) -> InputValue {
+    let mut input_value = InputValue::new(
+        definition.name.node.to_string(),
+        type_ref(&definition.ty.node),
+    );
+    if let Some(description) = definition.description {
+        input_value = input_value.description(description.node);
+    }
+    if let Some(default_value) = definition.default_value {
+        input_value = input_value.default_value(default_value.node);
+    }
+    input_value
+}
+
+trait Describe {
+    fn describe(self, description: String) -> Self;
+}
+
+macro_rules! impl_describe {
+    ($($ty:ty),*) => {
+        $(
+            impl Describe for $ty {
+                fn describe(self, description: String) -> Self {
+                    self.description(description)
+                }
+            }
+        )*
+    };
+}
+
+impl_describe!(
+    Scalar,
+    Object,
+    Subscription,
+    Interface,
+    Union,
+    Enum,
+    InputObject
+);
+
+fn with_description<T: Describe>(ty: T, description: Option<String>) -> T {
+    match description {
+        Some(description) => ty.describe(description),
+        None => ty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use futures_util::StreamExt;
+
+    use crate::{dynamic::*, value, Value};
+
+    const SDL: &str = r#"
+        scalar DateTime
+
+        enum Color {
+            RED
+            GREEN
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type User implements Node {
+            id: ID!
+            name: String!
+            age: Int
+            score: Float!
+            active: Boolean!
+            color: Color!
+            createdAt: DateTime!
+            friends: [User!]!
+        }
+
+        union SearchResult = User
+
+        type Query {
+            user(id: ID!): User
+            node: Node!
+            search(text: String = "abc"): [SearchResult!]!
+        }
+
+        type Subscription {
+            users: [User!]!
+        }
+    "#;
+
+    #[tokio::test]
+    async fn mock_schema() {
+        let schema = MockBuilder::new(SDL)
+            .mock_type("DateTime", || Value::from("2020-01-01T00:00:00Z"))
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{
+                        user(id: "10") {
+                            id name age score active color createdAt
+                            friends { name }
+                        }
+                        node { __typename id }
+                        search { __typename ... on User { name } }
+                    }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "user": {
+                    "id": "1",
+                    "name": "Hello World",
+                    "age": 42,
+                    "score": 4.2,
+                    "active": true,
+                    "color": "RED",
+                    "createdAt": "2020-01-01T00:00:00Z",
+                    "friends": [{ "name": "Hello World" }, { "name": "Hello World" }],
+                },
+                "node": { "__typename": "User", "id": "1" },
+                "search": [
+                    { "__typename": "User", "name": "Hello World" },
+                    { "__typename": "User", "name": "Hello World" },
+                ],
+            })
+        );
+
+        let mut stream = schema.execute_stream("subscription { users { age } }");
+        assert_eq!(
+            stream.next().await.unwrap().into_result().unwrap().data,
+            value!({ "users": [{ "age": 42 }, { "age": 42 }] })
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn mock_list_length() {
+        let schema = MockBuilder::new("type Query { values: [[Int!]!]! }")
+            .list_length(3)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ values }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "values": [[42, 42, 42], [42, 42, 42], [42, 42, 42]] })
+        );
+
+        let schema = MockBuilder::new("type Query { values: [Int!]! }")
+            .list_length(0)
+            .finish()
+            .unwrap();
+        assert_eq!(
+            schema
+                .execute("{ values }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "values": [] })
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_type_extensions() {
+        let schema = MockBuilder::new(
+            r#"
+            extend type Query { b: Int! }
+            type Query { a: String! }
+            extend type Query { c: Boolean! }
+            "#,
+        )
+        .finish()
+        .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ a b c }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "a": "Hello World", "b": 42, "c": true })
+        );
+    }
+
+    #[test]
+    fn mock_custom_scalar_without_generator() {
+        assert_eq!(
+            Schema::from_sdl_mocked(SDL).unwrap_err(),
+            SchemaError("No mock generator registered for the scalar \"DateTime\"".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_custom_generators() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let schema = MockBuilder::new(
+            r#"
+            scalar Email
+
+            enum Color { RED GREEN }
+
+            type User {
+                name: String!
+                email: Email!
+                color: Color!
+                scores: [Int!]!
+            }
+
+            type Query {
+                user: User!
+                emails: [Email!]!
+            }
+            "#,
+        )
+        .mock_type("Email", {
+            let counter = counter.clone();
+            move || {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                Value::from(format!("user{}@example.com", n))
+            }
+        })
+        .mock_type("Int", {
+            let counter = AtomicUsize::new(1);
+            move || Value::from(counter.fetch_add(1, Ordering::SeqCst))
+        })
+        .mock_type("Color", || Value::from("GREEN"))
+        .mock_type("User", || value!({ "name": "Sunli" }))
+        .list_length(3)
+        .finish()
+        .unwrap();
+
+        counter.store(0, Ordering::SeqCst);
+        assert_eq!(
+            schema
+                .execute("{ user { name email color scores } emails }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "user": {
+                    "name": "Sunli",
+                    "email": "user0@example.com",
+                    "color": "GREEN",
+                    "scores": [1, 2, 3],
+                },
+                "emails": ["user1@example.com", "user2@example.com", "user3@example.com"],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_generator_empty_list() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let schema = MockBuilder::new("type Query { values: [Int!]! }")
+            .mock_type("Int", {
+                let calls = calls.clone();
+                move || {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Value::from(1)
+                }
+            })
+            .list_length(0)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ values }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "values": [] })
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn mock_invalid_generators() {
+        assert_eq!(
+            MockBuilder::new("type Query { value: Int }")
+                .mock_type("Email", || Value::from("a@b.c"))
+                .finish()
+                .unwrap_err(),
+            SchemaError("Type \"Email\" of the mock generator not found".to_string())
+        );
+        assert_eq!(
+            MockBuilder::new("union U = Query type Query { value: U }")
+                .mock_type("U", Value::default)
+                .finish()
+                .unwrap_err(),
+            SchemaError(
+                "Mock generators are only supported for scalars, enums and objects, but \"U\" is not".to_string()
+            )
+        );
+    }
+}
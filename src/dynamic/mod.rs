@@ -41,6 +41,7 @@ mod field;
 mod input_object;
 mod input_value;
 mod interface;
+mod mock;
 mod object;
 mod request;
 mod resolve;
@@ -59,6 +60,7 @@ pub use indexmap;
 pub use input_object::InputObject;
 pub use input_value::InputValue;
 pub use interface::{Interface, InterfaceField};
+pub use mock::MockBuilder;
 pub use object::Object;
 pub use r#enum::{Enum, EnumItem};
 pub use r#type::Type;
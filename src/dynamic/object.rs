@@ -187,7 +187,11 @@ impl Object {
                     inaccessible: field.inaccessible,
                     tags: field.tags.clone(),
                     override_from: field.override_from.clone(),
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                 },
             );
@@ -7,8 +7,8 @@ use indexmap::IndexMap;
 
 use crate::{
     dynamic::{
-        field::FieldValueInner, FieldFuture, FieldValue, Object, ObjectAccessor, ResolverContext,
-        Schema, Type, TypeRef,
+        field::FieldValueInner, interface::TypeResolver, FieldFuture, FieldValue, Object,
+        ObjectAccessor, ResolverContext, Schema, Type, TypeRef,
     },
     extensions::ResolveInfo,
     parser::types::Selection,
@@ -546,18 +546,24 @@ async fn resolve_value(
                 .into_server_error(ctx.item.pos),
         )),
 
-        (Type::Interface(interface), FieldValueInner::WithType { value, ty }) => {
-            let is_contains_obj = schema
+        (Type::Interface(interface), _) => {
+            let possible_types = schema
                 .0
                 .env
                 .registry
                 .types
                 .get(&interface.name)
-                .and_then(|meta_type| {
-                    meta_type
-                        .possible_types()
-                        .map(|possible_types| possible_types.contains(ty.as_ref()))
-                })
+                .and_then(|meta_type| meta_type.possible_types());
+            let (value, ty) = resolve_abstract_type(
+                ctx,
+                "interface",
+                &interface.name,
+                interface.type_resolver.as_ref(),
+                |ty| possible_types.is_some_and(|possible_types| possible_types.contains(ty)),
+                value,
+            )?;
+            let is_contains_obj = possible_types
+                .map(|possible_types| possible_types.contains(ty.as_ref()))
                 .unwrap_or_default();
             if !is_contains_obj {
                 return Err(ctx.set_error_path(
@@ -596,15 +602,16 @@ async fn resolve_value(
             )
             .await
         }
-        (Type::Interface(interface), _) => Err(ctx.set_error_path(
-            Error::new(format!(
-                "internal: invalid value for interface \"{}\", expected \"FieldValue::WithType\"",
-                interface.name
-            ))
-            .into_server_error(ctx.item.pos),
-        )),
 
-        (Type::Union(union), FieldValueInner::WithType { value, ty }) => {
+        (Type::Union(union), _) => {
+            let (value, ty) = resolve_abstract_type(
+                ctx,
+                "union",
+                &union.name,
+                union.type_resolver.as_ref(),
+                |ty| union.possible_types.contains(ty),
+                value,
+            )?;
             if !union.possible_types.contains(ty.as_ref()) {
                 return Err(ctx.set_error_path(
                     Error::new(format!(
@@ -642,13 +649,6 @@ async fn resolve_value(
             )
             .await
         }
-        (Type::Union(union), _) => Err(ctx.set_error_path(
-            Error::new(format!(
-                "internal: invalid value for union \"{}\", expected \"FieldValue::WithType\"",
-                union.name
-            ))
-            .into_server_error(ctx.item.pos),
-        )),
         (Type::Subscription(subscription), _) => Err(ctx.set_error_path(
             Error::new(format!(
                 "internal: cannot use subscription \"{}\" as output value",
@@ -662,3 +662,34 @@ async fn resolve_value(
         )),
     }
 }
+
+fn resolve_abstract_type<'a, 'b>(
+    ctx: &Context<'_>,
+    kind: &str,
+    name: &str,
+    type_resolver: Option<&TypeResolver>,
+    is_possible_type: impl Fn(&str) -> bool,
+    value: &'b FieldValue<'a>,
+) -> ServerResult<(&'b FieldValue<'a>, Cow<'b, str>)> {
+    let err = match (&value.0, type_resolver) {
+        (FieldValueInner::WithType { value, ty }, _) => {
+            return Ok((value, Cow::Borrowed(ty.as_ref())))
+        }
+        (_, Some(type_resolver)) => match (type_resolver.0)(value) {
+            Some(ty) if is_possible_type(&ty) => return Ok((value, Cow::Owned(ty))),
+            Some(ty) => format!(
+                "internal: the type resolver of {} \"{}\" returned an unknown type \"{}\"",
+                kind, name, ty
+            ),
+            None => format!(
+                "internal: the type resolver of {} \"{}\" cannot resolve the type of the value",
+                kind, name
+            ),
+        },
+        (_, None) => format!(
+            "internal: invalid value for {} \"{}\", expected \"FieldValue::WithType\"",
+            kind, name
+        ),
+    };
+    Err(ctx.set_error_path(Error::new(err).into_server_error(ctx.item.pos)))
+}
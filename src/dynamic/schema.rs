@@ -5,6 +5,7 @@ use futures_util::{stream::BoxStream, Stream, StreamExt, TryFutureExt};
 use indexmap::IndexMap;
 
 use crate::{
+    dry_run::DryRun,
     dynamic::{
         field::BoxResolverFn, r#type::Type, resolve::resolve_container, DynamicRequest,
         FieldFuture, FieldValue, Object, ResolverContext, Scalar, SchemaError, Subscription,
@@ -66,6 +67,15 @@ impl SchemaBuilder {
         self
     }
 
+    /// Returns the names of the registered extensions in the order they are
+    /// called.
+    pub fn extensions_list(&self) -> Vec<&'static str> {
+        self.extensions
+            .iter()
+            .map(|extension| extension.name())
+            .collect()
+    }
+
     /// Set the maximum complexity a query can have. By default, there is no
     /// limit.
     #[must_use]
@@ -160,6 +170,11 @@ impl SchemaBuilder {
             federation_subscription: false,
             ignore_name_conflicts: Default::default(),
             enable_suggestions: self.enable_suggestions,
+            introspect_applied_directives: false,
+            localized_descriptions: Default::default(),
+            naming_convention: Default::default(),
+            lazy_types: Default::default(),
+            creating_types: Default::default(),
         };
         registry.add_system_types();
 
@@ -180,6 +195,7 @@ impl SchemaBuilder {
             IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly
         ) {
             registry.create_introspection_types();
+            registry.remove_applied_directives_introspection();
         }
 
         // create entity types
@@ -208,6 +224,10 @@ impl SchemaBuilder {
                 registry,
                 data: self.data,
                 custom_directives: Default::default(),
+                field_overrides: Default::default(),
+                document_transforms: Default::default(),
+                dry_run_placeholders: Default::default(),
+                upload_spool_threshold: None,
             })),
             extensions: self.extensions,
             types: self.types,
@@ -331,6 +351,12 @@ impl Schema {
             execute_data.as_ref(),
         );
         let res = match &env.operation.node.ty {
+            _ if env.dry_run => DryRun {
+                placeholders: &self.0.env.dry_run_placeholders,
+            }
+            .execute(&ctx)
+            .await
+            .map(Some),
             OperationType::Query => {
                 async move { self.query_root() }
                     .and_then(|query_root| {
@@ -355,7 +381,8 @@ impl Schema {
             Ok(value) => Response::new(value.unwrap_or_default()),
             Err(err) => Response::from_errors(vec![err]),
         }
-        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
+        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()))
+        .cache_control(*env.cache_control.lock().unwrap());
 
         resp.errors
             .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
@@ -373,23 +400,24 @@ impl Schema {
                     extensions,
                     request.inner,
                     Default::default(),
-                    &self.0.env.registry,
+                    &self.0.env,
                     self.0.validation_mode,
                     self.0.recursive_depth,
                     self.0.max_directives,
                     self.0.complexity,
                     self.0.depth,
+                    None,
+                    None,
                 )
                 .await
                 {
-                    Ok((env, cache_control)) => {
+                    Ok(env) => {
                         let f = {
                             |execute_data| {
                                 let env = env.clone();
                                 async move {
                                     self.execute_once(env, &request.root_value, execute_data)
                                         .await
-                                        .cache_control(cache_control)
                                 }
                             }
                         };
@@ -427,16 +455,18 @@ impl Schema {
                     }
                 };
 
-                let (env, _) = match prepare_request(
+                let env = match prepare_request(
                     extensions,
                     request.inner,
                     session_data,
-                    &schema.0.env.registry,
+                    &schema.0.env,
                     schema.0.validation_mode,
                     schema.0.recursive_depth,
                     schema.0.max_directives,
                     schema.0.complexity,
                     schema.0.depth,
+                    None,
+                    None,
                 )
                 .await {
                     Ok(res) => res,
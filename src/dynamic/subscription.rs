@@ -161,7 +161,11 @@ impl Subscription {
                     inaccessible: false,
                     tags: vec![],
                     override_from: None,
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     directive_invocations: vec![],
                 },
             );
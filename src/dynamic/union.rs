@@ -1,8 +1,10 @@
 use indexmap::IndexSet;
 
-use super::{directive::to_meta_directive_invocation, Directive};
+use std::sync::Arc;
+
+use super::{directive::to_meta_directive_invocation, interface::TypeResolver, Directive};
 use crate::{
-    dynamic::SchemaError,
+    dynamic::{FieldValue, SchemaError},
     registry::{MetaType, Registry},
 };
 
@@ -83,6 +85,7 @@ pub struct Union {
     pub(crate) name: String,
     pub(crate) description: Option<String>,
     pub(crate) possible_types: IndexSet<String>,
+    pub(crate) type_resolver: Option<TypeResolver>,
     inaccessible: bool,
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
@@ -96,6 +99,7 @@ impl Union {
             name: name.into(),
             description: None,
             possible_types: Default::default(),
+            type_resolver: None,
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
@@ -114,6 +118,19 @@ impl Union {
         self
     }
 
+    /// Set a resolver for the object type of the values of this union
+    ///
+    /// See also: [`Interface::type_resolver`](crate::dynamic::Interface::type_resolver)
+    pub fn type_resolver(
+        self,
+        resolver: impl Fn(&FieldValue<'_>) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            type_resolver: Some(TypeResolver(Arc::new(resolver))),
+            ..self
+        }
+    }
+
     /// Returns the type name
     #[inline]
     pub fn type_name(&self) -> &str {
@@ -264,6 +281,117 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn type_resolver() {
+        let obj_a = Object::new("MyObjA").field(Field::new(
+            "a",
+            TypeRef::named_nn(TypeRef::STRING),
+            |ctx| {
+                FieldFuture::new(
+                    async move { Ok(Some(ctx.parent_value.as_value().unwrap().clone())) },
+                )
+            },
+        ));
+
+        let obj_b =
+            Object::new("MyObjB").field(Field::new("b", TypeRef::named_nn(TypeRef::INT), |ctx| {
+                FieldFuture::new(
+                    async move { Ok(Some(ctx.parent_value.as_value().unwrap().clone())) },
+                )
+            }));
+
+        let union = Union::new("MyUnion")
+            .possible_type(obj_a.type_name())
+            .possible_type(obj_b.type_name())
+            .type_resolver(|value| match value.as_value()? {
+                Value::String(s) if s == "unknown" => Some("MyObjC".to_string()),
+                Value::String(_) => Some("MyObjA".to_string()),
+                Value::Number(_) => Some("MyObjB".to_string()),
+                _ => None,
+            });
+
+        let query = Object::new("Query")
+            .field(Field::new(
+                "values",
+                TypeRef::named_nn_list_nn(union.type_name()),
+                |_| {
+                    FieldFuture::new(async {
+                        Ok(Some(FieldValue::list([
+                            FieldValue::value("abc"),
+                            FieldValue::value(10),
+                            FieldValue::value("def").with_type("MyObjA"),
+                        ])))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "unknown",
+                TypeRef::named(union.type_name()),
+                |_| FieldFuture::new(async { Ok(Some(FieldValue::value("unknown"))) }),
+            ))
+            .field(Field::new(
+                "unresolved",
+                TypeRef::named(union.type_name()),
+                |_| FieldFuture::new(async { Ok(Some(FieldValue::value(true))) }),
+            ));
+
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(obj_a)
+            .register(obj_b)
+            .register(union)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ values { __typename ... on MyObjA { a } ... on MyObjB { b } } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "values": [
+                    { "__typename": "MyObjA", "a": "abc" },
+                    { "__typename": "MyObjB", "b": 10 },
+                    { "__typename": "MyObjA", "a": "def" },
+                ]
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute("{ unknown { __typename } }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "internal: the type resolver of union \"MyUnion\" returned an unknown type \"MyObjC\""
+                    .to_owned(),
+                source: None,
+                locations: vec![Pos { column: 3, line: 1 }],
+                path: vec![PathSegment::Field("unknown".to_owned())],
+                extensions: None,
+            }]
+        );
+
+        assert_eq!(
+            schema
+                .execute("{ unresolved { __typename } }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "internal: the type resolver of union \"MyUnion\" cannot resolve the type of the value"
+                    .to_owned(),
+                source: None,
+                locations: vec![Pos { column: 3, line: 1 }],
+                path: vec![PathSegment::Field("unresolved".to_owned())],
+                extensions: None,
+            }]
+        );
+    }
+
     #[tokio::test]
     async fn test_query() {
         struct Dog;
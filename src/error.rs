@@ -33,6 +33,59 @@ impl ErrorExtensionValues {
     }
 }
 
+/// Stable codes of the errors reported by `async-graphql` itself.
+///
+/// The code is stored in the `code` extension of the error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The query document could not be parsed.
+    ParseFailed,
+    /// The query document failed to validate against the schema.
+    ValidationFailed,
+    /// The complexity of the query exceeds the limit of the schema.
+    QueryTooComplex,
+    /// The depth of the query exceeds the limit of the schema.
+    QueryTooDeep,
+    /// The number of directives on a field exceeds the limit of the schema.
+    TooManyDirectives,
+    /// A websocket connection started more subscriptions than the limit of
+    /// the schema.
+    TooManySubscriptions,
+}
+
+impl ErrorCode {
+    /// Returns the value of the `code` extension for this error code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ParseFailed => "GRAPHQL_PARSE_FAILED",
+            ErrorCode::ValidationFailed => "GRAPHQL_VALIDATION_FAILED",
+            ErrorCode::QueryTooComplex => "QUERY_TOO_COMPLEX",
+            ErrorCode::QueryTooDeep => "QUERY_TOO_DEEP",
+            ErrorCode::TooManyDirectives => "TOO_MANY_DIRECTIVES",
+            ErrorCode::TooManySubscriptions => "TOO_MANY_SUBSCRIPTIONS",
+        }
+    }
+
+    fn from_str(code: &str) -> Option<Self> {
+        Some(match code {
+            "GRAPHQL_PARSE_FAILED" => ErrorCode::ParseFailed,
+            "GRAPHQL_VALIDATION_FAILED" => ErrorCode::ValidationFailed,
+            "QUERY_TOO_COMPLEX" => ErrorCode::QueryTooComplex,
+            "QUERY_TOO_DEEP" => ErrorCode::QueryTooDeep,
+            "TOO_MANY_DIRECTIVES" => ErrorCode::TooManyDirectives,
+            "TOO_MANY_SUBSCRIPTIONS" => ErrorCode::TooManySubscriptions,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// An error in a GraphQL server.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ServerError {
@@ -130,6 +183,25 @@ impl ServerError {
     pub fn with_path(self, path: Vec<PathSegment>) -> Self {
         Self { path, ..self }
     }
+
+    /// Set the `code` extension of the error, unless the error already has a
+    /// code.
+    #[must_use]
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        let extensions = self.extensions.get_or_insert_with(Default::default);
+        if extensions.get("code").is_none() {
+            extensions.set("code", code.as_str());
+        }
+        self
+    }
+
+    /// Get the code of the error if it is one of the built-in [`ErrorCode`]s.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self.extensions.as_ref()?.get("code")? {
+            Value::String(code) => ErrorCode::from_str(code),
+            _ => None,
+        }
+    }
 }
 
 impl Display for ServerError {
@@ -153,6 +225,7 @@ impl From<parser::Error> for ServerError {
             path: Vec::new(),
             extensions: None,
         }
+        .with_code(ErrorCode::ParseFailed)
     }
 }
 
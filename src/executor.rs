@@ -58,4 +58,10 @@ pub trait Executor: Unpin + Clone + Send + Sync + 'static {
         request: Request,
         session_data: Option<Arc<Data>>,
     ) -> BoxStream<'static, Response>;
+
+    /// Returns the maximum number of subscriptions running at the same time
+    /// on a websocket connection, `None` if there is no limit.
+    fn max_subscriptions_per_connection(&self) -> Option<usize> {
+        None
+    }
 }
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use futures_util::{stream::BoxStream, StreamExt};
+
+use crate::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextRequest, NextSubscribe},
+    Response, ServerError,
+};
+
+type MaskFn = dyn Fn(&ServerError) -> String + Send + Sync;
+type SafeFn = dyn Fn(&ServerError) -> bool + Send + Sync;
+
+/// Mask errors extension
+///
+/// Rewrites the messages of the errors before they are returned to the
+/// client, for example to hide internal details such as SQL errors in
+/// production. The closure receives the original error, so it can be logged
+/// there. The locations, path, extensions and source of the errors are
+/// preserved.
+///
+/// Errors considered safe are passed through unchanged, by default the errors
+/// with a `code` extension, such as the errors reported by `async-graphql`
+/// itself (see [`ErrorCode`](crate::ErrorCode)).
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{extensions::MaskErrors, *};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn internal(&self) -> Result<i32> {
+///         Err("connection refused: 10.0.0.1:5432".into())
+///     }
+///
+///     async fn not_found(&self) -> Result<i32> {
+///         Err(Error::new("Not found").extend_with(|_, e| e.set("code", "NOT_FOUND")))
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(MaskErrors::new(|_err| "Internal server error".to_string()))
+///     .finish();
+/// let errors = schema.execute("{ internal }").await.errors;
+/// assert_eq!(errors[0].message, "Internal server error");
+/// let errors = schema.execute("{ notFound }").await.errors;
+/// assert_eq!(errors[0].message, "Not found");
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct MaskErrors {
+    mask: Arc<MaskFn>,
+    safe: Arc<SafeFn>,
+}
+
+impl MaskErrors {
+    /// Create a mask errors extension, `mask` returns the message of an
+    /// error that is not safe.
+    pub fn new<F>(mask: F) -> Self
+    where
+        F: Fn(&ServerError) -> String + Send + Sync + 'static,
+    {
+        Self {
+            mask: Arc::new(mask),
+            safe: Arc::new(|err| {
+                err.extensions
+                    .as_ref()
+                    .is_some_and(|extensions| extensions.get("code").is_some())
+            }),
+        }
+    }
+
+    /// Set the function that returns `true` if an error is safe to return
+    /// unchanged.
+    #[must_use]
+    pub fn safe<F>(self, safe: F) -> Self
+    where
+        F: Fn(&ServerError) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            safe: Arc::new(safe),
+            ..self
+        }
+    }
+}
+
+impl ExtensionFactory for MaskErrors {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(self.clone())
+    }
+}
+
+impl MaskErrors {
+    fn mask_response(&self, mut resp: Response) -> Response {
+        for err in &mut resp.errors {
+            if !(self.safe)(err) {
+                err.message = (self.mask)(err);
+            }
+        }
+        resp
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for MaskErrors {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        self.mask_response(next.run(ctx).await)
+    }
+
+    fn subscribe<'s>(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        stream: BoxStream<'s, Response>,
+        next: NextSubscribe<'_>,
+    ) -> BoxStream<'s, Response> {
+        let this = self.clone();
+        next.run(ctx, stream)
+            .map(move |resp| this.mask_response(resp))
+            .boxed()
+    }
+}
@@ -7,8 +7,10 @@ pub mod apollo_persisted_queries;
 mod apollo_tracing;
 #[cfg(feature = "log")]
 mod logger;
+mod mask_errors;
 #[cfg(feature = "opentelemetry")]
 mod opentelemetry;
+mod stats;
 #[cfg(feature = "tracing")]
 mod tracing;
 
@@ -25,8 +27,10 @@ pub use self::analyzer::Analyzer;
 pub use self::apollo_tracing::ApolloTracing;
 #[cfg(feature = "log")]
 pub use self::logger::Logger;
+pub use self::mask_errors::MaskErrors;
 #[cfg(feature = "opentelemetry")]
 pub use self::opentelemetry::OpenTelemetry;
+pub use self::stats::{ExecutionStats, Stats};
 #[cfg(feature = "tracing")]
 pub use self::tracing::Tracing;
 use crate::{
@@ -56,6 +60,13 @@ pub struct ExtensionContext<'a> {
     /// inside the [`Extension::request`], [`Extension::subscribe`], and
     /// [`Extension::prepare_request`] hooks.
     pub query_data: Option<&'a Data>,
+
+    /// The extensions of the request.
+    ///
+    /// This is a reference to [`Request::extensions`](Request) field, and
+    /// like `query_data` it is seen as `None` until the request has been
+    /// prepared.
+    pub request_extensions: Option<&'a crate::Extensions>,
 }
 
 impl<'a> DataContext<'a> for ExtensionContext<'a> {
@@ -379,6 +390,25 @@ impl NextResolve<'_> {
 }
 
 /// Represents a GraphQL extension
+///
+/// # Order
+///
+/// The extensions are called in the order they are registered with
+/// [`SchemaBuilder::extension`](crate::SchemaBuilder::extension). Each hook
+/// receives the remainder of the chain as `next`, so the first registered
+/// extension is the outermost one:
+///
+/// - For [`request`](Extension::request),
+///   [`prepare_request`](Extension::prepare_request),
+///   [`parse_query`](Extension::parse_query),
+///   [`validation`](Extension::validation), [`execute`](Extension::execute) and
+///   [`resolve`](Extension::resolve), the code before `next.run` runs in
+///   registration order and the code after it runs in reverse order.
+/// - For [`subscribe`](Extension::subscribe), the first registered extension
+///   receives the response stream first. A stream it wraps before passing it
+///   to `next.run` is polled after the streams wrapped by the extensions
+///   registered later, and a wrapper of the stream returned by `next.run` is
+///   polled before them.
 #[async_trait::async_trait]
 pub trait Extension: Sync + Send + 'static {
     /// Called at start query/mutation request.
@@ -453,6 +483,11 @@ pub trait Extension: Sync + Send + 'static {
 pub trait ExtensionFactory: Send + Sync + 'static {
     /// Create an extended instance.
     fn create(&self) -> Arc<dyn Extension>;
+
+    /// Returns the name of the extension, the type name by default.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 #[derive(Clone)]
@@ -462,6 +497,7 @@ pub struct Extensions {
     schema_env: SchemaEnv,
     session_data: Arc<Data>,
     query_data: Option<Arc<Data>>,
+    request_extensions: Option<Arc<crate::Extensions>>,
 }
 
 #[doc(hidden)]
@@ -476,6 +512,7 @@ impl Extensions {
             schema_env,
             session_data,
             query_data: None,
+            request_extensions: None,
         }
     }
 
@@ -484,6 +521,11 @@ impl Extensions {
         self.query_data = Some(data);
     }
 
+    #[inline]
+    pub(crate) fn attach_request_extensions(&mut self, extensions: Arc<crate::Extensions>) {
+        self.request_extensions = Some(extensions);
+    }
+
     #[inline]
     pub(crate) fn is_empty(&self) -> bool {
         self.extensions.is_empty()
@@ -495,6 +537,7 @@ impl Extensions {
             schema_env: &self.schema_env,
             session_data: &self.session_data,
             query_data: self.query_data.as_deref(),
+            request_extensions: self.request_extensions.as_deref(),
         }
     }
 
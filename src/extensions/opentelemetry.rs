@@ -4,7 +4,10 @@ use async_graphql_parser::types::ExecutableDocument;
 use async_graphql_value::Variables;
 use futures_util::{stream::BoxStream, TryFutureExt};
 use opentelemetry::{
-    trace::{FutureExt, SpanKind, TraceContextExt, Tracer},
+    trace::{
+        FutureExt, SpanContext, SpanId, SpanKind, TraceContextExt, TraceFlags, TraceId, TraceState,
+        Tracer,
+    },
     Context as OpenTelemetryContext, Key, KeyValue,
 };
 
@@ -25,6 +28,39 @@ const KEY_COMPLEXITY: Key = Key::from_static_str("graphql.complexity");
 const KEY_DEPTH: Key = Key::from_static_str("graphql.depth");
 
 /// OpenTelemetry extension
+///
+/// If the request data contains an [`http::HeaderMap`] with a valid W3C
+/// [`traceparent`](https://www.w3.org/TR/trace-context/#traceparent-header)
+/// header, the `execute` span continues the trace of the caller. The header
+/// is ignored if it's malformed, the spans then belong to a new trace.
+///
+/// The request, parse and validation spans are started before the request
+/// data is available, they are not linked to the caller.
+///
+/// ```no_run
+/// use async_graphql::{extensions::OpenTelemetry, *};
+/// use opentelemetry::trace::noop::NoopTracer;
+///
+/// #[derive(SimpleObject)]
+/// struct Query {
+///     value: i32,
+/// }
+///
+/// let schema = Schema::build(Query { value: 100 }, EmptyMutation, EmptySubscription)
+///     .extension(OpenTelemetry::new(NoopTracer::new()))
+///     .finish();
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let mut headers = ::http::HeaderMap::new();
+/// headers.insert(
+///     "traceparent",
+///     "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+///         .parse()
+///         .unwrap(),
+/// );
+/// schema.execute(Request::new("{ value }").data(headers)).await;
+/// # });
+/// ```
 #[cfg_attr(docsrs, doc(cfg(feature = "opentelemetry")))]
 pub struct OpenTelemetry<T> {
     tracer: Arc<T>,
@@ -158,8 +194,19 @@ where
         let span = self
             .tracer
             .span_builder("execute")
-            .with_kind(SpanKind::Server)
-            .start(&*self.tracer);
+            .with_kind(SpanKind::Server);
+        let span = match ctx
+            .data_opt::<http::HeaderMap>()
+            .and_then(|headers| headers.get("traceparent"))
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_traceparent)
+        {
+            Some(parent) => span.start_with_context(
+                &*self.tracer,
+                &OpenTelemetryContext::current().with_remote_span_context(parent),
+            ),
+            None => span.start(&*self.tracer),
+        };
         next.run(ctx, operation_name)
             .with_context(OpenTelemetryContext::current_with_span(span))
             .await
@@ -204,3 +251,37 @@ where
         }
     }
 }
+
+/// Parses a `traceparent` header, `{version}-{trace-id}-{parent-id}-{flags}`.
+fn parse_traceparent(value: &str) -> Option<SpanContext> {
+    let is_hex = |s: &str, len: usize| {
+        s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    };
+
+    let mut parts = value.trim().split('-');
+    let version = parts.next()?;
+    let trace_id = parts.next()?;
+    let span_id = parts.next()?;
+    let flags = parts.next()?;
+    // future versions may append fields, version `00` has exactly four
+    if !is_hex(version, 2) || version == "ff" || (version == "00" && parts.next().is_some()) {
+        return None;
+    }
+    if !is_hex(trace_id, 32) || !is_hex(span_id, 16) || !is_hex(flags, 2) {
+        return None;
+    }
+
+    let trace_id = TraceId::from_hex(trace_id).ok()?;
+    let span_id = SpanId::from_hex(span_id).ok()?;
+    if trace_id == TraceId::INVALID || span_id == SpanId::INVALID {
+        return None;
+    }
+    let flags = u8::from_str_radix(flags, 16).ok()?;
+    Some(SpanContext::new(
+        trace_id,
+        span_id,
+        TraceFlags::new(flags) & TraceFlags::SAMPLED,
+        true,
+        TraceState::default(),
+    ))
+}
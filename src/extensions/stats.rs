@@ -0,0 +1,232 @@
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::{
+    extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextExecute, NextRequest, NextResolve,
+        ResolveInfo,
+    },
+    value, QueryPathSegment, Response, ServerResult, Value,
+};
+
+thread_local! {
+    static CURRENT_STATS: RefCell<Option<Arc<ExecutionStats>>> = const { RefCell::new(None) };
+}
+
+/// The statistics of the execution of a request, collected by the [`Stats`]
+/// extension.
+#[derive(Debug, Default)]
+pub struct ExecutionStats {
+    fields_resolved: AtomicUsize,
+    dataloader_batches: AtomicUsize,
+    cache_hits: AtomicUsize,
+}
+
+impl ExecutionStats {
+    /// Returns the number of resolved fields.
+    pub fn fields_resolved(&self) -> usize {
+        self.fields_resolved.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of batches started by the DataLoaders.
+    pub fn dataloader_batches(&self) -> usize {
+        self.dataloader_batches.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of keys loaded from the caches of the DataLoaders.
+    pub fn cache_hits(&self) -> usize {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    /// Records a batch started by a DataLoader, in the request being
+    /// executed on this thread if it has the [`Stats`] extension.
+    #[cfg(feature = "dataloader")]
+    pub(crate) fn record_dataloader_batch() {
+        Self::with_current(|stats| {
+            stats.dataloader_batches.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Records the keys loaded from the cache of a DataLoader, in the request
+    /// being executed on this thread if it has the [`Stats`] extension.
+    #[cfg(feature = "dataloader")]
+    pub(crate) fn record_cache_hits(count: usize) {
+        if count > 0 {
+            Self::with_current(|stats| {
+                stats.cache_hits.fetch_add(count, Ordering::Relaxed);
+            });
+        }
+    }
+
+    #[cfg(feature = "dataloader")]
+    fn with_current(f: impl FnOnce(&ExecutionStats)) {
+        CURRENT_STATS.with(|current| {
+            if let Some(stats) = &*current.borrow() {
+                f(stats);
+            }
+        });
+    }
+
+    fn to_value(&self) -> Value {
+        value!({
+            "fieldsResolved": self.fields_resolved(),
+            "dataloaderBatches": self.dataloader_batches(),
+            "cacheHits": self.cache_hits(),
+        })
+    }
+}
+
+type StatsCallback = dyn Fn(&ExecutionStats) + Send + Sync;
+
+/// Stats extension
+///
+/// Collects the number of resolved fields, and the number of batches and
+/// cache hits of the [`DataLoader`](crate::dataloader::DataLoader)s used by
+/// the resolvers, for each request. By default the statistics are added to
+/// the `stats` field of the response extensions, with
+/// [`Stats::with_callback`] they are passed to a callback instead.
+///
+/// The DataLoaders record their statistics in the request being executed on
+/// the current thread, a batch is counted by the request that started it.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{extensions::Stats, *};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(Stats::new())
+///     .finish();
+/// let resp = schema.execute("{ value }").await;
+/// assert_eq!(
+///     resp.extensions["stats"],
+///     value!({ "fieldsResolved": 1, "dataloaderBatches": 0, "cacheHits": 0 })
+/// );
+/// # });
+/// ```
+#[derive(Clone, Default)]
+pub struct Stats {
+    callback: Option<Arc<StatsCallback>>,
+}
+
+impl Stats {
+    /// Create a stats extension adding the statistics to the response
+    /// extensions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a stats extension passing the statistics of each request to
+    /// `callback`, instead of adding them to the response extensions.
+    pub fn with_callback<F>(callback: F) -> Self
+    where
+        F: Fn(&ExecutionStats) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Some(Arc::new(callback)),
+        }
+    }
+}
+
+impl ExtensionFactory for Stats {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(StatsExtension {
+            stats: Default::default(),
+            callback: self.callback.clone(),
+        })
+    }
+}
+
+struct StatsExtension {
+    stats: Arc<ExecutionStats>,
+    callback: Option<Arc<StatsCallback>>,
+}
+
+#[async_trait::async_trait]
+impl Extension for StatsExtension {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        let resp = next.run(ctx).await;
+        match &self.callback {
+            Some(callback) => {
+                callback(&self.stats);
+                resp
+            }
+            None => resp.extension("stats", self.stats.to_value()),
+        }
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        WithStats {
+            fut: next.run(ctx, operation_name),
+            stats: self.stats.clone(),
+        }
+        .await
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        // The items of the lists are resolved with the path of their index.
+        if let QueryPathSegment::Name(_) = info.path_node.segment {
+            self.stats.fields_resolved.fetch_add(1, Ordering::Relaxed);
+        }
+        next.run(ctx, info).await
+    }
+}
+
+pin_project! {
+    /// Makes the stats of a request current on the thread while its future
+    /// is polled.
+    struct WithStats<F> {
+        #[pin]
+        fut: F,
+        stats: Arc<ExecutionStats>,
+    }
+}
+
+impl<F: Future> Future for WithStats<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        struct Restore(Option<Arc<ExecutionStats>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_STATS.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let this = self.project();
+        let _restore =
+            Restore(CURRENT_STATS.with(|current| current.replace(Some(this.stats.clone()))));
+        this.fut.poll(cx)
+    }
+}
@@ -18,7 +18,7 @@ use futures_util::{
 use pin_project_lite::pin_project;
 use serde::{Deserialize, Serialize};
 
-use crate::{Data, Error, Executor, Request, Response, Result};
+use crate::{Data, Error, ErrorCode, Executor, Request, Response, Result, ServerError};
 
 /// All known protocols based on WebSocket.
 pub const ALL_WEBSOCKET_PROTOCOLS: [&str; 2] = ["graphql-transport-ws", "graphql-ws"];
@@ -370,6 +370,28 @@ where
                         payload: request,
                     } => {
                         if let Some(data) = this.data.clone() {
+                            if let Some(max_subscriptions) =
+                                this.executor.max_subscriptions_per_connection()
+                            {
+                                if this.streams.len() >= max_subscriptions
+                                    && !this.streams.contains_key(&id)
+                                {
+                                    let err = ServerError::new(
+                                        format!(
+                                            "Too many subscriptions, the limit is {} per connection.",
+                                            max_subscriptions
+                                        ),
+                                        None,
+                                    )
+                                    .with_code(ErrorCode::TooManySubscriptions);
+                                    return Poll::Ready(Some(WsMessage::Text(
+                                        serde_json::to_string(
+                                            &this.protocol.error_message(&id, err),
+                                        )
+                                        .unwrap(),
+                                    )));
+                                }
+                            }
                             this.streams.insert(
                                 id,
                                 Box::pin(this.executor.execute_stream(request, Some(data))),
@@ -512,6 +534,17 @@ impl Protocols {
             Protocols::GraphQLWS => ServerMessage::Next { id, payload },
         }
     }
+
+    fn error_message<'s>(&self, id: &'s str, err: ServerError) -> ServerMessage<'s> {
+        let payload = match self {
+            Protocols::SubscriptionsTransportWS => serde_json::to_value(err),
+            Protocols::GraphQLWS => serde_json::to_value([err]),
+        };
+        ServerMessage::Error {
+            id,
+            payload: payload.unwrap(),
+        }
+    }
 }
 
 impl std::str::FromStr for Protocols {
@@ -602,11 +635,12 @@ enum ServerMessage<'a> {
         id: &'a str,
         payload: Response,
     },
-    // Not used by this library, as it's not necessary to send
-    // Error {
-    //     id: &'a str,
-    //     payload: serde_json::Value,
-    // },
+    /// The operation failed before it was executed, the payload is an error
+    /// for subscriptions-transport-ws and a list of errors for graphql-ws.
+    Error {
+        id: &'a str,
+        payload: serde_json::Value,
+    },
     Complete {
         id: &'a str,
     },
@@ -179,7 +179,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod base;
+mod broker;
 mod custom_directive;
+mod document_transform;
+mod dry_run;
 mod error;
 mod executor;
 mod guard;
@@ -211,7 +214,7 @@ pub mod registry;
 pub use async_graphql_parser as parser;
 pub use async_graphql_value::{
     from_value, to_value, value, ConstValue as Value, DeserializerError, Extensions, Name, Number,
-    SerializerError, Variables,
+    NumberExt, SerializerError, Variables,
 };
 #[doc(hidden)]
 pub use async_stream;
@@ -221,12 +224,14 @@ pub use base::{
     ComplexObject, Description, InputObjectType, InputType, InterfaceType, ObjectType,
     OneofObjectType, OutputType, TypeName, UnionType,
 };
+pub use broker::SimpleBroker;
 #[doc(hidden)]
 pub use context::ContextSelectionSet;
 pub use context::*;
 pub use custom_directive::{CustomDirective, CustomDirectiveFactory, TypeDirective};
+pub use document_transform::DocumentVisitor;
 pub use error::{
-    Error, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
+    Error, ErrorCode, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
     ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult,
 };
 pub use executor::Executor;
@@ -239,15 +244,17 @@ pub use indexmap;
 pub use look_ahead::Lookahead;
 #[doc(no_inline)]
 pub use parser::{Pos, Positioned};
-pub use registry::{CacheControl, SDLExportOptions};
+pub use registry::{
+    CacheControl, NamingConvention, SDLExportOptions, SchemaWarning, SchemaWarningKind,
+};
 pub use request::{BatchRequest, Request};
 #[doc(no_inline)]
-pub use resolver_utils::{ContainerType, EnumType, ScalarType};
+pub use resolver_utils::{ContainerType, EnumType, FieldResolver, ScalarType};
 pub use response::{BatchResponse, Response};
-pub use schema::{IntrospectionMode, Schema, SchemaBuilder, SchemaEnv};
+pub use schema::{IntrospectionMode, Locale, Schema, SchemaBuilder, SchemaEnv};
 #[doc(hidden)]
 pub use static_assertions_next;
-pub use subscription::SubscriptionType;
+pub use subscription::{merge_streams, MergeErrorPolicy, MergeStreams, SubscriptionType};
 pub use types::*;
 pub use validation::{ValidationMode, ValidationResult, VisitorContext};
 pub use validators::CustomValidator;
@@ -284,8 +291,12 @@ pub use async_graphql_derive::Object;
 pub use async_graphql_derive::OneofObject;
 #[doc = include_str!("docs/scalar.md")]
 pub use async_graphql_derive::Scalar;
+#[doc = include_str!("docs/scalar_from_try_from.md")]
+pub use async_graphql_derive::ScalarFromTryFrom;
 #[doc = include_str!("docs/simple_object.md")]
 pub use async_graphql_derive::SimpleObject;
+#[doc = include_str!("docs/string_enum.md")]
+pub use async_graphql_derive::StringEnum;
 #[doc = include_str!("docs/subscription.md")]
 pub use async_graphql_derive::Subscription;
 pub use async_graphql_derive::TypeDirective;
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     parser::types::{Field, FragmentDefinition, Selection, SelectionSet},
@@ -65,6 +65,24 @@ impl<'a> Lookahead<'a> {
             })
             .collect()
     }
+
+    /// Returns an iterator over the fields selected by the fields covered by
+    /// this `Lookahead`, with their names, aliases and arguments.
+    ///
+    /// The fields of the fragments are included, and the fields selected more
+    /// than once with the same response key are only returned once. Their
+    /// selection sets are merged by the lookahead returned by
+    /// [`Lookahead::field`].
+    ///
+    /// For example, calling `.selected_fields()` on
+    /// `{ a { x: b c ... { b } } }` will return `x: b`, `c` and `b`.
+    pub fn selected_fields(&self) -> impl Iterator<Item = SelectionField<'a>> {
+        let mut keys = HashSet::new();
+        self.selection_fields()
+            .into_iter()
+            .flat_map(|field| field.selection_set())
+            .filter(move |field| keys.insert(field.alias().unwrap_or_else(|| field.name())))
+    }
 }
 
 impl<'a> From<SelectionField<'a>> for Lookahead<'a> {
@@ -343,4 +361,100 @@ mod tests {
             .await
             .is_ok());
     }
+
+    #[tokio::test]
+    async fn test_selected_fields() {
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Detail {
+            c: i32,
+            d: i32,
+        }
+
+        struct MyObj;
+
+        #[Object(internal)]
+        impl MyObj {
+            async fn a(&self, n: i32) -> i32 {
+                n
+            }
+
+            async fn b(&self) -> i32 {
+                0
+            }
+
+            async fn detail(&self) -> Detail {
+                Detail { c: 0, d: 0 }
+            }
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn obj(&self, ctx: &Context<'_>) -> MyObj {
+                let mut fields = Vec::new();
+                for field in ctx.look_ahead().selected_fields() {
+                    fields.push((
+                        field.name().to_string(),
+                        field.alias().map(ToString::to_string),
+                        field.arguments().unwrap(),
+                    ));
+                }
+                assert_eq!(
+                    fields,
+                    vec![
+                        (
+                            "a".to_string(),
+                            Some("x".to_string()),
+                            vec![(Name::new("n"), value!(1))]
+                        ),
+                        ("b".to_string(), None, vec![]),
+                        ("a".to_string(), None, vec![(Name::new("n"), value!(2))]),
+                        ("detail".to_string(), None, vec![]),
+                    ]
+                );
+
+                let detail = ctx.look_ahead().field("detail");
+                let detail_fields = detail
+                    .selected_fields()
+                    .map(|field| field.name())
+                    .collect::<Vec<_>>();
+                assert_eq!(detail_fields, vec!["c", "d"]);
+                MyObj
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let res = schema
+            .execute(
+                Request::new(
+                    r#"query($m: Int!) {
+            obj {
+                x: a(n: 1)
+                b
+                ... A
+                ... on MyObj {
+                    b
+                    detail {
+                        c
+                    }
+                }
+                detail {
+                    c
+                    d
+                }
+            }
+        }
+
+        fragment A on MyObj {
+            a(n: $m)
+            x: a(n: 1)
+        }"#,
+                )
+                .variables(Variables::from_value(value!({ "m": 2 }))),
+            )
+            .await;
+        assert!(res.is_ok(), "{:?}", res.errors);
+    }
 }
@@ -0,0 +1,75 @@
+use crate::{registry, registry::is_visible, Context, Object};
+
+pub struct __AppliedDirective<'a> {
+    pub directive: &'a registry::MetaDirectiveInvocation,
+}
+
+impl<'a> __AppliedDirective<'a> {
+    /// Returns the applications of the introspectable and visible directives
+    /// among `invocations`.
+    pub(crate) fn list(
+        ctx: &Context<'_>,
+        registry: &registry::Registry,
+        invocations: &'a [registry::MetaDirectiveInvocation],
+    ) -> Vec<__AppliedDirective<'a>> {
+        invocations
+            .iter()
+            .filter(|invocation| {
+                registry
+                    .directives
+                    .get(&invocation.name)
+                    .is_some_and(|directive| {
+                        directive.is_introspectable && is_visible(ctx, &directive.visible)
+                    })
+            })
+            .map(|directive| __AppliedDirective { directive })
+            .collect()
+    }
+}
+
+/// A Directive applied to a schema element, with the values of its arguments.
+#[Object(
+    internal,
+    name = "__AppliedDirective",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
+impl<'a> __AppliedDirective<'a> {
+    #[inline]
+    async fn name(&self) -> &str {
+        &self.directive.name
+    }
+
+    async fn args(&self) -> Vec<__DirectiveArgument<'a>> {
+        self.directive
+            .args
+            .iter()
+            .map(|(name, value)| __DirectiveArgument { name, value })
+            .collect()
+    }
+}
+
+pub struct __DirectiveArgument<'a> {
+    pub name: &'a str,
+    pub value: &'a crate::Value,
+}
+
+/// The value of an argument of an applied Directive.
+#[Object(
+    internal,
+    name = "__DirectiveArgument",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
+impl __DirectiveArgument<'_> {
+    #[inline]
+    async fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The value of the argument, as a GraphQL literal.
+    #[inline]
+    async fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
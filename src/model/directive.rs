@@ -118,7 +118,12 @@ pub struct __Directive<'a> {
 /// behavior in ways field arguments will not suffice, such as conditionally
 /// including or skipping a field. Directives provide this by describing
 /// additional information to the executor.
-#[Object(internal, name = "__Directive")]
+#[Object(
+    internal,
+    name = "__Directive",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
 impl<'a> __Directive<'a> {
     #[inline]
     async fn name(&self) -> &str {
@@ -145,6 +150,8 @@ impl<'a> __Directive<'a> {
             .filter(|input_value| include_deprecated || !input_value.deprecation.is_deprecated())
             .map(|input_value| __InputValue {
                 registry: self.registry,
+                owner: None,
+                field: None,
                 visible_types: self.visible_types,
                 input_value,
             })
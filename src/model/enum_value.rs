@@ -1,22 +1,31 @@
-use crate::{registry, Object};
+use crate::{model::__AppliedDirective, registry, Context, Object};
 
 pub struct __EnumValue<'a> {
+    pub registry: &'a registry::Registry,
+    pub owner: &'a str,
     pub value: &'a registry::MetaEnumValue,
 }
 
 /// One possible value for a given Enum. Enum values are unique values, not a
 /// placeholder for a string or numeric value. However an Enum value is returned
 /// in a JSON response as a string.
-#[Object(internal, name = "__EnumValue")]
-impl __EnumValue<'_> {
+#[Object(
+    internal,
+    name = "__EnumValue",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
+impl<'a> __EnumValue<'a> {
     #[inline]
     async fn name(&self) -> &str {
         &self.value.name
     }
 
     #[inline]
-    async fn description(&self) -> Option<&str> {
-        self.value.description.as_deref()
+    async fn description(&self, ctx: &Context<'_>) -> Option<&str> {
+        self.registry
+            .localized_description(ctx, self.owner, &self.value.name)
+            .or(self.value.description.as_deref())
     }
 
     #[inline]
@@ -28,4 +37,10 @@ impl __EnumValue<'_> {
     async fn deprecation_reason(&self) -> Option<&str> {
         self.value.deprecation.reason()
     }
+
+    // Removed from the registry unless enabled with
+    // `SchemaBuilder::enable_applied_directives_introspection`.
+    async fn applied_directives(&self, ctx: &Context<'_>) -> Vec<__AppliedDirective<'a>> {
+        __AppliedDirective::list(ctx, self.registry, &self.value.directive_invocations)
+    }
 }
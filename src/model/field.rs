@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    model::{__InputValue, __Type},
+    model::{__AppliedDirective, __InputValue, __Type},
     registry,
     registry::is_visible,
     Context, Object,
@@ -10,12 +10,18 @@ use crate::{
 pub struct __Field<'a> {
     pub registry: &'a registry::Registry,
     pub visible_types: &'a HashSet<&'a str>,
+    pub owner: &'a str,
     pub field: &'a registry::MetaField,
 }
 
 /// Object and Interface types are described by a list of Fields, each of which
 /// has a name, potentially a list of arguments, and a return type.
-#[Object(internal, name = "__Field")]
+#[Object(
+    internal,
+    name = "__Field",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
 impl<'a> __Field<'a> {
     #[inline]
     async fn name(&self) -> &str {
@@ -23,8 +29,10 @@ impl<'a> __Field<'a> {
     }
 
     #[inline]
-    async fn description(&self) -> Option<&str> {
-        self.field.description.as_deref()
+    async fn description(&self, ctx: &Context<'_>) -> Option<&str> {
+        self.registry
+            .localized_description(ctx, self.owner, &self.field.name)
+            .or(self.field.description.as_deref())
     }
 
     async fn args(
@@ -40,6 +48,8 @@ impl<'a> __Field<'a> {
             .map(|input_value| __InputValue {
                 registry: self.registry,
                 visible_types: self.visible_types,
+                owner: Some(self.owner),
+                field: Some(&self.field.name),
                 input_value,
             })
             .collect()
@@ -59,4 +69,10 @@ impl<'a> __Field<'a> {
     async fn deprecation_reason(&self) -> Option<&str> {
         self.field.deprecation.reason()
     }
+
+    // Removed from the registry unless enabled with
+    // `SchemaBuilder::enable_applied_directives_introspection`.
+    async fn applied_directives(&self, ctx: &Context<'_>) -> Vec<__AppliedDirective<'a>> {
+        __AppliedDirective::list(ctx, self.registry, &self.field.directive_invocations)
+    }
 }
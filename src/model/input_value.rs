@@ -1,17 +1,30 @@
 use std::collections::HashSet;
 
-use crate::{model::__Type, registry, Object};
+use crate::{
+    model::{__AppliedDirective, __Type},
+    registry, Context, Object,
+};
 
 pub struct __InputValue<'a> {
     pub registry: &'a registry::Registry,
     pub visible_types: &'a HashSet<&'a str>,
+    /// The input object this is a field of, or the type of the field this is
+    /// an argument of, `None` for the arguments of directives.
+    pub owner: Option<&'a str>,
+    /// The field this is an argument of.
+    pub field: Option<&'a str>,
     pub input_value: &'a registry::MetaInputValue,
 }
 
 /// Arguments provided to Fields or Directives and the input fields of an
 /// InputObject are represented as Input Values which describe their type and
 /// optionally a default value.
-#[Object(internal, name = "__InputValue")]
+#[Object(
+    internal,
+    name = "__InputValue",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
 impl<'a> __InputValue<'a> {
     #[inline]
     async fn name(&self) -> &str {
@@ -19,8 +32,20 @@ impl<'a> __InputValue<'a> {
     }
 
     #[inline]
-    async fn description(&self) -> Option<&str> {
-        self.input_value.description.as_deref()
+    async fn description(&self, ctx: &Context<'_>) -> Option<&str> {
+        let localized = match (self.owner, self.field) {
+            (Some(owner), Some(field)) => self.registry.localized_description(
+                ctx,
+                owner,
+                &format!("{}({}:)", field, self.input_value.name),
+            ),
+            (Some(owner), None) => {
+                self.registry
+                    .localized_description(ctx, owner, &self.input_value.name)
+            }
+            (None, _) => None,
+        };
+        localized.or(self.input_value.description.as_deref())
     }
 
     #[graphql(name = "type")]
@@ -43,4 +68,10 @@ impl<'a> __InputValue<'a> {
     async fn deprecation_reason(&self) -> Option<&str> {
         self.input_value.deprecation.reason()
     }
+
+    // Removed from the registry unless enabled with
+    // `SchemaBuilder::enable_applied_directives_introspection`.
+    async fn applied_directives(&self, ctx: &Context<'_>) -> Vec<__AppliedDirective<'a>> {
+        __AppliedDirective::list(ctx, self.registry, &self.input_value.directive_invocations)
+    }
 }
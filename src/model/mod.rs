@@ -1,3 +1,4 @@
+mod applied_directive;
 mod directive;
 mod enum_value;
 mod field;
@@ -6,6 +7,7 @@ mod kind;
 mod schema;
 mod r#type;
 
+pub use applied_directive::__AppliedDirective;
 pub use directive::{__Directive, __DirectiveLocation, location_traits};
 pub use enum_value::__EnumValue;
 pub use field::__Field;
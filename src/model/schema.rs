@@ -22,7 +22,12 @@ impl<'a> __Schema<'a> {
 /// A GraphQL Schema defines the capabilities of a GraphQL server. It exposes
 /// all available types and directives on the server, as well as the entry
 /// points for query, mutation, and subscription operations.
-#[Object(internal, name = "__Schema")]
+#[Object(
+    internal,
+    name = "__Schema",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
 impl<'a> __Schema<'a> {
     /// description of __Schema for newer graphiql introspection schema
     /// requirements
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    model::{__EnumValue, __Field, __InputValue, __TypeKind},
+    model::{__AppliedDirective, __EnumValue, __Field, __InputValue, __TypeKind},
     registry,
     registry::is_visible,
     Context, Object,
@@ -71,7 +71,12 @@ impl<'a> __Type<'a> {
 /// types provide the fields they describe. Abstract types, Union and Interface,
 /// provide the Object types possible at runtime. List and NonNull types compose
 /// other types.
-#[Object(internal, name = "__Type")]
+#[Object(
+    internal,
+    name = "__Type",
+    rename_fields = "camelCase",
+    rename_args = "camelCase"
+)]
 impl<'a> __Type<'a> {
     #[inline]
     async fn kind(&self) -> __TypeKind {
@@ -131,6 +136,7 @@ impl<'a> __Type<'a> {
                     .map(|field| __Field {
                         registry: self.registry,
                         visible_types: self.visible_types,
+                        owner: ty.name(),
                         field,
                     })
                     .collect()
@@ -178,13 +184,20 @@ impl<'a> __Type<'a> {
         ctx: &Context<'_>,
         #[graphql(default = false)] include_deprecated: bool,
     ) -> Option<Vec<__EnumValue<'a>>> {
-        if let TypeDetail::Named(registry::MetaType::Enum { enum_values, .. }) = &self.detail {
+        if let TypeDetail::Named(registry::MetaType::Enum {
+            name, enum_values, ..
+        }) = &self.detail
+        {
             Some(
                 enum_values
                     .values()
                     .filter(|value| is_visible(ctx, &value.visible))
                     .filter(|value| include_deprecated || !value.deprecation.is_deprecated())
-                    .map(|value| __EnumValue { value })
+                    .map(|value| __EnumValue {
+                        registry: self.registry,
+                        owner: name,
+                        value,
+                    })
                     .collect(),
             )
         } else {
@@ -197,8 +210,9 @@ impl<'a> __Type<'a> {
         ctx: &Context<'_>,
         #[graphql(default = false)] include_deprecated: bool,
     ) -> Option<Vec<__InputValue<'a>>> {
-        if let TypeDetail::Named(registry::MetaType::InputObject { input_fields, .. }) =
-            &self.detail
+        if let TypeDetail::Named(registry::MetaType::InputObject {
+            name, input_fields, ..
+        }) = &self.detail
         {
             Some(
                 input_fields
@@ -210,6 +224,8 @@ impl<'a> __Type<'a> {
                     .map(|input_value| __InputValue {
                         registry: self.registry,
                         visible_types: self.visible_types,
+                        owner: Some(name),
+                        field: None,
                         input_value,
                     })
                     .collect(),
@@ -249,4 +265,18 @@ impl<'a> __Type<'a> {
             None
         }
     }
+
+    // Removed from the registry unless enabled with
+    // `SchemaBuilder::enable_applied_directives_introspection`.
+    async fn applied_directives(&self, ctx: &Context<'_>) -> Option<Vec<__AppliedDirective<'a>>> {
+        if let TypeDetail::Named(ty) = &self.detail {
+            Some(__AppliedDirective::list(
+                ctx,
+                self.registry,
+                ty.directive_invocations(),
+            ))
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,59 @@
+//! Evaluation of the expressions of `#[graphql(complexity = "...")]`.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{ServerError, ServerResult};
+
+/// A value a complexity expression can evaluate to.
+///
+/// The value is converted to the complexity of the field, saturating to the
+/// range of `usize`: negative values are converted to `0`, and too large
+/// values and `NaN` to `usize::MAX`.
+pub trait ComplexityValue {
+    /// Converts the value to the complexity of the field.
+    fn into_complexity(self) -> usize;
+}
+
+macro_rules! impl_complexity_int {
+    ($($ty:ty),*) => {
+        $(
+        impl ComplexityValue for $ty {
+            #[allow(clippy::unnecessary_fallible_conversions)]
+            fn into_complexity(self) -> usize {
+                usize::try_from(self).unwrap_or(if self > 0 { usize::MAX } else { 0 })
+            }
+        }
+        )*
+    };
+}
+
+impl_complexity_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_complexity_float {
+    ($($ty:ty),*) => {
+        $(
+        impl ComplexityValue for $ty {
+            fn into_complexity(self) -> usize {
+                if self.is_nan() {
+                    usize::MAX
+                } else {
+                    self as usize
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_complexity_float!(f32, f64);
+
+/// Evaluates a complexity expression.
+///
+/// A panic while evaluating the expression, e.g. a division by zero, fails
+/// the validation of the query instead of unwinding.
+#[doc(hidden)]
+pub fn eval<T: ComplexityValue>(f: impl FnOnce() -> T) -> ServerResult<usize> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map(ComplexityValue::into_complexity)
+        .map_err(|_| ServerError::new("Failed to evaluate the complexity expression.", None))
+}
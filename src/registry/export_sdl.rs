@@ -156,9 +156,23 @@ impl Registry {
         });
 
         if options.federation {
+            // The authorization directives require federation v2.5, the link is
+            // only upgraded when they are used.
+            let uses_auth = self.types.values().any(|ty| {
+                ty.fields().is_some_and(|fields| {
+                    fields
+                        .values()
+                        .any(|field| field.authenticated || !field.requires_scopes.is_empty())
+                })
+            });
             writeln!(sdl, "extend schema @link(").ok();
-            writeln!(sdl, "\turl: \"https://specs.apollo.dev/federation/v2.3\",").ok();
-            writeln!(sdl, "\timport: [\"@key\", \"@tag\", \"@shareable\", \"@inaccessible\", \"@override\", \"@external\", \"@provides\", \"@requires\", \"@composeDirective\", \"@interfaceObject\"]").ok();
+            if uses_auth {
+                writeln!(sdl, "\turl: \"https://specs.apollo.dev/federation/v2.5\",").ok();
+                writeln!(sdl, "\timport: [\"@key\", \"@tag\", \"@shareable\", \"@inaccessible\", \"@override\", \"@external\", \"@provides\", \"@requires\", \"@composeDirective\", \"@interfaceObject\", \"@authenticated\", \"@requiresScopes\"]").ok();
+            } else {
+                writeln!(sdl, "\turl: \"https://specs.apollo.dev/federation/v2.3\",").ok();
+                writeln!(sdl, "\timport: [\"@key\", \"@tag\", \"@shareable\", \"@inaccessible\", \"@override\", \"@external\", \"@provides\", \"@requires\", \"@composeDirective\", \"@interfaceObject\"]").ok();
+            }
             writeln!(sdl, ")").ok();
 
             if options.compose_directive {
@@ -279,6 +293,13 @@ impl Registry {
                 write!(sdl, " {}", directive.sdl()).ok();
             }
 
+            if let Some(cost) = field.cost {
+                write!(sdl, " @cost(weight: \"{}\")", cost).ok();
+            }
+            if let Some(list_size) = &field.list_size {
+                write!(sdl, " {}", list_size.sdl()).ok();
+            }
+
             if options.federation {
                 if field.external {
                     write!(sdl, " @external").ok();
@@ -301,6 +322,23 @@ impl Registry {
                 if let Some(from) = &field.override_from {
                     write!(sdl, " @override(from: \"{}\")", from).ok();
                 }
+                if field.authenticated {
+                    write!(sdl, " @authenticated").ok();
+                }
+                if !field.requires_scopes.is_empty() {
+                    let scopes = field
+                        .requires_scopes
+                        .iter()
+                        .map(|scopes| {
+                            let scopes = scopes
+                                .iter()
+                                .map(|scope| format!("\"{}\"", scope.replace('"', "\\\"")))
+                                .collect::<Vec<_>>();
+                            format!("[{}]", scopes.join(", "))
+                        })
+                        .collect::<Vec<_>>();
+                    write!(sdl, " @requiresScopes(scopes: [{}])", scopes.join(", ")).ok();
+                }
             }
 
             writeln!(sdl).ok();
@@ -765,6 +803,7 @@ extend schema @link(
             locations: vec![__DirectiveLocation::FIELD_DEFINITION],
             args: Default::default(),
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: Some("https://custom.spec.dev/extension/v1.0".to_string()),
         });
@@ -821,6 +860,7 @@ schema {
             ]
             .into(),
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
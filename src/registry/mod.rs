@@ -1,6 +1,9 @@
 mod cache_control;
+pub mod complexity;
 mod export_sdl;
+mod naming;
 mod stringify_exec_doc;
+mod warnings;
 
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
@@ -11,12 +14,14 @@ use std::{
 pub use cache_control::CacheControl;
 pub use export_sdl::SDLExportOptions;
 use indexmap::{map::IndexMap, set::IndexSet};
+pub use naming::NamingConvention;
+pub use warnings::{SchemaWarning, SchemaWarningKind};
 
 pub use crate::model::{__DirectiveLocation, location_traits};
 use crate::{
     model::__Schema,
     parser::types::{BaseType as ParsedBaseType, Field, Type as ParsedType, VariableDefinition},
-    schema::IntrospectionMode,
+    schema::{IntrospectionMode, Locale},
     Any, Context, InputType, OutputType, Positioned, ServerResult, SubscriptionType, Value,
     VisitorContext, ID,
 };
@@ -234,12 +239,74 @@ pub struct MetaField {
     /// Mark the field as overriding a field currently present on another
     /// subgraph. It is used to migrate fields between subgraphs.
     pub override_from: Option<String>,
+    /// Indicate that the field can only be queried by authenticated users
+    /// when using Apollo Federation
+    pub authenticated: bool,
+    /// The scopes required to query the field when using Apollo Federation,
+    /// one of the inner lists must be granted entirely
+    pub requires_scopes: Vec<Vec<String>>,
     /// A constant or function to get the complexity
     pub compute_complexity: Option<ComputeComplexityFn>,
+    /// The weight of the field in the cost analysis, exported as the `@cost`
+    /// directive
+    pub cost: Option<usize>,
+    /// The size of the list returned by the field in the cost analysis,
+    /// exported as the `@listSize` directive
+    pub list_size: Option<MetaListSize>,
     /// Custom directive invocations
     pub directive_invocations: Vec<MetaDirectiveInvocation>,
 }
 
+/// The arguments of the `@listSize` directive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaListSize {
+    /// The size of the list if no slicing argument is provided
+    pub assumed_size: Option<usize>,
+    /// The arguments of the field that limit the size of the list, such as
+    /// `first` and `last`
+    pub slicing_arguments: Vec<String>,
+    /// The fields of the returned type the slicing arguments apply to, used
+    /// for connections where the list is nested, e.g. `edges`
+    pub sized_fields: Vec<String>,
+    /// Requires exactly one of the slicing arguments to be provided
+    pub require_one_slicing_argument: bool,
+}
+
+impl MetaListSize {
+    pub(crate) fn sdl(&self) -> String {
+        fn string_list(values: &[String]) -> String {
+            let values = values
+                .iter()
+                .map(|value| format!("\"{}\"", value))
+                .collect::<Vec<_>>();
+            format!("[{}]", values.join(", "))
+        }
+
+        let mut args = Vec::new();
+        if let Some(assumed_size) = self.assumed_size {
+            args.push(format!("assumedSize: {}", assumed_size));
+        }
+        if !self.slicing_arguments.is_empty() {
+            args.push(format!(
+                "slicingArguments: {}",
+                string_list(&self.slicing_arguments)
+            ));
+        }
+        if !self.sized_fields.is_empty() {
+            args.push(format!("sizedFields: {}", string_list(&self.sized_fields)));
+        }
+        if !self.require_one_slicing_argument {
+            args.push("requireOneSlicingArgument: false".to_string());
+        }
+
+        if args.is_empty() {
+            "@listSize".to_string()
+        } else {
+            format!("@listSize({})", args.join(", "))
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct MetaEnumValue {
     pub name: String,
@@ -616,6 +683,36 @@ impl MetaType {
         }
     }
 
+    #[inline]
+    pub fn directive_invocations(&self) -> &[MetaDirectiveInvocation] {
+        match self {
+            MetaType::Scalar {
+                directive_invocations,
+                ..
+            }
+            | MetaType::Object {
+                directive_invocations,
+                ..
+            }
+            | MetaType::Interface {
+                directive_invocations,
+                ..
+            }
+            | MetaType::Union {
+                directive_invocations,
+                ..
+            }
+            | MetaType::Enum {
+                directive_invocations,
+                ..
+            }
+            | MetaType::InputObject {
+                directive_invocations,
+                ..
+            } => directive_invocations,
+        }
+    }
+
     #[inline]
     pub fn is_visible(&self, ctx: &Context<'_>) -> bool {
         let visible = match self {
@@ -722,11 +819,81 @@ pub struct MetaDirective {
     pub locations: Vec<__DirectiveLocation>,
     pub args: IndexMap<String, MetaInputValue>,
     pub is_repeatable: bool,
+    /// If `true`, the applications of the directive are exposed by the
+    /// `appliedDirectives` introspection fields.
+    pub(crate) is_introspectable: bool,
     pub visible: Option<MetaVisibleFn>,
     pub composable: Option<String>,
 }
 
 impl MetaDirective {
+    /// Create a directive with a name and the locations it can be used on.
+    pub fn new(name: impl Into<String>, locations: Vec<__DirectiveLocation>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            locations,
+            args: Default::default(),
+            is_repeatable: false,
+            is_introspectable: false,
+            visible: None,
+            composable: None,
+        }
+    }
+
+    /// Set the description of the directive.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add an argument to the directive.
+    #[must_use]
+    pub fn argument(mut self, argument: MetaInputValue) -> Self {
+        self.args.insert(argument.name.clone(), argument);
+        self
+    }
+
+    /// Set whether the directive can be used several times on the same
+    /// location.
+    #[must_use]
+    pub fn repeatable(mut self, is_repeatable: bool) -> Self {
+        self.is_repeatable = is_repeatable;
+        self
+    }
+
+    /// Set whether the applications of the directive are exposed by the
+    /// `appliedDirectives` introspection fields, see
+    /// [`SchemaBuilder::enable_applied_directives_introspection`](crate::SchemaBuilder::enable_applied_directives_introspection).
+    #[must_use]
+    pub fn introspectable(mut self, is_introspectable: bool) -> Self {
+        self.is_introspectable = is_introspectable;
+        self
+    }
+
+    /// Set the function that checks if the directive is visible.
+    #[must_use]
+    pub fn visible(mut self, visible: MetaVisibleFn) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Set the specification URL of the directive, to compose it with
+    /// Apollo Federation's `@composeDirective`.
+    #[must_use]
+    pub fn composable(mut self, url: impl Into<String>) -> Self {
+        self.composable = Some(url.into());
+        self
+    }
+
+    /// Returns `true` if the applications of the directive are exposed by the
+    /// `appliedDirectives` introspection fields.
+    #[inline]
+    pub fn is_introspectable(&self) -> bool {
+        self.is_introspectable
+    }
+
     pub(crate) fn sdl(&self) -> String {
         let mut sdl = format!("directive @{}", self.name);
         if !self.args.is_empty() {
@@ -738,6 +905,9 @@ impl MetaDirective {
                 .join(", ");
             write!(sdl, "({})", args).ok();
         }
+        if self.is_repeatable {
+            sdl.push_str(" repeatable");
+        }
         let locations = self
             .locations
             .iter()
@@ -772,6 +942,21 @@ pub struct Registry {
     pub federation_subscription: bool,
     pub ignore_name_conflicts: HashSet<String>,
     pub enable_suggestions: bool,
+    /// If `true`, the introspection exposes the directives applied on the
+    /// fields, see
+    /// [`SchemaBuilder::enable_applied_directives_introspection`](crate::SchemaBuilder::enable_applied_directives_introspection).
+    pub introspect_applied_directives: bool,
+    /// The descriptions of fields for each locale, keyed by locale, type name
+    /// and field name, or `field(argument:)` for arguments.
+    pub localized_descriptions: HashMap<String, HashMap<String, HashMap<String, String>>>,
+    /// The naming convention of the fields and arguments generated from Rust
+    /// identifiers.
+    pub naming_convention: NamingConvention,
+    /// The types with a [`Lazy`](crate::Lazy) field, whose fields wait for
+    /// each other when resolved concurrently.
+    pub lazy_types: HashSet<String>,
+    /// The names of the types being created, the innermost last.
+    pub(crate) creating_types: Vec<String>,
 }
 
 impl Registry {
@@ -801,6 +986,7 @@ impl Registry {
                 args
             },
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
@@ -830,6 +1016,7 @@ impl Registry {
                 args
             },
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
@@ -868,6 +1055,7 @@ impl Registry {
                 args
             },
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
@@ -896,6 +1084,7 @@ impl Registry {
                 args
             },
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
@@ -910,6 +1099,7 @@ impl Registry {
             locations: vec![__DirectiveLocation::INPUT_OBJECT],
             args: Default::default(),
             is_repeatable: false,
+            is_introspectable: false,
             visible: None,
             composable: None,
         });
@@ -991,14 +1181,28 @@ impl Registry {
                 // exist.
                 self.types
                     .insert(name.to_string(), type_id.create_fake_type(rust_typename));
+                self.creating_types.push(name.to_string());
                 let ty = f(self);
+                self.creating_types.pop();
                 *self.types.get_mut(name).unwrap() = ty;
             }
         }
     }
 
+    /// Marks the type being created as having a [`Lazy`](crate::Lazy)
+    /// field.
+    pub(crate) fn add_lazy_field(&mut self) {
+        if let Some(name) = self.creating_types.last() {
+            self.lazy_types.insert(name.clone());
+        }
+    }
+
     pub fn create_fake_output_type<T: OutputType>(&mut self) -> MetaType {
         T::create_type_info(self);
+        // The fields of a merged object are resolved by the merged object.
+        if self.lazy_types.contains(&*T::type_name()) {
+            self.add_lazy_field();
+        }
         self.types
             .get(&*T::type_name())
             .cloned()
@@ -1021,6 +1225,73 @@ impl Registry {
             .expect("You definitely encountered a bug!")
     }
 
+    pub(crate) fn add_cost_directives(&mut self) {
+        let fields = || {
+            self.types
+                .values()
+                .filter_map(MetaType::fields)
+                .flat_map(|fields| fields.values())
+        };
+        let has_cost = fields().any(|field| field.cost.is_some());
+        let has_list_size = fields().any(|field| field.list_size.is_some());
+
+        let input_value = |name: &str, ty: &str, default_value: Option<&str>| MetaInputValue {
+            name: name.to_string(),
+            description: None,
+            ty: ty.to_string(),
+            deprecation: Deprecation::NoDeprecated,
+            default_value: default_value.map(ToString::to_string),
+            visible: None,
+            inaccessible: false,
+            tags: Default::default(),
+            is_secret: false,
+            directive_invocations: vec![],
+        };
+
+        if has_cost {
+            self.add_directive(MetaDirective {
+                name: "cost".into(),
+                description: Some("The weight of a field in the cost analysis.".to_string()),
+                locations: vec![__DirectiveLocation::FIELD_DEFINITION],
+                args: {
+                    let mut args = IndexMap::new();
+                    args.insert("weight".to_string(), input_value("weight", "String!", None));
+                    args
+                },
+                is_repeatable: false,
+                is_introspectable: false,
+                visible: None,
+                composable: None,
+            });
+        }
+
+        if has_list_size {
+            self.add_directive(MetaDirective {
+                name: "listSize".into(),
+                description: Some(
+                    "The size of the list returned by a field in the cost analysis.".to_string(),
+                ),
+                locations: vec![__DirectiveLocation::FIELD_DEFINITION],
+                args: {
+                    let mut args = IndexMap::new();
+                    for (name, ty, default_value) in [
+                        ("assumedSize", "Int", None),
+                        ("slicingArguments", "[String!]", None),
+                        ("sizedFields", "[String!]", None),
+                        ("requireOneSlicingArgument", "Boolean", Some("true")),
+                    ] {
+                        args.insert(name.to_string(), input_value(name, ty, default_value));
+                    }
+                    args
+                },
+                is_repeatable: false,
+                is_introspectable: false,
+                visible: None,
+                composable: None,
+            });
+        }
+    }
+
     pub fn add_directive(&mut self, directive: MetaDirective) {
         self.directives
             .insert(directive.name.to_string(), directive);
@@ -1123,8 +1394,12 @@ impl Registry {
                     inaccessible: false,
                     tags: Default::default(),
                     override_from: None,
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     visible: None,
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     directive_invocations: vec![],
                 },
             );
@@ -1181,7 +1456,11 @@ impl Registry {
                         inaccessible: false,
                         tags: Default::default(),
                         override_from: None,
+                        authenticated: false,
+                        requires_scopes: Default::default(),
                         compute_complexity: None,
+                        cost: None,
+                        list_size: None,
                         directive_invocations: vec![],
                     },
                 );
@@ -1210,7 +1489,11 @@ impl Registry {
                     tags: Default::default(),
                     visible: None,
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     override_from: None,
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     directive_invocations: vec![],
                 },
             );
@@ -1249,8 +1532,12 @@ impl Registry {
                     inaccessible: false,
                     tags: Default::default(),
                     override_from: None,
+                    authenticated: false,
+                    requires_scopes: Default::default(),
                     visible: None,
                     compute_complexity: None,
+                    cost: None,
+                    list_size: None,
                     directive_invocations: vec![],
                 },
             );
@@ -1284,7 +1571,11 @@ impl Registry {
                             inaccessible: false,
                             tags: Default::default(),
                             override_from: None,
+                            authenticated: false,
+                            requires_scopes: Default::default(),
                             compute_complexity: None,
+                            cost: None,
+                            list_size: None,
                             directive_invocations: vec![],
                         },
                     );
@@ -1365,6 +1656,84 @@ impl Registry {
         }
     }
 
+    /// Set the description of a field, an argument (`field(argument:)`), an
+    /// input field or an enum value, returns `false` if it doesn't exist.
+    pub fn set_field_description(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+        desc: impl Into<String>,
+    ) -> bool {
+        match self.field_description_mut(type_name, field_name) {
+            Some(description) => {
+                *description = Some(desc.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the description of a field, an argument (`field(argument:)`),
+    /// an input field or an enum value.
+    pub(crate) fn field_description_mut(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&mut Option<String>> {
+        match self.types.get_mut(type_name)? {
+            MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                match field_name
+                    .strip_suffix(":)")
+                    .and_then(|field_name| field_name.split_once('('))
+                {
+                    Some((field_name, arg_name)) => fields
+                        .get_mut(field_name)?
+                        .args
+                        .get_mut(arg_name)
+                        .map(|arg| &mut arg.description),
+                    None => fields
+                        .get_mut(field_name)
+                        .map(|field| &mut field.description),
+                }
+            }
+            MetaType::InputObject { input_fields, .. } => input_fields
+                .get_mut(field_name)
+                .map(|field| &mut field.description),
+            MetaType::Enum { enum_values, .. } => enum_values
+                .get_mut(field_name)
+                .map(|value| &mut value.description),
+            _ => None,
+        }
+    }
+
+    /// Returns the description of a field, an argument (`field(argument:)`),
+    /// an input field or an enum value for the locale of the current request.
+    pub fn localized_description(
+        &self,
+        ctx: &Context<'_>,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&str> {
+        let locale = ctx.data_opt::<Locale>()?;
+        self.localized_descriptions
+            .get(&locale.0)?
+            .get(type_name)?
+            .get(field_name)
+            .map(String::as_str)
+    }
+
+    /// Removes the `appliedDirectives` fields of the introspection types and
+    /// their types, they are only part of the introspection when enabled.
+    pub(crate) fn remove_applied_directives_introspection(&mut self) {
+        for name in ["__Type", "__Field", "__InputValue", "__EnumValue"] {
+            if let Some(MetaType::Object { fields, .. }) = self.types.get_mut(name) {
+                fields.shift_remove("appliedDirectives");
+            }
+        }
+        self.types.remove("__AppliedDirective");
+        self.types.remove("__DirectiveArgument");
+    }
+
     pub fn remove_unused_types(&mut self) {
         let mut used_types = BTreeSet::new();
         let mut unused_types = BTreeSet::new();
@@ -0,0 +1,34 @@
+/// The naming convention of fields and arguments, see
+/// [`Schema::build_with_naming_convention`](crate::Schema::build_with_naming_convention).
+///
+/// Only the names generated from Rust identifiers are affected, names set
+/// with `#[graphql(name = "...")]` or a `rename_fields`/`rename_args` rule are
+/// kept as they are.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum NamingConvention {
+    /// `camelCase`, the default.
+    #[default]
+    CamelCase,
+    /// `snake_case`.
+    SnakeCase,
+    /// The Rust identifiers, as they are written.
+    Verbatim,
+}
+
+impl NamingConvention {
+    /// Returns the name following the naming convention, among the names
+    /// generated from a Rust identifier.
+    #[doc(hidden)]
+    pub fn name(
+        self,
+        camel_case: &'static str,
+        snake_case: &'static str,
+        verbatim: &'static str,
+    ) -> &'static str {
+        match self {
+            NamingConvention::CamelCase => camel_case,
+            NamingConvention::SnakeCase => snake_case,
+            NamingConvention::Verbatim => verbatim,
+        }
+    }
+}
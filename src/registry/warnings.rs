@@ -0,0 +1,195 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::registry::{is_system_type, MetaField, MetaInputValue, MetaType, Registry};
+
+/// The category of a [`SchemaWarning`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SchemaWarningKind {
+    /// A deprecated input field or argument is required, clients can't stop
+    /// using it.
+    Deprecation,
+    /// A name doesn't follow the GraphQL conventions, `PascalCase` for types,
+    /// `camelCase` for fields and arguments and `SCREAMING_SNAKE_CASE` for
+    /// enum values.
+    Naming,
+    /// A type or a field has no description.
+    MissingDescription,
+    /// A description set with
+    /// [`SchemaBuilder::descriptions`](crate::SchemaBuilder::descriptions) or
+    /// [`SchemaBuilder::localized_descriptions`](crate::SchemaBuilder::localized_descriptions)
+    /// doesn't match any field.
+    UnknownDescription,
+}
+
+/// A non-fatal issue of a schema, returned by
+/// [`SchemaBuilder::finish_with_warnings`](crate::SchemaBuilder::finish_with_warnings).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SchemaWarning {
+    /// The category of the warning.
+    pub kind: SchemaWarningKind,
+    /// The coordinate of the schema element, such as `Type`, `Type.field` or
+    /// `Type.field(argument:)`.
+    pub path: String,
+    /// The warning message.
+    pub message: String,
+}
+
+impl Display for SchemaWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl SchemaWarning {
+    pub(crate) fn new(kind: SchemaWarningKind, path: String, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path,
+            message: message.into(),
+        }
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains('_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) && !name.contains('_')
+}
+
+fn is_screaming_snake_case(name: &str) -> bool {
+    !name.chars().any(|c| c.is_ascii_lowercase())
+}
+
+impl Registry {
+    /// Returns the non-fatal issues of the schema, types and fields provided
+    /// by the GraphQL specification or by federation are not checked.
+    pub(crate) fn check_warnings(&self) -> Vec<SchemaWarning> {
+        let mut warnings = Vec::new();
+
+        for ty in self.types.values() {
+            let type_name = ty.name();
+            if is_system_type(type_name) || type_name.starts_with('_') {
+                continue;
+            }
+
+            if !is_pascal_case(type_name) {
+                warnings.push(SchemaWarning::new(
+                    SchemaWarningKind::Naming,
+                    type_name.to_string(),
+                    "The type name should be PascalCase.",
+                ));
+            }
+
+            let description = match ty {
+                MetaType::Scalar { description, .. }
+                | MetaType::Object { description, .. }
+                | MetaType::Interface { description, .. }
+                | MetaType::Union { description, .. }
+                | MetaType::Enum { description, .. }
+                | MetaType::InputObject { description, .. } => description,
+            };
+            if description.is_none() {
+                warnings.push(SchemaWarning::new(
+                    SchemaWarningKind::MissingDescription,
+                    type_name.to_string(),
+                    "The type has no description.",
+                ));
+            }
+
+            match ty {
+                MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                    for field in fields.values() {
+                        check_field(&mut warnings, type_name, field);
+                    }
+                }
+                MetaType::InputObject { input_fields, .. } => {
+                    for input_field in input_fields.values() {
+                        check_input_value(
+                            &mut warnings,
+                            format!("{}.{}", type_name, input_field.name),
+                            "input field",
+                            input_field,
+                        );
+                        if input_field.description.is_none() {
+                            warnings.push(SchemaWarning::new(
+                                SchemaWarningKind::MissingDescription,
+                                format!("{}.{}", type_name, input_field.name),
+                                "The input field has no description.",
+                            ));
+                        }
+                    }
+                }
+                MetaType::Enum { enum_values, .. } => {
+                    for value in enum_values.values() {
+                        if !is_screaming_snake_case(&value.name) {
+                            warnings.push(SchemaWarning::new(
+                                SchemaWarningKind::Naming,
+                                format!("{}.{}", type_name, value.name),
+                                "The enum value should be SCREAMING_SNAKE_CASE.",
+                            ));
+                        }
+                    }
+                }
+                MetaType::Scalar { .. } | MetaType::Union { .. } => {}
+            }
+        }
+
+        warnings
+    }
+}
+
+fn check_field(warnings: &mut Vec<SchemaWarning>, type_name: &str, field: &MetaField) {
+    if field.name.starts_with('_') {
+        return;
+    }
+
+    let path = format!("{}.{}", type_name, field.name);
+    if !is_camel_case(&field.name) {
+        warnings.push(SchemaWarning::new(
+            SchemaWarningKind::Naming,
+            path.clone(),
+            "The field name should be camelCase.",
+        ));
+    }
+    if field.description.is_none() {
+        warnings.push(SchemaWarning::new(
+            SchemaWarningKind::MissingDescription,
+            path,
+            "The field has no description.",
+        ));
+    }
+
+    for arg in field.args.values() {
+        check_input_value(
+            warnings,
+            format!("{}.{}({}:)", type_name, field.name, arg.name),
+            "argument",
+            arg,
+        );
+    }
+}
+
+fn check_input_value(
+    warnings: &mut Vec<SchemaWarning>,
+    path: String,
+    kind: &str,
+    value: &MetaInputValue,
+) {
+    if !is_camel_case(&value.name) {
+        warnings.push(SchemaWarning::new(
+            SchemaWarningKind::Naming,
+            path.clone(),
+            format!("The {} name should be camelCase.", kind),
+        ));
+    }
+    if value.deprecation.is_deprecated() && value.ty.ends_with('!') && value.default_value.is_none()
+    {
+        warnings.push(SchemaWarning::new(
+            SchemaWarningKind::Deprecation,
+            path,
+            format!("The {} is deprecated but required.", kind),
+        ));
+    }
+}
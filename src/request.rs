@@ -1,12 +1,13 @@
 use std::{
     any::Any,
     fmt::{self, Debug, Formatter},
+    time::Instant,
 };
 
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
-    parser::{parse_query, types::ExecutableDocument},
+    parser::{parse_query, parse_query_recovering, types::ExecutableDocument},
     schema::IntrospectionMode,
     Data, Extensions, ParseRequestError, ServerError, UploadValue, Value, Variables,
 };
@@ -53,6 +54,16 @@ pub struct Request {
     /// [IntrospectionMode::Enabled]).
     #[serde(skip)]
     pub introspection_mode: IntrospectionMode,
+
+    /// The deadline of the request, which can be accessed through
+    /// `Context::deadline`.
+    #[serde(skip)]
+    pub deadline: Option<Instant>,
+
+    /// Whether the request is a dry run (defaults to `false`), see
+    /// [`Request::dry_run`].
+    #[serde(skip)]
+    pub dry_run: bool,
 }
 
 impl Request {
@@ -67,6 +78,8 @@ impl Request {
             extensions: Default::default(),
             parsed_query: None,
             introspection_mode: IntrospectionMode::Enabled,
+            deadline: None,
+            dry_run: false,
         }
     }
 
@@ -106,6 +119,33 @@ impl Request {
         self
     }
 
+    /// Execute this request as a dry run.
+    ///
+    /// The query is parsed and validated, then its selection set is walked
+    /// with the types of the schema, but no resolver is called. The response
+    /// has the shape of a real response, with placeholder values, see
+    /// [`SchemaBuilder::dry_run_placeholder`](crate::SchemaBuilder::dry_run_placeholder).
+    ///
+    /// The `@skip` and `@include` directives are evaluated, the custom
+    /// directives are applied to the placeholders, and the introspection
+    /// fields are resolved as usual.
+    #[must_use]
+    pub fn dry_run(self, dry_run: bool) -> Self {
+        Self { dry_run, ..self }
+    }
+
+    /// Set the deadline of the request.
+    ///
+    /// The request isn't cancelled when the deadline is reached, resolvers
+    /// can read it with `Context::deadline` to skip expensive work.
+    #[must_use]
+    pub fn deadline(self, deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
     #[inline]
     /// Performs parsing of query ahead of execution.
     ///
@@ -124,6 +164,23 @@ impl Request {
         Ok(self.parsed_query.as_ref().unwrap())
     }
 
+    /// Performs parsing of query ahead of execution, like
+    /// [`Request::parsed_query`], but reports every syntax error of the
+    /// query.
+    ///
+    /// The parser resumes at the next top-level definition after a syntax
+    /// error, see [`parse_query_recovering`](crate::parser::parse_query_recovering).
+    pub fn parsed_query_recovering(&mut self) -> Result<&ExecutableDocument, Vec<ServerError>> {
+        if self.parsed_query.is_none() {
+            match parse_query_recovering(&self.query) {
+                Ok(parsed) => self.parsed_query = Some(parsed),
+                Err(errors) => return Err(errors.into_iter().map(Into::into).collect()),
+            }
+        }
+
+        Ok(self.parsed_query.as_ref().unwrap())
+    }
+
     /// Sets the parsed query into the request.
     ///
     /// This is useful special with dynamic schema when the query has been
@@ -378,6 +435,23 @@ mod tests {
         assert!(request.variables.is_empty());
     }
 
+    #[test]
+    fn test_deserialize_request_with_malformed_extensions() {
+        let request: Request = from_value(value! ({
+            "query": "{ a b c }",
+            "extensions": [1, 2, 3]
+        }))
+        .unwrap();
+        assert!(request.extensions.is_empty());
+
+        let request: Request = from_value(value! ({
+            "query": "{ a b c }",
+            "extensions": { "a": 1 }
+        }))
+        .unwrap();
+        assert_eq!(request.extensions.get("a"), Some(&value!(1)));
+    }
+
     #[test]
     fn test_batch_request_single() {
         let request: BatchRequest = from_value(value! ({
@@ -418,4 +492,32 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn test_request_parsed_query_recovering() {
+        let mut request = Request::new("query A { a(x: ) }\nquery B { b @ }");
+        let errors = request.parsed_query_recovering().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].locations,
+            vec![Pos {
+                line: 1,
+                column: 16
+            }]
+        );
+        assert_eq!(
+            errors[1].locations,
+            vec![Pos {
+                line: 2,
+                column: 15
+            }]
+        );
+        assert!(errors
+            .iter()
+            .all(|err| err.code() == Some(ErrorCode::ParseFailed)));
+
+        let mut request = Request::new("query A { a } query B { b }");
+        assert!(request.parsed_query_recovering().is_ok());
+        assert!(request.parsed_query().is_ok());
+    }
 }
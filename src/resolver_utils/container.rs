@@ -1,11 +1,17 @@
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
 
-use futures_util::FutureExt;
+use futures_util::{future::BoxFuture, FutureExt};
 use indexmap::IndexMap;
 
 use crate::{
-    extensions::ResolveInfo, parser::types::Selection, Context, ContextBase, ContextSelectionSet,
-    Error, Name, OutputType, ServerError, ServerResult, Value,
+    context::GuardMode, extensions::ResolveInfo, parser::types::Selection, schema::FieldResolverFn,
+    Context, ContextBase, ContextSelectionSet, Error, Name, OutputType, ServerError, ServerResult,
+    Value,
 };
 
 /// Represents a GraphQL container object.
@@ -20,6 +26,15 @@ pub trait ContainerType: OutputType {
         false
     }
 
+    /// Returns true if the fields of this type only check their guard,
+    /// without being resolved, when the guards of a mutation are checked
+    /// first, see
+    /// [`SchemaBuilder::check_mutation_guards_first`](crate::SchemaBuilder::check_mutation_guards_first).
+    #[doc(hidden)]
+    fn can_check_guards() -> bool {
+        false
+    }
+
     /// Resolves a field value and outputs it as a json value
     /// `async_graphql::Value`.
     ///
@@ -76,6 +91,10 @@ pub trait ContainerType: OutputType {
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
 impl<T: ContainerType + ?Sized> ContainerType for &T {
+    fn can_check_guards() -> bool {
+        T::can_check_guards()
+    }
+
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         T::resolve_field(*self, ctx).await
     }
@@ -87,6 +106,10 @@ impl<T: ContainerType + ?Sized> ContainerType for &T {
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
 impl<T: ContainerType + ?Sized> ContainerType for Arc<T> {
+    fn can_check_guards() -> bool {
+        T::can_check_guards()
+    }
+
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         T::resolve_field(self, ctx).await
     }
@@ -98,6 +121,10 @@ impl<T: ContainerType + ?Sized> ContainerType for Arc<T> {
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
 impl<T: ContainerType + ?Sized> ContainerType for Box<T> {
+    fn can_check_guards() -> bool {
+        T::can_check_guards()
+    }
+
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         T::resolve_field(self, ctx).await
     }
@@ -109,6 +136,10 @@ impl<T: ContainerType + ?Sized> ContainerType for Box<T> {
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
 impl<T: ContainerType, E: Into<Error> + Send + Sync + Clone> ContainerType for Result<T, E> {
+    fn can_check_guards() -> bool {
+        T::can_check_guards()
+    }
+
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         match self {
             Ok(value) => T::resolve_field(value, ctx).await,
@@ -124,12 +155,13 @@ impl<T: ContainerType, E: Into<Error> + Send + Sync + Clone> ContainerType for R
     }
 }
 
-/// Resolve an container by executing each of the fields concurrently.
+/// Resolve an container by executing each of the fields concurrently, or
+/// serially in the subtree of a field with the `serial` attribute.
 pub async fn resolve_container<'a, T: ContainerType + ?Sized>(
     ctx: &ContextSelectionSet<'a>,
     root: &'a T,
 ) -> ServerResult<Value> {
-    resolve_container_inner(ctx, root, true).await
+    resolve_container_inner(ctx, root, !ctx.serial).await
 }
 
 /// Resolve an container by executing each of the fields serially.
@@ -140,6 +172,52 @@ pub async fn resolve_container_serial<'a, T: ContainerType + ?Sized>(
     resolve_container_inner(ctx, root, false).await
 }
 
+/// Resolve a container serially after checking the guards of all its fields,
+/// see
+/// [`SchemaBuilder::check_mutation_guards_first`](crate::SchemaBuilder::check_mutation_guards_first).
+pub(crate) async fn resolve_container_serial_guards_first<'a, T: ContainerType + ?Sized>(
+    ctx: &ContextSelectionSet<'a>,
+    root: &'a T,
+) -> ServerResult<Value> {
+    if !T::can_check_guards() {
+        return Err(ServerError::new(
+            format!(
+                r#"The guards of the fields of "{}" can not be checked before executing them, only the types defined with `#[Object]`, `#[derive(SimpleObject)]` or `#[derive(MergedObject)]` support it."#,
+                T::type_name()
+            ),
+            Some(ctx.item.pos),
+        ));
+    }
+
+    let ctx_check = ContextBase {
+        lazy_gate: None,
+        guard_mode: GuardMode::CheckOnly,
+        ..ctx.clone()
+    };
+    let mut fields = Fields(Vec::new());
+    fields.add_set(&ctx_check, root)?;
+    let mut checks = Vec::with_capacity(fields.0.len());
+    for field in fields.0 {
+        checks.push(field.await.err());
+    }
+
+    let ctx = ContextBase {
+        lazy_gate: None,
+        guard_mode: GuardMode::Skip,
+        ..ctx.clone()
+    };
+    let mut fields = Fields(Vec::new());
+    fields.add_set(&ctx, root)?;
+    let mut results = Vec::with_capacity(fields.0.len());
+    for (field, check) in fields.0.into_iter().zip(checks) {
+        if let Some(err) = check {
+            return Err(err);
+        }
+        results.push(field.await?);
+    }
+    Ok(create_value_object(results))
+}
+
 pub(crate) fn create_value_object(values: Vec<(Name, Value)>) -> Value {
     let mut map = IndexMap::new();
     for (name, value) in values {
@@ -174,29 +252,184 @@ fn insert_value(target: &mut IndexMap<Name, Value>, name: Name, value: Value) {
     }
 }
 
+/// Opens when every field of a container has either completed or is a
+/// `Lazy` waiting for the gate, so lazy fields only run once the other fields
+/// have succeeded, and are dropped with the container if one of them fails.
+#[derive(Default)]
+pub(crate) struct LazyGate(Mutex<LazyGateState>);
+
+#[derive(Default)]
+struct LazyGateState {
+    total: usize,
+    completed: usize,
+    waiting: usize,
+    wakers: Vec<Waker>,
+}
+
+impl LazyGateState {
+    fn is_open(&self) -> bool {
+        self.completed + self.waiting >= self.total
+    }
+
+    fn wake_if_open(&mut self) {
+        if self.is_open() {
+            self.wakers.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+impl LazyGate {
+    fn complete(&self) {
+        let mut state = self.0.lock().unwrap();
+        state.completed += 1;
+        state.wake_if_open();
+    }
+
+    /// Waits until all the other fields of the container have completed.
+    pub(crate) async fn wait(&self) {
+        {
+            let mut state = self.0.lock().unwrap();
+            state.waiting += 1;
+            state.wake_if_open();
+        }
+        futures_util::future::poll_fn(|cx| {
+            let mut state = self.0.lock().unwrap();
+            if state.is_open() {
+                Poll::Ready(())
+            } else {
+                state.wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
 async fn resolve_container_inner<'a, T: ContainerType + ?Sized>(
     ctx: &ContextSelectionSet<'a>,
     root: &'a T,
     parallel: bool,
 ) -> ServerResult<Value> {
+    let has_lazy_fields = parallel
+        && ctx
+            .schema_env
+            .registry
+            .lazy_types
+            .contains(&*root.introspection_type_name());
+    if !has_lazy_fields {
+        // Lazy fields run in place when the fields are resolved serially or
+        // the type has none.
+        let ctx = ContextBase {
+            lazy_gate: None,
+            ..ctx.clone()
+        };
+        let mut fields = Fields(Vec::new());
+        fields.add_set(&ctx, root)?;
+
+        let res = if parallel {
+            futures_util::future::try_join_all(fields.0).await?
+        } else {
+            let mut results = Vec::with_capacity(fields.0.len());
+            for field in fields.0 {
+                results.push(field.await?);
+            }
+            results
+        };
+        return Ok(create_value_object(res));
+    }
+
+    let gate = LazyGate::default();
+    let ctx = ContextBase {
+        lazy_gate: Some(&gate),
+        ..ctx.clone()
+    };
     let mut fields = Fields(Vec::new());
-    fields.add_set(ctx, root)?;
+    fields.add_set(&ctx, root)?;
+    *gate.0.lock().unwrap() = LazyGateState {
+        total: fields.0.len(),
+        ..Default::default()
+    };
 
-    let res = if parallel {
-        futures_util::future::try_join_all(fields.0).await?
-    } else {
-        let mut results = Vec::with_capacity(fields.0.len());
-        for field in fields.0 {
-            results.push(field.await?);
+    let res = futures_util::future::try_join_all(fields.0.into_iter().map(|field| {
+        let gate = &gate;
+        async move {
+            let res = field.await;
+            gate.complete();
+            res
         }
-        results
-    };
+    }))
+    .await?;
 
     Ok(create_value_object(res))
 }
 
+/// Returns the resolver overriding the field, looked up on the concrete type
+/// of the value first, so that the overrides of an object also apply when it
+/// is selected through an interface.
+fn field_override<'a, T: ContainerType + ?Sized>(
+    root: &T,
+    ctx: &Context<'a>,
+) -> Option<&'a FieldResolverFn> {
+    if ctx.schema_env.field_overrides.is_empty() {
+        return None;
+    }
+    let field_name = ctx.item.node.name.node.as_str();
+    ctx.schema_env
+        .field_override(&root.introspection_type_name(), field_name)
+        .or_else(|| ctx.schema_env.field_override(&T::type_name(), field_name))
+}
+
+async fn resolve_field<T: ContainerType + ?Sized>(
+    root: &T,
+    ctx: &Context<'_>,
+) -> ServerResult<Option<Value>> {
+    match field_override(root, ctx) {
+        Some(resolver) => resolver(ctx).await.map(Some),
+        None => root.resolve_field(ctx).await,
+    }
+}
+
 type BoxFieldFuture<'a> = Pin<Box<dyn Future<Output = ServerResult<(Name, Value)>> + 'a + Send>>;
 
+/// Wraps the future resolving a field with the custom directives applied on
+/// the field.
+pub(crate) fn apply_custom_directives<'a>(
+    ctx_field: &Context<'a>,
+    mut resolve_fut: BoxFuture<'a, ServerResult<Option<Value>>>,
+) -> ServerResult<BoxFuture<'a, ServerResult<Option<Value>>>> {
+    for directive in &ctx_field.item.node.directives {
+        if let Some(directive_factory) = ctx_field
+            .schema_env
+            .custom_directives
+            .get(directive.node.name.node.as_str())
+        {
+            let ctx_directive = ContextBase {
+                path_node: ctx_field.path_node,
+                is_for_introspection: false,
+                item: directive,
+                schema_env: ctx_field.schema_env,
+                query_env: ctx_field.query_env,
+                execute_data: ctx_field.execute_data,
+                lazy_gate: ctx_field.lazy_gate,
+                prepared: ctx_field.prepared,
+                guard_mode: ctx_field.guard_mode,
+                serial: ctx_field.serial,
+                parent_type: ctx_field.parent_type,
+            };
+            let directive_instance = directive_factory.create(&ctx_directive, &directive.node)?;
+            resolve_fut = Box::pin({
+                let ctx_field = ctx_field.clone();
+                async move {
+                    directive_instance
+                        .resolve_field(&ctx_field, &mut resolve_fut)
+                        .await
+                }
+            });
+        }
+    }
+    Ok(resolve_fut)
+}
+
 /// A set of fields on an container that are being selected.
 pub struct Fields<'a>(Vec<BoxFieldFuture<'a>>);
 
@@ -208,6 +441,15 @@ impl<'a> Fields<'a> {
         ctx: &ContextSelectionSet<'a>,
         root: &'a T,
     ) -> ServerResult<()> {
+        let ctx = &ContextBase {
+            parent_type: ctx
+                .schema_env
+                .registry
+                .types
+                .get_key_value(&*root.introspection_type_name())
+                .map(|(name, _)| name.as_str()),
+            ..ctx.clone()
+        };
         for selection in &ctx.item.node.items {
             match &selection.node {
                 Selection::Field(field) => {
@@ -230,10 +472,19 @@ impl<'a> Fields<'a> {
                             let field_name = ctx_field.item.node.response_key().node.clone();
                             let extensions = &ctx.query_env.extensions;
 
+                            if ctx_field.guard_mode() == GuardMode::CheckOnly {
+                                // Only the guards run, an overridden field has
+                                // no guard.
+                                if field_override(root, &ctx_field).is_none() {
+                                    root.resolve_field(&ctx_field).await?;
+                                }
+                                return Ok((field_name, Value::Null));
+                            }
+
                             if extensions.is_empty() && field.node.directives.is_empty() {
                                 Ok((
                                     field_name,
-                                    root.resolve_field(&ctx_field).await?.unwrap_or_default(),
+                                    resolve_field(root, &ctx_field).await?.unwrap_or_default(),
                                 ))
                             } else {
                                 let type_name = T::type_name();
@@ -271,7 +522,7 @@ impl<'a> Fields<'a> {
                                     field: &field.node,
                                 };
 
-                                let resolve_fut = root.resolve_field(&ctx_field);
+                                let resolve_fut = resolve_field(root, &ctx_field);
 
                                 if field.node.directives.is_empty() {
                                     futures_util::pin_mut!(resolve_fut);
@@ -283,34 +534,8 @@ impl<'a> Fields<'a> {
                                             .unwrap_or_default(),
                                     ))
                                 } else {
-                                    let mut resolve_fut = resolve_fut.boxed();
-
-                                    for directive in &field.node.directives {
-                                        if let Some(directive_factory) = ctx
-                                            .schema_env
-                                            .custom_directives
-                                            .get(directive.node.name.node.as_str())
-                                        {
-                                            let ctx_directive = ContextBase {
-                                                path_node: ctx_field.path_node,
-                                                is_for_introspection: false,
-                                                item: directive,
-                                                schema_env: ctx_field.schema_env,
-                                                query_env: ctx_field.query_env,
-                                                execute_data: ctx_field.execute_data,
-                                            };
-                                            let directive_instance = directive_factory
-                                                .create(&ctx_directive, &directive.node)?;
-                                            resolve_fut = Box::pin({
-                                                let ctx_field = ctx_field.clone();
-                                                async move {
-                                                    directive_instance
-                                                        .resolve_field(&ctx_field, &mut resolve_fut)
-                                                        .await
-                                                }
-                                            });
-                                        }
-                                    }
+                                    let mut resolve_fut =
+                                        apply_custom_directives(&ctx_field, resolve_fut.boxed())?;
 
                                     Ok((
                                         field_name,
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::{
+    registry::{MetaType, MetaTypeName, Registry},
+    Context, OutputType, ServerError, ServerResult, Value,
+};
+
+/// A resolver of the fields of an object declared with
+/// `#[graphql(external_resolver)]`.
+///
+/// The fields of the object are declared by the fields of the struct, and
+/// resolved by the `Arc<dyn FieldResolver<T>>` found in the context data,
+/// which is added with
+/// [`SchemaBuilder::field_resolver`](crate::SchemaBuilder::field_resolver),
+/// or with the data of a request.
+#[async_trait::async_trait]
+pub trait FieldResolver<T: Sync>: Send + Sync {
+    /// Resolves the field `ctx.item` of `parent`, returns `None` if the
+    /// field is unknown to this resolver, which is reported as an error. A
+    /// value not matching the type of the field is reported as an error too.
+    ///
+    /// The fields of object types must resolve their own selection set, for
+    /// example with
+    /// `OutputType::resolve(&obj, &ctx.with_selection_set(&ctx.item.node.selection_set), ctx.item)`.
+    async fn resolve_field(&self, parent: &T, ctx: &Context<'_>) -> ServerResult<Option<Value>>;
+}
+
+/// Resolves a field of an object declared with
+/// `#[graphql(external_resolver)]` with the `FieldResolver` found in the
+/// context data.
+#[doc(hidden)]
+pub async fn resolve_external_field<T: OutputType + Sync + 'static>(
+    parent: &T,
+    ctx: &Context<'_>,
+) -> ServerResult<Value> {
+    let resolver = ctx
+        .data::<Arc<dyn FieldResolver<T>>>()
+        .map_err(|err| err.into_server_error(ctx.item.pos))?;
+    let type_name = T::type_name();
+    let field_name = ctx.item.node.name.node.as_str();
+    let Some(value) = resolver.resolve_field(parent, ctx).await? else {
+        return Err(ServerError::new(
+            format!(r#"Unknown field "{}" on type "{}"."#, field_name, type_name),
+            Some(ctx.item.pos),
+        ));
+    };
+
+    let registry = &ctx.schema_env.registry;
+    let field = registry
+        .types
+        .get(&*type_name)
+        .and_then(|ty| ty.field_by_name(field_name));
+    if let Some(field) = field {
+        if !is_valid_output_value(registry, &field.ty, &value) {
+            return Err(ServerError::new(
+                format!(
+                    r#"Invalid value for field "{}" on type "{}", expected type "{}"."#,
+                    field_name, type_name, field.ty
+                ),
+                Some(ctx.item.pos),
+            ));
+        }
+    }
+    Ok(value)
+}
+
+/// Returns `true` if the value is an output value of the type, the fields of
+/// the objects are not checked.
+fn is_valid_output_value(registry: &Registry, type_name: &str, value: &Value) -> bool {
+    match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => {
+            *value != Value::Null && is_valid_output_value(registry, type_name, value)
+        }
+        MetaTypeName::List(type_name) => match value {
+            Value::Null => true,
+            Value::List(items) => items
+                .iter()
+                .all(|item| is_valid_output_value(registry, type_name, item)),
+            _ => false,
+        },
+        MetaTypeName::Named(type_name) => match (registry.types.get(type_name), value) {
+            (_, Value::Null) => true,
+            (
+                Some(MetaType::Scalar {
+                    is_valid: Some(is_valid),
+                    ..
+                }),
+                value,
+            ) => is_valid(value),
+            (Some(MetaType::Scalar { .. }), _) => true,
+            (Some(MetaType::Enum { enum_values, .. }), Value::Enum(name)) => {
+                enum_values.contains_key(name.as_str())
+            }
+            (
+                Some(MetaType::Object { .. } | MetaType::Interface { .. } | MetaType::Union { .. }),
+                Value::Object(_),
+            ) => true,
+            _ => false,
+        },
+    }
+}
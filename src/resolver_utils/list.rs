@@ -1,9 +1,12 @@
+use std::future::Future;
+
 use crate::{
     extensions::ResolveInfo, parser::types::Field, ContextSelectionSet, OutputType, Positioned,
     ServerResult, Value,
 };
 
-/// Resolve an list by executing each of the items concurrently.
+/// Resolve an list by executing each of the items concurrently, or serially in
+/// the subtree of a field with the `serial` attribute.
 pub async fn resolve_list<'a, T: OutputType + 'a>(
     ctx: &ContextSelectionSet<'a>,
     field: &Positioned<Field>,
@@ -43,9 +46,7 @@ pub async fn resolve_list<'a, T: OutputType + 'a>(
                 }
             });
         }
-        Ok(Value::List(
-            futures_util::future::try_join_all(futures).await?,
-        ))
+        join_items(ctx, futures).await
     } else {
         let mut futures = len.map(Vec::with_capacity).unwrap_or_default();
         for (idx, item) in iter.into_iter().enumerate() {
@@ -56,6 +57,21 @@ pub async fn resolve_list<'a, T: OutputType + 'a>(
                     .map_err(|err| ctx_idx.set_error_path(err))
             });
         }
+        join_items(ctx, futures).await
+    }
+}
+
+async fn join_items(
+    ctx: &ContextSelectionSet<'_>,
+    futures: Vec<impl Future<Output = ServerResult<Value>>>,
+) -> ServerResult<Value> {
+    if ctx.serial {
+        let mut values = Vec::with_capacity(futures.len());
+        for fut in futures {
+            values.push(fut.await?);
+        }
+        Ok(Value::List(values))
+    } else {
         Ok(Value::List(
             futures_util::future::try_join_all(futures).await?,
         ))
@@ -3,10 +3,12 @@
 
 mod container;
 mod r#enum;
+mod field_resolver;
 mod list;
 mod scalar;
 
 pub use container::*;
+pub use field_resolver::*;
 pub use list::*;
 pub use r#enum::*;
 pub use scalar::*;
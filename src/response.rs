@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::{CacheControl, Result, ServerError, Value};
+use crate::{CacheControl, DownloadValue, Result, ServerError, Value};
 
 /// Query response
 #[non_exhaustive]
@@ -27,6 +27,10 @@ pub struct Response {
     /// HTTP headers
     #[serde(skip)]
     pub http_headers: http::HeaderMap,
+
+    /// File downloads returned by the [`Download`](crate::Download) fields
+    #[serde(skip)]
+    pub downloads: Vec<DownloadValue>,
 }
 
 impl Response {
@@ -95,6 +99,30 @@ impl Response {
             Ok(self)
         }
     }
+
+    /// Deserialize the data of the response, returning it together with the
+    /// errors of the response.
+    ///
+    /// Fields that were nullified by an error are `null` in the data, so they
+    /// must be deserialized into an `Option`. The data is `None` if the
+    /// response has no data, or if it can't be deserialized, in which case
+    /// the deserialization error is added to the errors.
+    pub fn into_partial<T: DeserializeOwned>(self) -> (Option<T>, Vec<ServerError>) {
+        let mut errors = self.errors;
+        if self.data == Value::Null {
+            return (None, errors);
+        }
+        match crate::from_value(self.data) {
+            Ok(data) => (Some(data), errors),
+            Err(err) => {
+                errors.push(ServerError::new(
+                    format!("Failed to deserialize the response data: {}", err),
+                    None,
+                ));
+                (None, errors)
+            }
+        }
+    }
 }
 
 /// Response for batchable queries
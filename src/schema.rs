@@ -1,30 +1,44 @@
 use std::{
     any::Any,
     collections::{HashMap, HashSet},
+    future::Future,
     ops::Deref,
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 use async_graphql_parser::types::ExecutableDocument;
-use futures_util::stream::{self, BoxStream, FuturesOrdered, Stream, StreamExt};
+use futures_util::{
+    future::BoxFuture,
+    stream::{self, BoxStream, FuturesOrdered, Stream, StreamExt},
+};
+use serde::de::DeserializeOwned;
 
+#[cfg(feature = "tempfile")]
+use crate::types::spool_uploads;
 use crate::{
-    context::{Data, QueryEnvInner},
+    context::{Data, ExecuteChildFn, GuardMode, QueryEnvInner},
     custom_directive::CustomDirectiveFactory,
+    document_transform::visit_document,
+    dry_run::DryRun,
     extensions::{ExtensionFactory, Extensions},
     parser::{
         parse_query,
         types::{Directive, DocumentOperations, OperationType, Selection, SelectionSet},
         Positioned,
     },
-    registry::{Registry, SDLExportOptions},
-    resolver_utils::{resolve_container, resolve_container_serial},
+    registry::{
+        __DirectiveLocation, MetaDirective, NamingConvention, Registry, SDLExportOptions,
+        SchemaWarning, SchemaWarningKind,
+    },
+    resolver_utils::{
+        resolve_container, resolve_container_serial, resolve_container_serial_guards_first,
+    },
     subscription::collect_subscription_streams,
     types::QueryRoot,
-    validation::{check_rules, ValidationMode},
-    BatchRequest, BatchResponse, CacheControl, ContextBase, EmptyMutation, EmptySubscription,
-    Executor, InputType, ObjectType, OutputType, QueryEnv, Request, Response, ServerError,
-    ServerResult, SubscriptionType, Variables,
+    validation::{check_rules, coerce_variables, ValidationMode},
+    BatchRequest, BatchResponse, Context, ContextBase, DocumentVisitor, EmptyMutation,
+    EmptySubscription, ErrorCode, Executor, FieldResolver, InputType, ObjectType, OutputType,
+    QueryEnv, Request, Response, ServerError, ServerResult, SubscriptionType, Value, Variables,
 };
 
 /// Introspection mode
@@ -39,6 +53,24 @@ pub enum IntrospectionMode {
     Disabled,
 }
 
+/// The locale of a request, used to select the descriptions registered with
+/// [`SchemaBuilder::localized_descriptions`] in introspection.
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// let request = Request::new("{ __schema { queryType { name } } }").data(Locale::new("fr"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(pub String);
+
+impl Locale {
+    /// Create a locale.
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self(locale.into())
+    }
+}
+
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
     validation_mode: ValidationMode,
@@ -51,8 +83,17 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     depth: Option<usize>,
     recursive_depth: usize,
     max_directives: Option<usize>,
+    child_depth: usize,
     extensions: Vec<Box<dyn ExtensionFactory>>,
     custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    field_overrides: HashMap<String, HashMap<String, FieldResolverFn>>,
+    document_transforms: Vec<DocumentTransformFn>,
+    dry_run_placeholders: HashMap<String, Value>,
+    upload_spool_threshold: Option<usize>,
+    descriptions: HashMap<String, String>,
+    localized_descriptions: HashMap<String, HashMap<String, String>>,
+    check_mutation_guards_first: bool,
+    max_subscriptions_per_connection: Option<usize>,
 }
 
 impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription> {
@@ -123,8 +164,33 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum nesting of child requests executed by
+    /// [`Context::execute_child`](crate::Context::execute_child). (default: 8)
+    #[must_use]
+    pub fn limit_child_depth(mut self, depth: usize) -> Self {
+        self.child_depth = depth;
+        self
+    }
+
+    /// Set the maximum number of subscriptions running at the same time on a
+    /// websocket connection, see [`WebSocket`](crate::http::WebSocket). (default:
+    /// no limit)
+    ///
+    /// The subscriptions started beyond the limit are rejected with an error,
+    /// a subscription frees its slot when it completes or is stopped by the
+    /// client.
+    #[must_use]
+    pub fn max_subscriptions_per_connection(mut self, max_subscriptions: usize) -> Self {
+        self.max_subscriptions_per_connection = Some(max_subscriptions);
+        self
+    }
+
     /// Add an extension to the schema.
     ///
+    /// The extensions are called in the order they are added, see
+    /// [`Extension`](crate::extensions::Extension#order) for how each hook is
+    /// ordered.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -149,6 +215,36 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Returns the names of the registered extensions in the order they are
+    /// called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         100
+    ///     }
+    /// }
+    ///
+    /// let builder = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .extension(extensions::Analyzer);
+    /// let names = builder.extensions_list();
+    /// assert_eq!(names.len(), 1);
+    /// assert!(names[0].ends_with("Analyzer"));
+    /// ```
+    pub fn extensions_list(&self) -> Vec<&'static str> {
+        self.extensions
+            .iter()
+            .map(|extension| extension.name())
+            .collect()
+    }
+
     /// Add a global data that can be accessed in the `Schema`. You access it
     /// with `Context::data`.
     #[must_use]
@@ -157,6 +253,18 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Add the resolver of the fields of the object `T` declared with
+    /// `#[graphql(external_resolver)]`, see
+    /// [`FieldResolver`](crate::FieldResolver).
+    #[must_use]
+    pub fn field_resolver<T, R>(self, resolver: R) -> Self
+    where
+        T: Sync + 'static,
+        R: FieldResolver<T> + 'static,
+    {
+        self.data(Arc::new(resolver) as Arc<dyn FieldResolver<T>>)
+    }
+
     /// Set the validation mode, default is `ValidationMode::Strict`.
     #[must_use]
     pub fn validation_mode(mut self, validation_mode: ValidationMode) -> Self {
@@ -196,6 +304,324 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the descriptions of fields, arguments, input fields and enum values
+    /// from a map keyed by `"Type.field"`, or `"Type.field(argument:)"` for
+    /// arguments, replacing their doc comments in introspection and SDL.
+    ///
+    /// The descriptions are applied when the schema is built, keys that
+    /// don't match any field are ignored and reported as a
+    /// [`SchemaWarningKind::UnknownDescription`](crate::SchemaWarningKind::UnknownDescription)
+    /// warning by [`finish_with_warnings`](Self::finish_with_warnings), or
+    /// logged with the `log` or `tracing` feature by [`finish`](Self::finish).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     /// A value
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .descriptions(HashMap::from([(
+    ///         "Query.value".to_string(),
+    ///         "Une valeur".to_string(),
+    ///     )]))
+    ///     .finish();
+    /// assert!(schema.sdl().contains("Une valeur"));
+    /// ```
+    #[must_use]
+    pub fn descriptions(mut self, descriptions: HashMap<String, String>) -> Self {
+        self.descriptions.extend(descriptions);
+        self
+    }
+
+    /// Set the descriptions of fields, arguments, input fields and enum values
+    /// for a locale, keyed by `"Type.field"` or `"Type.field(argument:)"`
+    /// like [`descriptions`](Self::descriptions), which also describes how
+    /// unknown keys are reported.
+    ///
+    /// Introspection uses these descriptions when the request has a
+    /// [`Locale`] in its data, and falls back to the default descriptions if
+    /// the locale or the field has no description. The SDL always contains
+    /// the default descriptions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     /// A value
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .localized_descriptions(
+    ///         "fr",
+    ///         HashMap::from([("Query.value".to_string(), "Une valeur".to_string())]),
+    ///     )
+    ///     .finish();
+    /// let query = r#"{ __type(name: "Query") { fields { description } } }"#;
+    /// let res = schema
+    ///     .execute(Request::new(query).data(Locale::new("fr")))
+    ///     .await
+    ///     .into_result()
+    ///     .unwrap()
+    ///     .data;
+    /// assert_eq!(
+    ///     res,
+    ///     value!({ "__type": { "fields": [{ "description": "Une valeur" }] } })
+    /// );
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn localized_descriptions(
+        mut self,
+        locale: impl Into<String>,
+        descriptions: HashMap<String, String>,
+    ) -> Self {
+        self.localized_descriptions
+            .entry(locale.into())
+            .or_default()
+            .extend(descriptions);
+        self
+    }
+
+    /// Replace the resolver of a field, specified as `"Type.field"`, with a
+    /// closure.
+    ///
+    /// This is mostly useful for mocking fields in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field does not exist, or if the closure does not return a
+    /// value of the field type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .override_field("Query.value", |_| 100)
+    ///     .finish();
+    /// let res = schema.execute("{ value }").await.into_result().unwrap().data;
+    /// assert_eq!(res, value!({ "value": 100 }));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn override_field<T, F>(self, field: &str, f: F) -> Self
+    where
+        T: OutputType + 'static,
+        F: Fn(&Context<'_>) -> T + Send + Sync + 'static,
+    {
+        self.override_resolver(field, move |ctx| {
+            let value = f(ctx);
+            async move { Ok::<_, crate::Error>(value) }
+        })
+    }
+
+    /// Replace the resolver of a field, specified as `"Type.field"`, with an
+    /// async closure.
+    ///
+    /// The closure is called with the context of the field, and returns a
+    /// future that can't borrow the context, so the arguments and the data
+    /// must be extracted before the future is created.
+    ///
+    /// The override also applies when the field is selected through an
+    /// interface or a union implemented by the type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field does not exist, or if the closure does not return a
+    /// value of the field type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn add(&self, a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .data(2)
+    ///     .override_resolver("Query.add", |ctx| {
+    ///         let factor = *ctx.data_unchecked::<i32>();
+    ///         let a = ctx.param_value::<i32>("a", None).map(|(_, a)| a).unwrap_or_default();
+    ///         async move { Ok(a * factor) }
+    ///     })
+    ///     .finish();
+    /// let res = schema.execute("{ add(a: 3, b: 4) }").await.into_result().unwrap().data;
+    /// assert_eq!(res, value!({ "add": 6 }));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn override_resolver<T, F, R>(mut self, field: &str, f: F) -> Self
+    where
+        T: OutputType + 'static,
+        F: Fn(&Context<'_>) -> R + Send + Sync + 'static,
+        R: Future<Output = crate::Result<T>> + Send + 'static,
+    {
+        let Some((type_name, field_name)) = field.split_once('.') else {
+            panic!("The field `{}` must be specified as `Type.field`", field);
+        };
+        let field_ty = match self
+            .registry
+            .types
+            .get(type_name)
+            .and_then(|ty| ty.field_by_name(field_name))
+        {
+            Some(field) => field.ty.clone(),
+            None => panic!("The field `{}` is not defined", field),
+        };
+        let ty = T::create_type_info(&mut self.registry);
+        if ty != field_ty {
+            panic!(
+                "The field `{}` is of type `{}`, but the resolver returns `{}`",
+                field, field_ty, ty
+            );
+        }
+
+        self.field_overrides
+            .entry(type_name.to_string())
+            .or_default()
+            .insert(
+                field_name.to_string(),
+                Box::new(move |ctx| {
+                    let fut = f(ctx);
+                    Box::pin(async move {
+                        let value = fut
+                            .await
+                            .map_err(|err| err.into_server_error(ctx.item.pos))?;
+                        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                        OutputType::resolve(&value, &ctx_obj, ctx.item).await
+                    })
+                }),
+            );
+        self
+    }
+
+    /// Add a transform that rewrites the parsed document of every request.
+    ///
+    /// The transforms are applied in the order they are added, after the
+    /// query is parsed and before it is checked against the limits of the
+    /// schema and validated, so a transform producing a document that is too
+    /// deep or invalid results in the usual errors.
+    ///
+    /// See [`SchemaBuilder::add_document_visitor`] to rewrite the nodes of the
+    /// document without walking it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::{parser::types::ExecutableDocument, *};
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn a(&self) -> i32 {
+    ///         1
+    ///     }
+    ///
+    ///     async fn b(&self) -> i32 {
+    ///         2
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .add_document_transform(|doc: &mut ExecutableDocument| {
+    ///         *doc = parser::parse_query("{ b }").unwrap();
+    ///     })
+    ///     .finish();
+    /// let res = schema.execute("{ a }").await.into_result().unwrap().data;
+    /// assert_eq!(res, value!({ "b": 2 }));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn add_document_transform<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&mut ExecutableDocument) + Send + Sync + 'static,
+    {
+        self.document_transforms.push(Box::new(f));
+        self
+    }
+
+    /// Add a visitor that rewrites the nodes of the parsed document of every
+    /// request.
+    ///
+    /// The visitors are applied like the transforms added with
+    /// [`SchemaBuilder::add_document_transform`], in the order they are added,
+    /// see [`DocumentVisitor`] for an example.
+    #[must_use]
+    pub fn add_document_visitor(mut self, visitor: impl DocumentVisitor) -> Self {
+        self.document_transforms
+            .push(Box::new(move |doc| visit_document(&visitor, doc)));
+        self
+    }
+
+    /// Set the placeholder value of a custom scalar in the responses of dry
+    /// runs, see [`Request::dry_run`].
+    ///
+    /// The built-in scalars have a default placeholder, a non-null custom
+    /// scalar without a placeholder makes the dry run fail.
+    #[must_use]
+    pub fn dry_run_placeholder(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.dry_run_placeholders.insert(name.into(), value.into());
+        self
+    }
+
+    /// Move the uploaded files larger than `size` bytes to named temporary
+    /// files, whose paths are returned by
+    /// [`Upload::path`](crate::Upload::path).
+    ///
+    /// The files are moved when the request is executed, and removed when the
+    /// execution of the request is finished.
+    #[cfg(feature = "tempfile")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tempfile")))]
+    #[must_use]
+    pub fn upload_spool_threshold(mut self, size: usize) -> Self {
+        self.upload_spool_threshold = Some(size);
+        self
+    }
+
     /// Register a custom directive.
     ///
     /// # Panics
@@ -221,6 +647,92 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Register a type-system directive defined at runtime, so it is
+    /// included in the introspection and the SDL of the schema.
+    ///
+    /// The directive is only declared, it is not executed, so it can only be
+    /// used on the type-system locations, such as `OBJECT` or
+    /// `FIELD_DEFINITION`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directive with the same name is already registered, or
+    /// if the directive has no locations or is used on an executable location,
+    /// such as `FIELD`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::{registry::*, *};
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .register_directive(MetaDirective::new(
+    ///         "cached",
+    ///         vec![__DirectiveLocation::OBJECT],
+    ///     ))
+    ///     .finish();
+    /// assert!(schema.sdl().contains("directive @cached on OBJECT"));
+    /// ```
+    #[must_use]
+    pub fn register_directive(mut self, directive: MetaDirective) -> Self {
+        if directive.locations.is_empty() {
+            panic!("Directive `{}` has no locations", directive.name);
+        }
+        if let Some(location) = directive
+            .locations
+            .iter()
+            .find(|location| !is_type_system_location(**location))
+        {
+            panic!(
+                "Directive `{}` can't be used on `{}`, only the type-system locations are allowed",
+                directive.name,
+                location.to_value()
+            );
+        }
+        if self.registry.directives.contains_key(&directive.name)
+            || self.custom_directives.contains_key(&directive.name)
+        {
+            panic!("Directive `{}` already exists", directive.name);
+        }
+
+        self.registry.add_directive(directive);
+        self
+    }
+
+    /// Check the guards of all the top-level fields of a mutation before
+    /// executing the first one.
+    ///
+    /// The fields of a mutation are executed serially, so by default the guard
+    /// of a field runs after the previous fields have been executed, and sees
+    /// their side effects. With this option, the guards of all the fields run
+    /// first, against the state before the mutation, and are not run again
+    /// when the fields are executed.
+    ///
+    /// The guards are checked without resolving the fields, which is only
+    /// supported by the mutation roots defined with `#[Object]`,
+    /// `#[derive(SimpleObject)]` (with its `#[ComplexObject]`) or
+    /// `#[derive(MergedObject)]`, and the types they flatten. The methods
+    /// returning the flattened objects are still called. With another mutation
+    /// root, the mutations fail with an error before any field is executed.
+    ///
+    /// If the guard of a field fails, the fields before it are still executed,
+    /// and are not rolled back, then the mutation stops with the error of the
+    /// guard.
+    #[must_use]
+    pub fn check_mutation_guards_first(mut self) -> Self {
+        self.check_mutation_guards_first = true;
+        self
+    }
+
     /// Disable field suggestions.
     #[must_use]
     pub fn disable_suggestions(mut self) -> Self {
@@ -228,6 +740,20 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Expose the directives applied on the types, fields, arguments, input
+    /// fields and enum values with the `appliedDirectives` field of `__Type`,
+    /// `__Field`, `__InputValue` and `__EnumValue` in introspection queries.
+    ///
+    /// Only the directives defined with `#[TypeDirective(introspectable)]` or
+    /// registered with [`MetaDirective::introspectable`](crate::registry::MetaDirective::introspectable)
+    /// are exposed, with the values of their arguments as GraphQL literals.
+    /// The field is `null` for the list and non-null types.
+    #[must_use]
+    pub fn enable_applied_directives_introspection(mut self) -> Self {
+        self.registry.introspect_applied_directives = true;
+        self
+    }
+
     /// Make all fields sorted on introspection queries.
     pub fn with_sorted_fields(mut self) -> Self {
         use crate::registry::MetaType;
@@ -259,13 +785,75 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
     }
 
     /// Consumes this builder and returns a schema.
-    pub fn finish(mut self) -> Schema<Query, Mutation, Subscription> {
+    pub fn finish(self) -> Schema<Query, Mutation, Subscription> {
+        let (schema, _warnings) = self.build();
+        #[cfg(feature = "log")]
+        for warning in &_warnings {
+            log::warn!("{}", warning);
+        }
+        #[cfg(feature = "tracing")]
+        for warning in &_warnings {
+            tracinglib::warn!("{}", warning);
+        }
+        schema
+    }
+
+    /// Builds the schema and returns the warnings found while building it,
+    /// such as unknown descriptions.
+    fn build(mut self) -> (Schema<Query, Mutation, Subscription>, Vec<SchemaWarning>) {
         // federation
         if self.registry.enable_federation || self.registry.has_entities() {
             self.registry.create_federation_types();
         }
 
-        Schema(Arc::new(SchemaInner {
+        if !self.registry.introspect_applied_directives {
+            self.registry.remove_applied_directives_introspection();
+        }
+
+        let mut warnings = Vec::new();
+        for (key, desc) in self.descriptions {
+            let found = key.split_once('.').is_some_and(|(type_name, field_name)| {
+                self.registry
+                    .set_field_description(type_name, field_name, desc)
+            });
+            if !found {
+                warnings.push(SchemaWarning::new(
+                    SchemaWarningKind::UnknownDescription,
+                    key,
+                    "The description does not match any field.",
+                ));
+            }
+        }
+        for (locale, descriptions) in self.localized_descriptions {
+            for (key, desc) in descriptions {
+                match key.split_once('.') {
+                    Some((type_name, field_name))
+                        if self
+                            .registry
+                            .field_description_mut(type_name, field_name)
+                            .is_some() =>
+                    {
+                        self.registry
+                            .localized_descriptions
+                            .entry(locale.clone())
+                            .or_default()
+                            .entry(type_name.to_string())
+                            .or_default()
+                            .insert(field_name.to_string(), desc);
+                    }
+                    _ => warnings.push(SchemaWarning::new(
+                        SchemaWarningKind::UnknownDescription,
+                        key,
+                        format!(
+                            "The description for the locale `{}` does not match any field.",
+                            locale
+                        ),
+                    )),
+                }
+            }
+        }
+
+        let schema = Schema(Arc::new(SchemaInner {
             validation_mode: self.validation_mode,
             query: self.query,
             mutation: self.mutation,
@@ -274,21 +862,90 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
             depth: self.depth,
             recursive_depth: self.recursive_depth,
             max_directives: self.max_directives,
+            check_mutation_guards_first: self.check_mutation_guards_first,
+            max_subscriptions_per_connection: self.max_subscriptions_per_connection,
+            child_depth: self.child_depth,
             extensions: self.extensions,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
                 data: self.data,
                 custom_directives: self.custom_directives,
+                field_overrides: self.field_overrides,
+                document_transforms: self.document_transforms,
+                dry_run_placeholders: self.dry_run_placeholders,
+                upload_spool_threshold: self.upload_spool_threshold,
             })),
-        }))
+        }));
+        (schema, warnings)
+    }
+
+    /// Build the GraphQL schema and return it together with its non-fatal
+    /// issues, such as deprecated required arguments or missing
+    /// descriptions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// /// The root query.
+    /// #[Object]
+    /// impl Query {
+    ///     /// Returns the value.
+    ///     async fn value(&self, #[graphql(deprecation)] a: i32) -> i32 {
+    ///         a
+    ///     }
+    /// }
+    ///
+    /// let (schema, warnings) =
+    ///     Schema::build(Query, EmptyMutation, EmptySubscription).finish_with_warnings();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].kind, SchemaWarningKind::Deprecation);
+    /// assert_eq!(warnings[0].path, "Query.value(a:)");
+    /// ```
+    pub fn finish_with_warnings(
+        self,
+    ) -> (Schema<Query, Mutation, Subscription>, Vec<SchemaWarning>) {
+        let (schema, mut warnings) = self.build();
+        warnings.extend(schema.0.env.registry.check_warnings());
+        (schema, warnings)
     }
 }
 
+/// A resolver that replaces the resolver of a field, see
+/// [`SchemaBuilder::override_field`].
+#[doc(hidden)]
+pub type FieldResolverFn =
+    Box<dyn for<'a> Fn(&'a Context<'a>) -> BoxFuture<'a, ServerResult<Value>> + Send + Sync>;
+
+/// A transform of the parsed document, see
+/// [`SchemaBuilder::add_document_transform`].
+#[doc(hidden)]
+pub type DocumentTransformFn = Box<dyn Fn(&mut ExecutableDocument) + Send + Sync>;
+
 #[doc(hidden)]
 pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
     pub custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    pub field_overrides: HashMap<String, HashMap<String, FieldResolverFn>>,
+    pub document_transforms: Vec<DocumentTransformFn>,
+    pub dry_run_placeholders: HashMap<String, Value>,
+    pub upload_spool_threshold: Option<usize>,
+}
+
+impl SchemaEnvInner {
+    pub(crate) fn field_override(
+        &self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&FieldResolverFn> {
+        self.field_overrides
+            .get(type_name)
+            .and_then(|fields| fields.get(field_name))
+    }
 }
 
 #[doc(hidden)]
@@ -312,7 +969,10 @@ pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
     pub(crate) recursive_depth: usize,
+    pub(crate) check_mutation_guards_first: bool,
+    pub(crate) max_subscriptions_per_connection: Option<usize>,
     pub(crate) max_directives: Option<usize>,
+    pub(crate) child_depth: usize,
     pub(crate) extensions: Vec<Box<dyn ExtensionFactory>>,
     pub(crate) env: SchemaEnv,
 }
@@ -379,25 +1039,109 @@ where
         I: IntoIterator<Item = T>,
         T: Into<String>,
     {
+        Self::create_builder(
+            query,
+            mutation,
+            subscription,
+            ignore_name_conflicts.into_iter().map(Into::into).collect(),
+            NamingConvention::CamelCase,
+        )
+    }
+
+    /// Create a schema builder with the naming convention of the fields and
+    /// arguments generated from Rust identifiers, the default is
+    /// [`NamingConvention::CamelCase`].
+    ///
+    /// Explicit names, set with `#[graphql(name = "...")]` or a
+    /// `rename_fields`/`rename_args` rule, are not affected. The names of
+    /// input object fields, enum values, directive arguments and the field
+    /// sets of federation directives are not changed either, and the
+    /// introspection and connection types keep the names required by their
+    /// specifications.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn total_count(&self, page_size: i32) -> i32 {
+    ///         page_size
+    ///     }
+    ///
+    ///     #[graphql(name = "itemCount")]
+    ///     async fn item_count(&self) -> i32 {
+    ///         5
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build_with_naming_convention(
+    ///     Query,
+    ///     EmptyMutation,
+    ///     EmptySubscription,
+    ///     NamingConvention::SnakeCase,
+    /// )
+    /// .finish();
+    /// let res = schema.execute("{ total_count(page_size: 10) itemCount }").await;
+    /// assert_eq!(res.data, value!({ "total_count": 10, "itemCount": 5 }));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn build_with_naming_convention(
+        query: Query,
+        mutation: Mutation,
+        subscription: Subscription,
+        naming_convention: NamingConvention,
+    ) -> SchemaBuilder<Query, Mutation, Subscription> {
+        Self::create_builder(
+            query,
+            mutation,
+            subscription,
+            Default::default(),
+            naming_convention,
+        )
+    }
+
+    fn create_builder(
+        query: Query,
+        mutation: Mutation,
+        subscription: Subscription,
+        ignore_name_conflicts: HashSet<String>,
+        naming_convention: NamingConvention,
+    ) -> SchemaBuilder<Query, Mutation, Subscription> {
         SchemaBuilder {
             validation_mode: ValidationMode::Strict,
             query: QueryRoot { inner: query },
             mutation,
             subscription,
-            registry: Self::create_registry(
-                ignore_name_conflicts.into_iter().map(Into::into).collect(),
-            ),
+            registry: Self::create_registry(ignore_name_conflicts, naming_convention),
             data: Default::default(),
             complexity: None,
             depth: None,
             recursive_depth: 32,
             max_directives: None,
+            child_depth: 8,
             extensions: Default::default(),
             custom_directives: Default::default(),
+            field_overrides: Default::default(),
+            document_transforms: Default::default(),
+            dry_run_placeholders: Default::default(),
+            upload_spool_threshold: None,
+            descriptions: Default::default(),
+            localized_descriptions: Default::default(),
+            check_mutation_guards_first: false,
+            max_subscriptions_per_connection: None,
         }
     }
 
-    pub(crate) fn create_registry(ignore_name_conflicts: HashSet<String>) -> Registry {
+    pub(crate) fn create_registry(
+        ignore_name_conflicts: HashSet<String>,
+        naming_convention: NamingConvention,
+    ) -> Registry {
         let mut registry = Registry {
             types: Default::default(),
             directives: Default::default(),
@@ -418,6 +1162,11 @@ where
             federation_subscription: false,
             ignore_name_conflicts,
             enable_suggestions: true,
+            introspect_applied_directives: false,
+            localized_descriptions: Default::default(),
+            naming_convention,
+            lazy_types: Default::default(),
+            creating_types: Default::default(),
         };
         registry.add_system_types();
 
@@ -430,6 +1179,7 @@ where
         }
 
         registry.remove_unused_types();
+        registry.add_cost_directives();
         registry
     }
 
@@ -485,15 +1235,29 @@ where
             schema_env: &self.0.env,
             query_env: &env,
             execute_data,
+            lazy_gate: None,
+            prepared: None,
+            guard_mode: GuardMode::Run,
+            serial: false,
+            parent_type: None,
         };
 
         let res = match &env.operation.node.ty {
+            _ if env.dry_run => {
+                DryRun {
+                    placeholders: &self.0.env.dry_run_placeholders,
+                }
+                .execute(&ctx)
+                .await
+            }
             OperationType::Query => resolve_container(&ctx, &self.0.query).await,
             OperationType::Mutation => {
                 if self.0.env.registry.introspection_mode == IntrospectionMode::IntrospectionOnly
                     || env.introspection_mode == IntrospectionMode::IntrospectionOnly
                 {
                     resolve_container_serial(&ctx, &EmptyMutation).await
+                } else if self.0.check_mutation_guards_first {
+                    resolve_container_serial_guards_first(&ctx, &self.0.mutation).await
                 } else {
                     resolve_container_serial(&ctx, &self.0.mutation).await
                 }
@@ -508,41 +1272,135 @@ where
             Ok(value) => Response::new(value),
             Err(err) => Response::from_errors(vec![err]),
         }
-        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
+        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()))
+        .cache_control(*env.cache_control.lock().unwrap());
 
+        resp.downloads = env.take_downloads(&resp.data, None);
         resp.errors
             .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
         resp
     }
 
+    fn execute_child_fn(&self) -> ExecuteChildFn {
+        let schema = self.clone();
+        Arc::new(move |mut request: Request, parent: QueryEnv| {
+            let schema = schema.clone();
+            Box::pin(async move {
+                if parent.child_depth() >= schema.0.child_depth {
+                    return Response::from_errors(vec![ServerError::new(
+                        format!(
+                            "The child request is nested too deeply, the limit is {}.",
+                            schema.0.child_depth
+                        ),
+                        None,
+                    )]);
+                }
+                if request.deadline.is_none() {
+                    request.deadline = parent.deadline;
+                }
+                let session_data = parent.session_data.clone();
+                schema
+                    .execute_with_parent(request, session_data, Some(parent))
+                    .await
+            })
+        })
+    }
+
     /// Execute a GraphQL query.
     pub async fn execute(&self, request: impl Into<Request>) -> Response {
-        let request = request.into();
-        let extensions = self.create_extensions(Default::default());
+        self.execute_with_parent(request.into(), Default::default(), None)
+            .await
+    }
+
+    /// Execute a GraphQL query and deserialize the data of the response,
+    /// returning the partial data together with the errors.
+    ///
+    /// See [`Response::into_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use serde::Deserialize;
+    ///
+    /// struct Obj;
+    ///
+    /// #[Object]
+    /// impl Obj {
+    ///     async fn value(&self) -> Result<i32> {
+    ///         Err("failed".into())
+    ///     }
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn a(&self) -> i32 {
+    ///         10
+    ///     }
+    ///
+    ///     async fn obj(&self) -> Option<Obj> {
+    ///         Some(Obj)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct ObjData {
+    ///     value: i32,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Data {
+    ///     a: i32,
+    ///     obj: Option<ObjData>,
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let (data, errors) = schema.execute_partial::<Data>("{ a obj { value } }").await;
+    /// let data = data.unwrap();
+    /// assert_eq!(data.a, 10);
+    /// assert!(data.obj.is_none());
+    /// assert_eq!(errors[0].message, "failed");
+    /// # });
+    /// ```
+    pub async fn execute_partial<T: DeserializeOwned>(
+        &self,
+        request: impl Into<Request>,
+    ) -> (Option<T>, Vec<ServerError>) {
+        self.execute(request).await.into_partial()
+    }
+
+    async fn execute_with_parent(
+        &self,
+        request: Request,
+        session_data: Arc<Data>,
+        parent: Option<QueryEnv>,
+    ) -> Response {
+        let extensions = self.create_extensions(session_data.clone());
         let request_fut = {
             let extensions = extensions.clone();
             async move {
                 match prepare_request(
                     extensions,
                     request,
-                    Default::default(),
-                    &self.0.env.registry,
+                    session_data,
+                    &self.0.env,
                     self.0.validation_mode,
                     self.0.recursive_depth,
                     self.0.max_directives,
                     self.0.complexity,
                     self.0.depth,
+                    Some(self.execute_child_fn()),
+                    parent,
                 )
                 .await
                 {
-                    Ok((env, cache_control)) => {
+                    Ok(env) => {
                         let f = |execute_data: Option<Data>| {
                             let env = env.clone();
-                            async move {
-                                self.execute_once(env, execute_data.as_ref())
-                                    .await
-                                    .cache_control(cache_control)
-                            }
+                            async move { self.execute_once(env, execute_data.as_ref()).await }
                         };
                         env.extensions
                             .execute(env.operation_name.as_deref(), f)
@@ -584,10 +1442,11 @@ where
             let extensions = extensions.clone();
             let env = self.0.env.clone();
             async_stream::stream! {
-                let (env, cache_control) = match prepare_request(
-                        extensions, request, session_data, &env.registry,
+                let env = match prepare_request(
+                        extensions, request, session_data, &env,
                         schema.0.validation_mode, schema.0.recursive_depth,
-                        schema.0.max_directives, schema.0.complexity, schema.0.depth
+                        schema.0.max_directives, schema.0.complexity, schema.0.depth,
+                        Some(schema.execute_child_fn()), None,
                 ).await {
                     Ok(res) => res,
                     Err(errors) => {
@@ -601,15 +1460,12 @@ where
                         let env = env.clone();
                         let schema = schema.clone();
                         async move {
-                            schema.execute_once(env, execute_data.as_ref())
-                                .await
-                                .cache_control(cache_control)
+                            schema.execute_once(env, execute_data.as_ref()).await
                         }
                     };
                     yield env.extensions
                         .execute(env.operation_name.as_deref(), f)
-                        .await
-                        .cache_control(cache_control);
+                        .await;
                     return;
                 }
 
@@ -670,6 +1526,27 @@ where
         Schema::execute_stream_with_session_data(&self, request, session_data.unwrap_or_default())
             .boxed()
     }
+
+    fn max_subscriptions_per_connection(&self) -> Option<usize> {
+        self.0.max_subscriptions_per_connection
+    }
+}
+
+fn is_type_system_location(location: __DirectiveLocation) -> bool {
+    matches!(
+        location,
+        __DirectiveLocation::SCHEMA
+            | __DirectiveLocation::SCALAR
+            | __DirectiveLocation::OBJECT
+            | __DirectiveLocation::FIELD_DEFINITION
+            | __DirectiveLocation::ARGUMENT_DEFINITION
+            | __DirectiveLocation::INTERFACE
+            | __DirectiveLocation::UNION
+            | __DirectiveLocation::ENUM
+            | __DirectiveLocation::ENUM_VALUE
+            | __DirectiveLocation::INPUT_OBJECT
+            | __DirectiveLocation::INPUT_FIELD_DEFINITION
+    )
 }
 
 fn check_max_directives(doc: &ExecutableDocument, max_directives: usize) -> ServerResult<()> {
@@ -688,7 +1565,8 @@ fn check_max_directives(doc: &ExecutableDocument, max_directives: usize) -> Serv
                                 field.node.name.node, limit_directives
                             ),
                             Some(field.pos),
-                        ));
+                        )
+                        .with_code(ErrorCode::TooManyDirectives));
                     }
                     check_selection_set(doc, &field.node.selection_set, limit_directives)?;
                 }
@@ -733,7 +1611,8 @@ fn check_recursive_depth(doc: &ExecutableDocument, max_depth: usize) -> ServerRe
                     max_depth
                 ),
                 Some(selection_set.pos),
-            ));
+            )
+            .with_code(ErrorCode::QueryTooDeep));
         }
 
         for selection in &selection_set.node.items {
@@ -834,25 +1713,32 @@ pub(crate) async fn prepare_request(
     mut extensions: Extensions,
     request: Request,
     session_data: Arc<Data>,
-    registry: &Registry,
+    env: &SchemaEnv,
     validation_mode: ValidationMode,
     recursive_depth: usize,
     max_directives: Option<usize>,
     complexity: Option<usize>,
     depth: Option<usize>,
-) -> Result<(QueryEnv, CacheControl), Vec<ServerError>> {
+    execute_child: Option<ExecuteChildFn>,
+    parent: Option<QueryEnv>,
+) -> Result<QueryEnv, Vec<ServerError>> {
     let mut request = extensions.prepare_request(request).await?;
     let query_data = Arc::new(std::mem::take(&mut request.data));
     extensions.attach_query_data(query_data.clone());
+    let request_extensions = Arc::new(std::mem::take(&mut request.extensions));
+    extensions.attach_request_extensions(request_extensions.clone());
 
     let mut document = {
         let query = &request.query;
         let parsed_doc = request.parsed_query.take();
         let fut_parse = async move {
-            let doc = match parsed_doc {
+            let mut doc = match parsed_doc {
                 Some(parsed_doc) => parsed_doc,
                 None => parse_query(query)?,
             };
+            for transform in &env.document_transforms {
+                transform(&mut doc);
+            }
             check_recursive_depth(&doc, recursive_depth)?;
             if let Some(max_directives) = max_directives {
                 check_max_directives(&doc, max_directives)?;
@@ -868,8 +1754,14 @@ pub(crate) async fn prepare_request(
     // check rules
     let validation_result = {
         let validation_fut = async {
+            coerce_variables(
+                &env.registry,
+                &document,
+                request.operation_name.as_deref(),
+                &mut request.variables,
+            )?;
             check_rules(
-                registry,
+                &env.registry,
                 &document,
                 Some(&request.variables),
                 validation_mode,
@@ -916,18 +1808,45 @@ pub(crate) async fn prepare_request(
     }
     remove_skipped_selection(&mut operation.node.selection_set.node, &request.variables);
 
+    let uploads = request.uploads;
+    #[cfg(feature = "tempfile")]
+    let (uploads, upload_paths) = {
+        let mut uploads = uploads;
+        let upload_paths = match env.upload_spool_threshold {
+            Some(threshold) => spool_uploads(&mut uploads, threshold).map_err(|err| {
+                vec![ServerError::new(
+                    format!("Failed to spool the uploaded files: {}", err),
+                    None,
+                )]
+            })?,
+            None => Vec::new(),
+        };
+        (uploads, upload_paths)
+    };
+
     let env = QueryEnvInner {
         extensions,
         variables: request.variables,
         operation_name,
         operation,
         fragments: document.fragments,
-        uploads: request.uploads,
+        uploads,
+        #[cfg(feature = "tempfile")]
+        upload_paths,
         session_data,
         query_data,
+        request_extensions,
         http_headers: Default::default(),
+        downloads: Default::default(),
+        next_download_id: Default::default(),
+        request_cache: Default::default(),
+        cache_control: Mutex::new(validation_result.cache_control),
         introspection_mode: request.introspection_mode,
+        deadline: request.deadline,
+        dry_run: request.dry_run,
         errors: Default::default(),
+        execute_child,
+        parent,
     };
-    Ok((QueryEnv::new(env), validation_result.cache_control))
+    Ok(QueryEnv::new(env))
 }
@@ -1,10 +1,14 @@
-use std::{borrow::Cow, pin::Pin};
+use std::{
+    borrow::Cow,
+    pin::Pin,
+    task::{ready, Context as TaskContext, Poll},
+};
 
-use futures_util::stream::{Stream, StreamExt};
+use futures_util::stream::{BoxStream, SelectAll, Stream, StreamExt};
 
 use crate::{
-    parser::types::Selection, registry, registry::Registry, Context, ContextSelectionSet,
-    PathSegment, Response, ServerError, ServerResult,
+    parser::types::Selection, registry, registry::Registry, Context, ContextBase,
+    ContextSelectionSet, Error, PathSegment, Response, ServerError, ServerResult,
 };
 
 /// A GraphQL subscription object
@@ -40,6 +44,15 @@ pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + 'static>(
     root: &'a T,
     streams: &mut Vec<BoxFieldStream<'a>>,
 ) -> ServerResult<()> {
+    let ctx = &ContextBase {
+        parent_type: ctx
+            .schema_env
+            .registry
+            .types
+            .get_key_value(&*T::type_name())
+            .map(|(name, _)| name.as_str()),
+        ..ctx.clone()
+    };
     for selection in &ctx.item.node.items {
         if let Selection::Field(field) = &selection.node {
             streams.push(Box::pin({
@@ -80,3 +93,128 @@ impl<T: SubscriptionType> SubscriptionType for &T {
         T::create_field_stream(*self, ctx)
     }
 }
+
+/// What a [`MergeStreams`] does when one of its sources yields an error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum MergeErrorPolicy {
+    /// Yield the error and end the merged stream, the default.
+    #[default]
+    Terminate,
+    /// Yield the error and keep receiving the items of all the sources.
+    Continue,
+}
+
+/// Returns an empty [`MergeStreams`], the sources are added with
+/// [`MergeStreams::source`] and [`MergeStreams::try_source`].
+pub fn merge_streams<'a, T, E>() -> MergeStreams<'a, T, E> {
+    MergeStreams {
+        sources: SelectAll::new(),
+        policy: MergeErrorPolicy::default(),
+        terminated: false,
+    }
+}
+
+/// A stream merging the items of several sources, in the order they are
+/// received, created with [`merge_streams`].
+///
+/// Each source has a function mapping its items into the output type, for
+/// example into the variants of an enum telling where an item comes from. The
+/// merged stream ends when all the sources have ended, or when a source
+/// yields an error with the [`MergeErrorPolicy::Terminate`] policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{futures_util::stream::Stream, *};
+///
+/// #[derive(Union)]
+/// enum BookEvent {
+///     Created(BookCreated),
+///     Deleted(BookDeleted),
+/// }
+///
+/// #[derive(SimpleObject, Clone)]
+/// struct BookCreated {
+///     id: ID,
+/// }
+///
+/// #[derive(SimpleObject, Clone)]
+/// struct BookDeleted {
+///     id: ID,
+/// }
+///
+/// struct Subscription;
+///
+/// #[Subscription]
+/// impl Subscription {
+///     async fn book_events(&self, ctx: &Context<'_>) -> impl Stream<Item = Result<BookEvent>> {
+///         merge_streams()
+///             .source(
+///                 ctx.data_unchecked::<SimpleBroker<BookCreated>>().subscribe(),
+///                 BookEvent::Created,
+///             )
+///             .source(
+///                 ctx.data_unchecked::<SimpleBroker<BookDeleted>>().subscribe(),
+///                 BookEvent::Deleted,
+///             )
+///     }
+/// }
+/// ```
+pub struct MergeStreams<'a, T, E = Error> {
+    sources: SelectAll<BoxStream<'a, Result<T, E>>>,
+    policy: MergeErrorPolicy,
+    terminated: bool,
+}
+
+impl<'a, T: 'a, E: 'a> MergeStreams<'a, T, E> {
+    /// Add a source, `f` maps its items into the output type.
+    #[must_use]
+    pub fn source<S, F>(self, stream: S, mut f: F) -> Self
+    where
+        S: Stream + Send + 'a,
+        F: FnMut(S::Item) -> T + Send + 'a,
+    {
+        self.push(stream.map(move |item| Ok(f(item))))
+    }
+
+    /// Add a source yielding results, `f` maps its items into the output
+    /// type.
+    #[must_use]
+    pub fn try_source<S, I, F>(self, stream: S, mut f: F) -> Self
+    where
+        S: Stream<Item = Result<I, E>> + Send + 'a,
+        F: FnMut(I) -> T + Send + 'a,
+    {
+        self.push(stream.map(move |item| item.map(&mut f)))
+    }
+
+    /// Set what the merged stream does when a source yields an error.
+    #[must_use]
+    pub fn on_error(self, policy: MergeErrorPolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    fn push<S>(mut self, stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, E>> + Send + 'a,
+    {
+        self.sources.push(stream.boxed());
+        self
+    }
+}
+
+impl<T, E> Stream for MergeStreams<'_, T, E> {
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+        let item = ready!(this.sources.poll_next_unpin(cx));
+        if matches!(item, Some(Err(_))) && this.policy == MergeErrorPolicy::Terminate {
+            this.terminated = true;
+        }
+        Poll::Ready(item)
+    }
+}
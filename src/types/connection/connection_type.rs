@@ -7,7 +7,7 @@ use crate::{
         PageInfo,
     },
     types::connection::{CursorType, EmptyFields},
-    Object, ObjectType, OutputType, TypeName,
+    Error, Object, ObjectType, OutputType, Result, TypeName,
 };
 
 /// Connection type
@@ -96,9 +96,33 @@ where
             edges: Vec::new(),
         }
     }
+
+    /// Replace the page info inferred from the edges with the one known by
+    /// the backend, once the edges are added.
+    ///
+    /// The start and end cursors are optional, if they are specified they
+    /// must be the cursors of the first and last edges, otherwise an error is
+    /// returned.
+    pub fn with_page_info(mut self, page_info: PageInfo) -> Result<Self> {
+        let start_cursor = self.edges.first().map(|edge| edge.cursor.encode_cursor());
+        let end_cursor = self.edges.last().map(|edge| edge.cursor.encode_cursor());
+        if page_info.start_cursor.is_some() && page_info.start_cursor != start_cursor {
+            return Err(Error::new(
+                "The start cursor of the page info does not match the cursor of the first edge",
+            ));
+        }
+        if page_info.end_cursor.is_some() && page_info.end_cursor != end_cursor {
+            return Err(Error::new(
+                "The end cursor of the page info does not match the cursor of the last edge",
+            ));
+        }
+        self.has_previous_page = page_info.has_previous_page;
+        self.has_next_page = page_info.has_next_page;
+        Ok(self)
+    }
 }
 
-#[Object(internal, name_type, shareable)]
+#[Object(internal, name_type, shareable, rename_fields = "camelCase")]
 impl<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName>
     Connection<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName, DisableNodesField>
 where
@@ -132,7 +156,7 @@ where
     }
 }
 
-#[Object(internal, name_type, shareable)]
+#[Object(internal, name_type, shareable, rename_fields = "camelCase")]
 impl<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName>
     Connection<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName, EnableNodesField>
 where
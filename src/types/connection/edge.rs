@@ -7,9 +7,13 @@ use crate::{
 };
 
 /// An edge in a connection.
+///
+/// The fields of `EdgeFields` are flattened into the edge object, next to
+/// `cursor` and `node`, to carry metadata about the relationship, such as a
+/// score or the date when the node was added.
 #[derive(SimpleObject)]
 #[graphql(internal, name_type, shareable, complex)]
-pub struct Edge<Cursor, Node, EdgeFields, Name = DefaultEdgeName>
+pub struct Edge<Cursor, Node, EdgeFields = EmptyFields, Name = DefaultEdgeName>
 where
     Cursor: CursorType + Send + Sync,
     Node: OutputType,
@@ -23,8 +27,9 @@ where
     pub cursor: Cursor,
     /// The item at the end of the edge
     pub node: Node,
+    /// Additional fields for edge object.
     #[graphql(flatten)]
-    pub(crate) additional_fields: EdgeFields,
+    pub additional_fields: EdgeFields,
 }
 
 #[ComplexObject(internal)]
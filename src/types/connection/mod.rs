@@ -3,6 +3,7 @@
 mod connection_type;
 mod cursor;
 mod edge;
+mod offset_query;
 mod page_info;
 
 use std::{fmt::Display, future::Future};
@@ -10,6 +11,7 @@ use std::{fmt::Display, future::Future};
 pub use connection_type::Connection;
 pub use cursor::{CursorType, OpaqueCursor};
 pub use edge::Edge;
+pub use offset_query::{offset_query, OffsetLimit};
 pub use page_info::PageInfo;
 
 use crate::{Error, ObjectType, OutputType, Result, SimpleObject};
@@ -72,6 +74,14 @@ pub struct DisableNodesField;
 
 /// Parses the parameters and executes the query.
 ///
+/// `first` and `last` must be non-negative. All the combinations of the
+/// parameters are allowed, and are applied in the order of the
+/// [Relay specification](https://relay.dev/graphql/connections.htm#sec-Pagination-algorithm):
+/// the edges are sliced after `after` and before `before`, then `first` keeps
+/// the first edges of the slice, then `last` keeps the last edges of what
+/// remains. Use [`query_strict`] to reject the combinations discouraged by the
+/// specification instead.
+///
 /// # Examples
 ///
 /// ```rust
@@ -255,6 +265,10 @@ where
 /// cannot customize the name of the type, so you can use this function to
 /// execute the query and return a customized `Connection` type.
 ///
+/// The parameters are validated like with [`query`], use
+/// [`query_with_strict`] to reject the combinations discouraged by the Relay
+/// specification.
+///
 /// # Examples
 ///
 /// ```rust
@@ -345,6 +359,135 @@ pub async fn query_with<Cursor, T, F, R, E>(
     last: Option<i32>,
     f: F,
 ) -> Result<T>
+where
+    Cursor: CursorType + Send + Sync,
+    <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
+    F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
+    R: Future<Output = Result<T, E>>,
+    E: Into<Error>,
+{
+    parse_and_query(after, before, first, last, false, f).await
+}
+
+/// Parses the parameters and executes the query like [`query`], but rejects
+/// the combinations of parameters discouraged by the
+/// [Relay specification](https://relay.dev/graphql/connections.htm#sec-Pagination-algorithm):
+/// `first` and `last` cannot be used together, `first` paginates forwards so
+/// it cannot be used with `before`, and `last` paginates backwards so it
+/// cannot be used with `after`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::types::connection::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn numbers(&self,
+///         after: Option<String>,
+///         before: Option<String>,
+///         first: Option<i32>,
+///         last: Option<i32>
+///     ) -> Result<Connection<usize, i32>> {
+///         query_strict(after, before, first, last, |after, before, first, last| async move {
+///             let start = after.map(|after| after + 1).unwrap_or(0);
+///             let end = start + first.unwrap_or(10);
+///             let mut connection = Connection::new(start > 0, true);
+///             connection.edges.extend((start..end).map(|n| Edge::new(n, n as i32)));
+///             Ok::<_, Error>(connection)
+///         }).await
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///
+/// assert_eq!(schema.execute("{ numbers(first: 2) { edges { node } } }").await.into_result().unwrap().data, value!({
+///     "numbers": {
+///         "edges": [
+///             {"node": 0},
+///             {"node": 1},
+///         ]
+///     },
+/// }));
+///
+/// let res = schema.execute("{ numbers(first: 2, last: 1) { edges { node } } }").await;
+/// assert_eq!(
+///     res.into_result().unwrap_err()[0].message,
+///     r#"The "first" and "last" parameters cannot be used together"#,
+/// );
+/// # });
+/// ```
+pub async fn query_strict<
+    Name,
+    EdgeName,
+    Cursor,
+    Node,
+    NodesVersion,
+    ConnectionFields,
+    EdgeFields,
+    F,
+    R,
+    E,
+>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    f: F,
+) -> Result<Connection<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName, NodesVersion>>
+where
+    Name: ConnectionNameType,
+    EdgeName: EdgeNameType,
+    Cursor: CursorType + Send + Sync,
+    <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
+    Node: OutputType,
+    NodesVersion: NodesFieldSwitcherSealed,
+    ConnectionFields: ObjectType,
+    EdgeFields: ObjectType,
+    F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
+    R: Future<
+        Output = Result<
+            Connection<Cursor, Node, ConnectionFields, EdgeFields, Name, EdgeName, NodesVersion>,
+            E,
+        >,
+    >,
+    E: Into<Error>,
+{
+    query_with_strict(after, before, first, last, f).await
+}
+
+/// Parses the parameters and executes the query and return a custom
+/// `Connection` type, the parameters are validated like with
+/// [`query_strict`].
+pub async fn query_with_strict<Cursor, T, F, R, E>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    f: F,
+) -> Result<T>
+where
+    Cursor: CursorType + Send + Sync,
+    <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
+    F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
+    R: Future<Output = Result<T, E>>,
+    E: Into<Error>,
+{
+    parse_and_query(after, before, first, last, true, f).await
+}
+
+async fn parse_and_query<Cursor, T, F, R, E>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    strict: bool,
+    f: F,
+) -> Result<T>
 where
     Cursor: CursorType + Send + Sync,
     <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
@@ -372,6 +515,26 @@ where
         None => None,
     };
 
+    if strict {
+        if first.is_some() && last.is_some() {
+            return Err(Error::new(
+                "The \"first\" and \"last\" parameters cannot be used together",
+            ));
+        }
+
+        if first.is_some() && before.is_some() {
+            return Err(Error::new(
+                "The \"first\" parameter cannot be used with \"before\", use \"last\" to paginate backwards",
+            ));
+        }
+
+        if last.is_some() && after.is_some() {
+            return Err(Error::new(
+                "The \"last\" parameter cannot be used with \"after\", use \"first\" to paginate forwards",
+            ));
+        }
+    }
+
     let before = match before {
         Some(before) => Some(Cursor::decode_cursor(&before).map_err(Error::new_with_source)?),
         None => None,
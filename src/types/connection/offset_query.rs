@@ -0,0 +1,165 @@
+use std::future::Future;
+
+use crate::{
+    connection::{
+        query, Connection, ConnectionNameType, Edge, EdgeNameType, EmptyFields,
+        NodesFieldSwitcherSealed,
+    },
+    Error, OutputType, Result,
+};
+
+/// The offset and the limit of a page, computed from the pagination
+/// arguments of a connection whose cursors are offsets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OffsetLimit {
+    /// The offset of the first item of the page.
+    pub offset: usize,
+
+    /// The maximum number of items of the page, `None` if the page is
+    /// unbounded.
+    pub limit: Option<usize>,
+}
+
+impl OffsetLimit {
+    /// Computes the offset and the limit of a page from the decoded
+    /// pagination arguments.
+    ///
+    /// The window starts after the `after` offset and ends before the
+    /// `before` offset, then `first` keeps its first items and `last` keeps
+    /// its last items. The window of `last` without `before` or `first` has no
+    /// end, so its limit is `None`. The offsets saturate at `usize::MAX`.
+    pub fn new(
+        after: Option<usize>,
+        before: Option<usize>,
+        first: Option<usize>,
+        last: Option<usize>,
+    ) -> Self {
+        let mut start = after.map(|after| after.saturating_add(1)).unwrap_or(0);
+        let mut end = before.map(|before| before.max(start));
+        if let Some(first) = first {
+            let first_end = start.saturating_add(first);
+            end = Some(end.map_or(first_end, |end| end.min(first_end)));
+        }
+        if let (Some(last), Some(end)) = (last, end) {
+            start = start.max(end.saturating_sub(last));
+        }
+        OffsetLimit {
+            offset: start,
+            limit: end.map(|end| end - start),
+        }
+    }
+}
+
+/// Parses the parameters and executes the query with an offset and a limit.
+///
+/// The cursors of the edges are the offsets of their nodes. The closure is
+/// called with the offset and the limit of the page (see [`OffsetLimit`]),
+/// and returns the nodes from this offset.
+///
+/// One more node than the limit is requested to know if there is a next
+/// page. If `last` is specified without `before` or `first`, the page has no
+/// end, so the closure is called without a limit and only the last nodes are
+/// kept.
+///
+/// The parameters are validated like with [`query`].
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{connection::*, *};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn numbers(
+///         &self,
+///         after: Option<String>,
+///         before: Option<String>,
+///         first: Option<i32>,
+///         last: Option<i32>,
+///     ) -> Result<Connection<usize, i32>> {
+///         offset_query(after, before, first, last, |offset, limit| async move {
+///             let numbers = (offset..10).map(|n| n as i32);
+///             Ok::<_, Error>(match limit {
+///                 Some(limit) => numbers.take(limit).collect::<Vec<_>>(),
+///                 None => numbers.collect(),
+///             })
+///         })
+///         .await
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///
+/// let query = r#"{
+///     numbers(last: 2, before: "5") {
+///         edges { cursor node }
+///         pageInfo { hasPreviousPage hasNextPage }
+///     }
+/// }"#;
+/// assert_eq!(schema.execute(query).await.into_result().unwrap().data, value!({
+///     "numbers": {
+///         "edges": [
+///             {"cursor": "3", "node": 3},
+///             {"cursor": "4", "node": 4},
+///         ],
+///         "pageInfo": { "hasPreviousPage": true, "hasNextPage": true },
+///     },
+/// }));
+/// # });
+/// ```
+pub async fn offset_query<Name, EdgeName, Node, NodesField, F, R, E>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    f: F,
+) -> Result<Connection<usize, Node, EmptyFields, EmptyFields, Name, EdgeName, NodesField>>
+where
+    Name: ConnectionNameType,
+    EdgeName: EdgeNameType,
+    Node: OutputType,
+    NodesField: NodesFieldSwitcherSealed,
+    F: FnOnce(usize, Option<usize>) -> R,
+    R: Future<Output = Result<Vec<Node>, E>>,
+    E: Into<Error>,
+{
+    query(
+        after,
+        before,
+        first,
+        last,
+        |after, before, first, last| async move {
+            let OffsetLimit { mut offset, limit } = OffsetLimit::new(after, before, first, last);
+            let mut nodes = f(offset, limit.map(|limit| limit.saturating_add(1)))
+                .await
+                .map_err(Into::into)?;
+
+            let mut has_next_page = false;
+            match (limit, last) {
+                (Some(limit), _) => {
+                    has_next_page = nodes.len() > limit;
+                    nodes.truncate(limit);
+                }
+                (None, Some(last)) if nodes.len() > last => {
+                    let skip = nodes.len() - last;
+                    nodes.drain(..skip);
+                    offset += skip;
+                }
+                (None, _) => {}
+            }
+
+            let mut connection = Connection::new(offset > 0, has_next_page);
+            connection.edges.extend(
+                nodes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, node)| Edge::new(offset + idx, node)),
+            );
+            Ok::<_, Error>(connection)
+        },
+    )
+    .await
+}
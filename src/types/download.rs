@@ -0,0 +1,320 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Debug, Formatter},
+    io,
+    sync::{atomic::Ordering, Mutex},
+};
+
+use bytes::Bytes;
+use futures_util::{
+    io::{AsyncRead, AsyncReadExt},
+    stream::{self, BoxStream, Stream, StreamExt},
+};
+
+use crate::{
+    context::QueryPathSegment, parser::types::Field, registry::Registry, ContextSelectionSet,
+    OutputType, PathSegment, Positioned, ServerError, ServerResult, SimpleObject, Value,
+};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// A file download value.
+///
+/// The downloads of a request are collected in [`Response::downloads`](crate::Response::downloads),
+/// the transport is responsible for forwarding the content to the client.
+pub struct DownloadValue {
+    /// The id of the download, referenced by the `id` field of the `Download`
+    /// object in the response data, unique in a request.
+    pub id: usize,
+    /// The name of the file.
+    pub filename: Option<String>,
+    /// The content type of the file.
+    pub content_type: Option<String>,
+    /// The length of the content in bytes, if known.
+    pub content_length: Option<u64>,
+    path: Vec<PathSegment>,
+    content: Mutex<BoxStream<'static, io::Result<Bytes>>>,
+}
+
+impl Debug for DownloadValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DownloadValue")
+            .field("id", &self.id)
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.content_length)
+            .finish()
+    }
+}
+
+impl DownloadValue {
+    /// Returns `true` if the field of the download is still in the response
+    /// data, it can be nulled by the error of a non-null sibling.
+    fn is_in(&self, data: &Value) -> bool {
+        let mut value = data;
+        for segment in &self.path {
+            value = match (segment, value) {
+                (PathSegment::Field(name), Value::Object(object)) => {
+                    match object.get(name.as_str()) {
+                        Some(value) => value,
+                        None => return false,
+                    }
+                }
+                (PathSegment::Index(idx), Value::List(items)) => match items.get(*idx) {
+                    Some(value) => value,
+                    None => return false,
+                },
+                _ => return false,
+            };
+        }
+        !matches!(value, Value::Null)
+    }
+}
+
+/// Takes the downloads of the response data from the downloads of a request.
+///
+/// If `field` is specified, only the downloads under this root field are
+/// taken, the data being the response of a subscription event. The downloads
+/// whose field is not in the data are dropped.
+pub(crate) fn take_downloads(
+    downloads: &Mutex<Vec<DownloadValue>>,
+    data: &Value,
+    field: Option<&str>,
+) -> Vec<DownloadValue> {
+    let mut downloads = downloads.lock().unwrap();
+    let (taken, rest): (Vec<_>, Vec<_>) =
+        std::mem::take(&mut *downloads)
+            .into_iter()
+            .partition(|download| match (field, download.path.first()) {
+                (None, _) => true,
+                (Some(field), Some(PathSegment::Field(name))) => name == field,
+                (Some(_), _) => false,
+            });
+    *downloads = rest;
+    taken
+        .into_iter()
+        .filter(|download| download.is_in(data))
+        .collect()
+}
+
+/// Two download values are equal if their metadata is equal, the content is
+/// not compared.
+impl PartialEq for DownloadValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.filename == other.filename
+            && self.content_type == other.content_type
+            && self.content_length == other.content_length
+    }
+}
+
+impl DownloadValue {
+    /// Convert to a stream of the file content.
+    pub fn into_stream(self) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static {
+        self.content.into_inner().unwrap()
+    }
+
+    /// Read the whole content of the file into memory.
+    pub async fn into_bytes(self) -> io::Result<Bytes> {
+        let mut stream = self.into_stream();
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+        Ok(data.into())
+    }
+}
+
+/// File download
+///
+/// The counterpart of [`Upload`](crate::Upload), returns a binary payload
+/// from a resolver without buffering it into the response.
+///
+/// The content is not part of the response data, it's moved to
+/// [`Response::downloads`](crate::Response::downloads) and the field is a
+/// `Download` object with the `id` of the download, unique in a request, and
+/// the metadata of the file:
+///
+/// ```graphql
+/// type Download {
+///   id: Int!
+///   filename: String
+///   contentType: String
+///   contentLength: Int
+/// }
+/// ```
+///
+/// A download can only be resolved once. If its field is nulled because of an
+/// error, the download is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn report(&self) -> Download {
+///         Download::from_bytes("a,b,c")
+///             .filename("report.csv")
+///             .content_type("text/csv")
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let mut resp = schema
+///     .execute("{ report { id filename contentType contentLength } }")
+///     .await;
+/// assert_eq!(
+///     resp.data,
+///     value!({
+///         "report": {
+///             "id": 0,
+///             "filename": "report.csv",
+///             "contentType": "text/csv",
+///             "contentLength": 5,
+///         }
+///     })
+/// );
+/// let download = resp.downloads.remove(0);
+/// assert_eq!(download.into_bytes().await.unwrap(), "a,b,c");
+/// # });
+/// ```
+pub struct Download {
+    filename: Option<String>,
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    content: Mutex<Option<BoxStream<'static, io::Result<Bytes>>>>,
+}
+
+impl Download {
+    /// Create a download from a stream of bytes.
+    pub fn new(content: impl Stream<Item = io::Result<Bytes>> + Send + 'static) -> Self {
+        Self {
+            filename: None,
+            content_type: None,
+            content_length: None,
+            content: Mutex::new(Some(content.boxed())),
+        }
+    }
+
+    /// Create a download from an `AsyncRead`.
+    pub fn from_async_read(reader: impl AsyncRead + Send + 'static) -> Self {
+        Self::new(stream::try_unfold(
+            Box::pin(reader),
+            |mut reader| async move {
+                let mut buf = vec![0; CHUNK_SIZE];
+                let n = reader.read(&mut buf).await?;
+                if n == 0 {
+                    return Ok(None);
+                }
+                buf.truncate(n);
+                Ok(Some((Bytes::from(buf), reader)))
+            },
+        ))
+    }
+
+    /// Create a download from bytes in memory, the content length is set
+    /// automatically.
+    pub fn from_bytes(bytes: impl Into<Bytes>) -> Self {
+        let bytes = bytes.into();
+        let content_length = bytes.len() as u64;
+        Self::new(stream::once(async move { Ok(bytes) })).content_length(content_length)
+    }
+
+    /// Set the name of the file.
+    #[must_use]
+    pub fn filename(self, filename: impl Into<String>) -> Self {
+        Self {
+            filename: Some(filename.into()),
+            ..self
+        }
+    }
+
+    /// Set the content type of the file.
+    #[must_use]
+    pub fn content_type(self, content_type: impl Into<String>) -> Self {
+        Self {
+            content_type: Some(content_type.into()),
+            ..self
+        }
+    }
+
+    /// Set the length of the content in bytes.
+    #[must_use]
+    pub fn content_length(self, content_length: u64) -> Self {
+        Self {
+            content_length: Some(content_length),
+            ..self
+        }
+    }
+}
+
+/// A file download, the content is transferred separately from the response.
+#[derive(SimpleObject)]
+#[graphql(internal, name = "Download", rename_fields = "camelCase")]
+struct DownloadMetadata {
+    /// The id of the download, unique in a request.
+    id: usize,
+    /// The name of the file.
+    filename: Option<String>,
+    /// The content type of the file.
+    content_type: Option<String>,
+    /// The length of the content in bytes, if known.
+    content_length: Option<u64>,
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl OutputType for Download {
+    fn type_name() -> Cow<'static, str> {
+        <DownloadMetadata as OutputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <DownloadMetadata as OutputType>::create_type_info(registry)
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let content = self.content.lock().unwrap().take().ok_or_else(|| {
+            ServerError::new("The download has already been consumed.", Some(field.pos))
+        })?;
+
+        let mut path = Vec::new();
+        if let Some(node) = ctx.path_node {
+            node.for_each(|segment| {
+                path.push(match segment {
+                    QueryPathSegment::Name(name) => PathSegment::Field((*name).to_string()),
+                    QueryPathSegment::Index(idx) => PathSegment::Index(*idx),
+                })
+            });
+        }
+
+        let id = ctx
+            .query_env
+            .next_download_id
+            .fetch_add(1, Ordering::Relaxed);
+        ctx.query_env.downloads.lock().unwrap().push(DownloadValue {
+            id,
+            filename: self.filename.clone(),
+            content_type: self.content_type.clone(),
+            content_length: self.content_length,
+            path,
+            content: Mutex::new(content),
+        });
+
+        let metadata = DownloadMetadata {
+            id,
+            filename: self.filename.clone(),
+            content_type: self.content_type.clone(),
+            content_length: self.content_length,
+        };
+        OutputType::resolve(&metadata, ctx, field).await
+    }
+}
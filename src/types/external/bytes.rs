@@ -1,19 +1,31 @@
+use base64::Engine;
 use bytes::Bytes;
 
 use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
 
 /// The `Binary` scalar type represents binary data.
+///
+/// The transports with native binary values, such as CBOR, send them as they
+/// are, the JSON transports send them as base64 strings.
 #[Scalar(internal)]
 impl ScalarType for Bytes {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
             Value::Binary(data) => Ok(data),
+            Value::String(s) => Ok(base64::engine::general_purpose::STANDARD
+                .decode(s)
+                .map_err(|_| InputValueError::custom("invalid base64 string"))?
+                .into()),
             _ => Err(InputValueError::expected_type(value)),
         }
     }
 
     fn is_valid(value: &Value) -> bool {
-        matches!(value, Value::Binary(_))
+        match value {
+            Value::Binary(_) => true,
+            Value::String(s) => base64::engine::general_purpose::STANDARD.decode(s).is_ok(),
+            _ => false,
+        }
     }
 
     fn to_value(&self) -> Value {
@@ -10,6 +10,7 @@ mod json_object;
 mod list;
 mod non_zero_integers;
 mod optional;
+mod range;
 mod string;
 
 #[cfg(feature = "tokio-sync")]
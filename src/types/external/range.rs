@@ -0,0 +1,177 @@
+use std::{borrow::Cow, ops::Range};
+
+use indexmap::IndexMap;
+
+use crate::{
+    parser::types::Field,
+    registry::{self, Deprecation, MetaField, MetaInputValue, MetaType, MetaTypeId},
+    resolver_utils::{resolve_container, ContainerType},
+    CacheControl, Context, ContextSelectionSet, InputObjectType, InputType, InputValueError,
+    InputValueResult, Name, ObjectType, OutputType, Positioned, ScalarType, ServerResult, Value,
+};
+
+/// A `Range<T>` is represented as an input object with the `start` and `end`
+/// fields.
+///
+/// The input type name is `{T}RangeInput`, for example `IntRangeInput`.
+/// `T` must be a scalar, so the bounds are non-null. Scalars sharing a name,
+/// such as `i32` and `i64`, share the same input type.
+///
+/// Inverted ranges (`start > end`) are accepted as is, use a custom validator
+/// to reject them.
+impl<T: ScalarType + InputType> InputType for Range<T> {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}RangeInput", T::type_name()))
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        registry.create_input_type::<Self, _>(MetaTypeId::InputObject, |registry| {
+            let mut input_fields = IndexMap::new();
+            for name in ["start", "end"] {
+                input_fields.insert(
+                    name.to_string(),
+                    MetaInputValue {
+                        name: name.to_string(),
+                        description: None,
+                        ty: T::create_type_info(registry),
+                        deprecation: Deprecation::NoDeprecated,
+                        default_value: None,
+                        visible: None,
+                        inaccessible: false,
+                        tags: Default::default(),
+                        is_secret: false,
+                        directive_invocations: Default::default(),
+                    },
+                );
+            }
+
+            MetaType::InputObject {
+                name: <Self as InputType>::type_name().to_string(),
+                description: None,
+                input_fields,
+                visible: None,
+                inaccessible: false,
+                tags: Default::default(),
+                rust_typename: None,
+                oneof: false,
+                directive_invocations: Default::default(),
+            }
+        })
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        match value {
+            Some(Value::Object(obj)) => {
+                let start = <T as InputType>::parse(obj.get("start").cloned())
+                    .map_err(InputValueError::propagate)?;
+                let end = <T as InputType>::parse(obj.get("end").cloned())
+                    .map_err(InputValueError::propagate)?;
+                Ok(start..end)
+            }
+            value => Err(InputValueError::expected_type(value.unwrap_or_default())),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let mut map = IndexMap::new();
+        map.insert(Name::new("start"), InputType::to_value(&self.start));
+        map.insert(Name::new("end"), InputType::to_value(&self.end));
+        Value::Object(map)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl<T: ScalarType + InputType> InputObjectType for Range<T> {}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T: ScalarType + OutputType> ContainerType for Range<T> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        let value = match ctx.item.node.name.node.as_str() {
+            "start" => &self.start,
+            "end" => &self.end,
+            _ => return Ok(None),
+        };
+        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        OutputType::resolve(value, &ctx_obj, ctx.item)
+            .await
+            .map(Some)
+    }
+}
+
+/// A `Range<T>` is represented as an object with the `start` and `end` fields.
+///
+/// The output type name is `{T}Range`, for example `IntRange`. `T` must be a
+/// scalar, so the bounds are non-null. Scalars sharing a name, such as `i32`
+/// and `i64`, share the same output type.
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T: ScalarType + OutputType> OutputType for Range<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}Range", T::type_name()))
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Object, |registry| {
+            let mut fields = IndexMap::new();
+            for name in ["start", "end"] {
+                fields.insert(
+                    name.to_string(),
+                    MetaField {
+                        name: name.to_string(),
+                        description: None,
+                        args: Default::default(),
+                        ty: T::create_type_info(registry),
+                        deprecation: Deprecation::NoDeprecated,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        shareable: false,
+                        inaccessible: false,
+                        tags: Default::default(),
+                        override_from: None,
+                        authenticated: false,
+                        requires_scopes: Default::default(),
+                        compute_complexity: None,
+                        cost: None,
+                        list_size: None,
+                        directive_invocations: Default::default(),
+                    },
+                );
+            }
+
+            MetaType::Object {
+                name: <Self as OutputType>::type_name().to_string(),
+                description: None,
+                fields,
+                cache_control: CacheControl::default(),
+                extends: false,
+                shareable: false,
+                resolvable: true,
+                keys: None,
+                visible: None,
+                inaccessible: false,
+                interface_object: false,
+                tags: Default::default(),
+                is_subscription: false,
+                rust_typename: None,
+                directive_invocations: Default::default(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<T: ScalarType + OutputType> ObjectType for Range<T> {}
@@ -0,0 +1,728 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+    ops::{Deref, DerefMut},
+};
+
+use indexmap::IndexMap;
+
+use crate::{
+    parser::types::Field,
+    registry::{Deprecation, MetaField, MetaInputValue, MetaType, MetaTypeId, Registry},
+    resolver_utils::{resolve_container, resolve_list, ContainerType},
+    CacheControl, Context, ContextSelectionSet, InputObjectType, InputType, InputValueError,
+    InputValueResult, Name, ObjectType, OutputType, Positioned, ScalarType, ServerResult, Value,
+};
+
+/// A map represented as a list of key-value pairs.
+///
+/// Maps are output as the `JSONObject` scalar by default, this type outputs
+/// them as a list of objects with the `key` and `value` fields instead. The
+/// name of the pair type is `{K}{V}KeyValuePair`, for example
+/// `StringIntKeyValuePair`. A nullable value type is prefixed with `Nullable`
+/// and a list with `ListOf`, so the pair type of `HashMap<String,
+/// Option<Vec<i32>>>` is `StringNullableListOfIntKeyValuePair`. Types sharing
+/// a name, such as `i32` and `i64`, share the same pair type.
+///
+/// The keys must be scalars, so non-string keys keep their type instead of
+/// being converted to strings. The pairs of a `BTreeMap` are in the order of
+/// the keys, the pairs of a `HashMap` are in an arbitrary order.
+///
+/// As an input type, it accepts a list of input objects with the `key` and
+/// `value` fields, the pair input type is named like the pair type with an
+/// `Input` suffix, for example `StringIntKeyValuePairInput`. A key given more
+/// than once is rejected, unless `LAST_WINS` is `true`, then the last value of
+/// the key is kept.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn scores(&self) -> KeyValueList<BTreeMap<String, i32>> {
+///         let mut scores = BTreeMap::new();
+///         scores.insert("alice".to_string(), 10);
+///         scores.insert("bob".to_string(), 20);
+///         scores.into()
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let res = schema.execute("{ scores { key value } }").await.into_result().unwrap();
+/// assert_eq!(
+///     res.data,
+///     value!({
+///         "scores": [
+///             { "key": "alice", "value": 10 },
+///             { "key": "bob", "value": 20 },
+///         ]
+///     })
+/// );
+/// # });
+/// ```
+///
+/// Accept a map argument:
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn total(&self, scores: KeyValueList<HashMap<String, i32>>) -> i32 {
+///         scores.values().sum()
+///     }
+///
+///     async fn last(&self, scores: KeyValueList<HashMap<String, i32>, true>) -> Option<i32> {
+///         scores.get("alice").copied()
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let res = schema
+///     .execute(r#"{ total(scores: [{ key: "alice", value: 10 }, { key: "bob", value: 20 }]) }"#)
+///     .await;
+/// assert_eq!(res.into_result().unwrap().data, value!({ "total": 30 }));
+///
+/// let query = r#"{ last(scores: [{ key: "alice", value: 10 }, { key: "alice", value: 20 }]) }"#;
+/// let res = schema.execute(query).await;
+/// assert_eq!(res.into_result().unwrap().data, value!({ "last": 20 }));
+/// # });
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct KeyValueList<M, const LAST_WINS: bool = false>(pub M);
+
+impl<M, const LAST_WINS: bool> Deref for KeyValueList<M, LAST_WINS> {
+    type Target = M;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<M, const LAST_WINS: bool> DerefMut for KeyValueList<M, LAST_WINS> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<M, const LAST_WINS: bool> From<M> for KeyValueList<M, LAST_WINS> {
+    fn from(value: M) -> Self {
+        Self(value)
+    }
+}
+
+/// Returns the part of the name of a pair type for the qualified name of its
+/// key or value type.
+fn type_name_part(qualified_type_name: &str) -> String {
+    let (ty, nullable) = match qualified_type_name.strip_suffix('!') {
+        Some(ty) => (ty, false),
+        None => (qualified_type_name, true),
+    };
+    let name = match ty.strip_prefix('[').and_then(|ty| ty.strip_suffix(']')) {
+        Some(item) => format!("ListOf{}", type_name_part(item)),
+        None => ty.to_string(),
+    };
+    if nullable {
+        format!("Nullable{}", name)
+    } else {
+        name
+    }
+}
+
+struct KeyValuePair<'a, K, V> {
+    key: &'a K,
+    value: &'a V,
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<K: ScalarType + OutputType, V: OutputType> ContainerType for KeyValuePair<'_, K, V> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        match ctx.item.node.name.node.as_str() {
+            "key" => OutputType::resolve(self.key, &ctx_obj, ctx.item)
+                .await
+                .map(Some),
+            "value" => OutputType::resolve(self.value, &ctx_obj, ctx.item)
+                .await
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<K: ScalarType + OutputType, V: OutputType> OutputType for KeyValuePair<'_, K, V> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{}{}KeyValuePair",
+            K::type_name(),
+            type_name_part(&V::qualified_type_name())
+        ))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Object, |registry| {
+            let mut fields = IndexMap::new();
+            for (name, ty) in [
+                ("key", K::create_type_info(registry)),
+                ("value", V::create_type_info(registry)),
+            ] {
+                fields.insert(
+                    name.to_string(),
+                    MetaField {
+                        name: name.to_string(),
+                        description: None,
+                        args: Default::default(),
+                        ty,
+                        deprecation: Deprecation::NoDeprecated,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        shareable: false,
+                        inaccessible: false,
+                        tags: Default::default(),
+                        override_from: None,
+                        authenticated: false,
+                        requires_scopes: Default::default(),
+                        compute_complexity: None,
+                        cost: None,
+                        list_size: None,
+                        directive_invocations: Default::default(),
+                    },
+                );
+            }
+
+            MetaType::Object {
+                name: Self::type_name().to_string(),
+                description: None,
+                fields,
+                cache_control: CacheControl::default(),
+                extends: false,
+                shareable: false,
+                resolvable: true,
+                keys: None,
+                visible: None,
+                inaccessible: false,
+                interface_object: false,
+                tags: Default::default(),
+                is_subscription: false,
+                rust_typename: None,
+                directive_invocations: Default::default(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<K: ScalarType + OutputType, V: OutputType> ObjectType for KeyValuePair<'_, K, V> {}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<K: ScalarType + OutputType + Ord, V: OutputType, const LAST_WINS: bool> OutputType
+    for KeyValueList<BTreeMap<K, V>, LAST_WINS>
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", KeyValuePair::<K, V>::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", KeyValuePair::<K, V>::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        KeyValuePair::<K, V>::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let pairs = self
+            .0
+            .iter()
+            .map(|(key, value)| KeyValuePair { key, value });
+        resolve_list(ctx, field, pairs, Some(self.0.len())).await
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<K, V, S, const LAST_WINS: bool> OutputType for KeyValueList<HashMap<K, V, S>, LAST_WINS>
+where
+    K: ScalarType + OutputType,
+    V: OutputType,
+    S: BuildHasher + Send + Sync,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", KeyValuePair::<K, V>::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", KeyValuePair::<K, V>::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        KeyValuePair::<K, V>::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let pairs = self
+            .0
+            .iter()
+            .map(|(key, value)| KeyValuePair { key, value });
+        resolve_list(ctx, field, pairs, Some(self.0.len())).await
+    }
+}
+
+struct KeyValuePairInput<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: ScalarType + InputType, V: InputType> InputType for KeyValuePairInput<K, V> {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{}{}KeyValuePairInput",
+            K::type_name(),
+            type_name_part(&V::qualified_type_name())
+        ))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_input_type::<Self, _>(MetaTypeId::InputObject, |registry| {
+            let mut input_fields = IndexMap::new();
+            for (name, ty) in [
+                ("key", K::create_type_info(registry)),
+                ("value", V::create_type_info(registry)),
+            ] {
+                input_fields.insert(
+                    name.to_string(),
+                    MetaInputValue {
+                        name: name.to_string(),
+                        description: None,
+                        ty,
+                        deprecation: Deprecation::NoDeprecated,
+                        default_value: None,
+                        visible: None,
+                        inaccessible: false,
+                        tags: Default::default(),
+                        is_secret: false,
+                        directive_invocations: Default::default(),
+                    },
+                );
+            }
+
+            MetaType::InputObject {
+                name: Self::type_name().to_string(),
+                description: None,
+                input_fields,
+                visible: None,
+                inaccessible: false,
+                tags: Default::default(),
+                rust_typename: None,
+                oneof: false,
+                directive_invocations: Default::default(),
+            }
+        })
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        match value {
+            Some(Value::Object(obj)) => Ok(Self {
+                key: <K as InputType>::parse(obj.get("key").cloned())
+                    .map_err(InputValueError::propagate)?,
+                value: V::parse(obj.get("value").cloned()).map_err(InputValueError::propagate)?,
+            }),
+            value => Err(InputValueError::expected_type(value.unwrap_or_default())),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        pair_to_value(&self.key, &self.value)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl<K: ScalarType + InputType, V: InputType> InputObjectType for KeyValuePairInput<K, V> {}
+
+fn pair_to_value<K: InputType, V: InputType>(key: &K, value: &V) -> Value {
+    let mut map = IndexMap::new();
+    map.insert(Name::new("key"), key.to_value());
+    map.insert(Name::new("value"), value.to_value());
+    Value::Object(map)
+}
+
+/// Parses a list of pairs, `insert` returns `true` if the key was already
+/// present.
+fn parse_pairs<T, K, V>(
+    value: Option<Value>,
+    last_wins: bool,
+    mut insert: impl FnMut(K, V) -> bool,
+) -> Result<(), InputValueError<T>>
+where
+    T: InputType,
+    K: ScalarType + InputType,
+    V: InputType,
+{
+    let values = match value.unwrap_or_default() {
+        Value::List(values) => values,
+        value => vec![value],
+    };
+    for value in values {
+        let pair =
+            KeyValuePairInput::<K, V>::parse(Some(value)).map_err(InputValueError::propagate)?;
+        let key = InputType::to_value(&pair.key);
+        if insert(pair.key, pair.value) && !last_wins {
+            return Err(InputValueError::<T>::custom(format!(
+                "duplicate key {}",
+                key
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl<K, V, const LAST_WINS: bool> InputType for KeyValueList<BTreeMap<K, V>, LAST_WINS>
+where
+    K: ScalarType + InputType + Ord,
+    V: InputType,
+{
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "[{}]",
+            KeyValuePairInput::<K, V>::qualified_type_name()
+        ))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", KeyValuePairInput::<K, V>::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        KeyValuePairInput::<K, V>::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        let mut map = BTreeMap::new();
+        parse_pairs::<Self, K, V>(value, LAST_WINS, |key, value| {
+            map.insert(key, value).is_some()
+        })?;
+        Ok(Self(map))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::List(
+            self.0
+                .iter()
+                .map(|(key, value)| pair_to_value(key, value))
+                .collect(),
+        )
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl<K, V, S, const LAST_WINS: bool> InputType for KeyValueList<HashMap<K, V, S>, LAST_WINS>
+where
+    K: ScalarType + InputType + Hash + Eq,
+    V: InputType,
+    S: Default + BuildHasher + Send + Sync,
+{
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "[{}]",
+            KeyValuePairInput::<K, V>::qualified_type_name()
+        ))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", KeyValuePairInput::<K, V>::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        KeyValuePairInput::<K, V>::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        let mut map = HashMap::default();
+        parse_pairs::<Self, K, V>(value, LAST_WINS, |key, value| {
+            map.insert(key, value).is_some()
+        })?;
+        Ok(Self(map))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::List(
+            self.0
+                .iter()
+                .map(|(key, value)| pair_to_value(key, value))
+                .collect(),
+        )
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_key_value_list() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn scores(&self) -> KeyValueList<BTreeMap<String, i32>> {
+                let mut scores = BTreeMap::new();
+                scores.insert("b".to_string(), 2);
+                scores.insert("a".to_string(), 1);
+                scores.insert("c".to_string(), 3);
+                scores.into()
+            }
+
+            async fn names(&self) -> KeyValueList<HashMap<i32, String>> {
+                let mut names = HashMap::new();
+                names.insert(1, "one".to_string());
+                names.into()
+            }
+
+            async fn empty(&self) -> KeyValueList<BTreeMap<String, i32>> {
+                Default::default()
+            }
+
+            async fn optional(&self) -> KeyValueList<BTreeMap<String, Option<i32>>> {
+                Default::default()
+            }
+
+            async fn lists(&self) -> KeyValueList<BTreeMap<String, Option<Vec<Option<i32>>>>> {
+                Default::default()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute("{ scores { key value } names { key value } empty { key } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "scores": [
+                    { "key": "a", "value": 1 },
+                    { "key": "b", "value": 2 },
+                    { "key": "c", "value": 3 },
+                ],
+                "names": [{ "key": 1, "value": "one" }],
+                "empty": [],
+            })
+        );
+
+        let sdl = schema.sdl();
+        assert!(sdl.contains("scores: [StringIntKeyValuePair!]!"));
+        assert!(sdl.contains("names: [IntStringKeyValuePair!]!"));
+        assert!(sdl.contains("type StringIntKeyValuePair {\n\tkey: String!\n\tvalue: Int!\n}"));
+        assert!(sdl.contains("type IntStringKeyValuePair {\n\tkey: Int!\n\tvalue: String!\n}"));
+        assert!(sdl.contains("optional: [StringNullableIntKeyValuePair!]!"));
+        assert!(sdl.contains("lists: [StringNullableListOfNullableIntKeyValuePair!]!"));
+        assert!(sdl.contains(
+            "type StringNullableListOfNullableIntKeyValuePair {\n\tkey: String!\n\tvalue: [Int]\n}"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_key_value_list_values_sharing_a_name() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn small(&self) -> KeyValueList<BTreeMap<String, i32>> {
+                let mut values = BTreeMap::new();
+                values.insert("a".to_string(), 1);
+                values.into()
+            }
+
+            async fn large(&self) -> KeyValueList<HashMap<String, i64>> {
+                let mut values = HashMap::new();
+                values.insert("b".to_string(), 5_000_000_000);
+                values.into()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute("{ small { key value } large { key value } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "small": [{ "key": "a", "value": 1 }],
+                "large": [{ "key": "b", "value": 5_000_000_000i64 }],
+            })
+        );
+
+        let sdl = schema.sdl();
+        assert!(sdl.contains("small: [StringIntKeyValuePair!]!"));
+        assert!(sdl.contains("large: [StringIntKeyValuePair!]!"));
+    }
+
+    #[tokio::test]
+    async fn test_key_value_list_input() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn scores(&self, input: KeyValueList<HashMap<String, i32>>) -> Vec<String> {
+                let mut scores = input
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect::<Vec<_>>();
+                scores.sort();
+                scores
+            }
+
+            async fn last(
+                &self,
+                input: KeyValueList<BTreeMap<i32, String>, true>,
+            ) -> KeyValueList<BTreeMap<i32, String>> {
+                input.0.into()
+            }
+
+            async fn optional(&self, input: KeyValueList<BTreeMap<String, Option<i32>>>) -> i32 {
+                input.values().flatten().sum()
+            }
+
+            async fn large(&self, input: KeyValueList<HashMap<String, i64>>) -> i64 {
+                input.values().sum()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(r#"{ scores(input: [{ key: "a", value: 1 }, { key: "b", value: 2 }]) }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "scores": ["a=1", "b=2"] })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new("query($v: [StringIntKeyValuePairInput!]!) { scores(input: $v) }")
+                        .variables(Variables::from_value(value!({
+                            "v": [{ "key": "c", "value": 3 }],
+                        })))
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "scores": ["c=3"] })
+        );
+
+        assert_eq!(
+            schema
+                .execute(r#"{ scores(input: [{ key: "a", value: 1 }, { key: "a", value: 2 }]) }"#)
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Failed to parse "[StringIntKeyValuePairInput!]": duplicate key "a""#
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{ last(input: [{ key: 1, value: "a" }, { key: 1, value: "b" }]) { key value } }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "last": [{ "key": 1, "value": "b" }] })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{ optional(input: [{ key: "a", value: 1 }, { key: "b", value: null }]) }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "optional": 1 })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{ large(input: [{ key: "a", value: 5000000000 }, { key: "b", value: 1 }]) }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "large": 5_000_000_001i64 })
+        );
+
+        let sdl = schema.sdl();
+        assert!(
+            sdl.contains("input StringIntKeyValuePairInput {\n\tkey: String!\n\tvalue: Int!\n}")
+        );
+        assert!(sdl.contains("large(input: [StringIntKeyValuePairInput!]!): Int!"));
+        assert!(sdl.contains(
+            "input StringNullableIntKeyValuePairInput {\n\tkey: String!\n\tvalue: Int\n}"
+        ));
+    }
+}
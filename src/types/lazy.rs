@@ -0,0 +1,102 @@
+use std::{borrow::Cow, future::Future};
+
+use futures_util::future::{BoxFuture, FutureExt};
+
+use crate::{
+    parser::types::Field, registry::Registry, ContextBase, ContextSelectionSet, OutputType,
+    Positioned, QueryPathSegment, ServerResult, Value,
+};
+
+/// A value that is computed only when it's output.
+///
+/// The closure runs after the other fields of the object have been resolved,
+/// so it doesn't run at all if one of them fails and the object is nullified.
+/// This is only the case when the `Lazy` is the value of a field, not an item
+/// of a list, and when the fields are resolved concurrently, the top-level
+/// fields of a mutation run in place. The fields of the types without a `Lazy`
+/// field are resolved without waiting for each other.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> Lazy<i32> {
+///         Lazy::new_async(|| async { 10 })
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let res = schema.execute("{ value }").await.into_result().unwrap().data;
+/// assert_eq!(res, value!({ "value": 10 }));
+/// # });
+/// ```
+pub struct Lazy<T> {
+    f: Box<dyn Fn() -> BoxFuture<'static, T> + Send + Sync>,
+}
+
+impl<T: Send + 'static> Lazy<T> {
+    /// Create a lazy value from a closure.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self::new_async(move || {
+            let value = f();
+            async move { value }
+        })
+    }
+
+    /// Create a lazy value from an async closure.
+    pub fn new_async<F, R>(f: F) -> Self
+    where
+        F: Fn() -> R + Send + Sync + 'static,
+        R: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            f: Box::new(move || f().boxed()),
+        }
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T: OutputType + Send + Sync + 'static> OutputType for Lazy<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.add_lazy_field();
+        T::create_type_info(registry)
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let is_field_value = matches!(
+            ctx.path_node.map(|node| node.segment),
+            Some(QueryPathSegment::Name(_))
+        );
+        if let (true, Some(gate)) = (is_field_value, ctx.lazy_gate) {
+            gate.wait().await;
+        }
+        // A nested `Lazy` doesn't wait for the gate again.
+        let ctx = ContextBase {
+            lazy_gate: None,
+            ..ctx.clone()
+        };
+        let value = (self.f)().await;
+        value.resolve(&ctx, field).await
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{
+    InputValueError, InputValueResult, Number, NumberExt, Scalar, ScalarType, TypeName, Value,
+};
+
+/// Used to specify the values accepted by [`LenientBool`].
+pub trait LenientBoolOptions: Send + Sync {
+    /// Returns the scalar type name.
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("LenientBoolean")
+    }
+
+    /// Returns the strings accepted as `true`, compared case-insensitively.
+    fn true_strings() -> &'static [&'static str] {
+        &["true", "1"]
+    }
+
+    /// Returns the strings accepted as `false`, compared case-insensitively.
+    fn false_strings() -> &'static [&'static str] {
+        &["false", "0"]
+    }
+}
+
+/// Accepts `"true"`, `"false"`, `"1"` and `"0"` by default.
+pub struct DefaultLenientBoolOptions;
+
+impl LenientBoolOptions for DefaultLenientBoolOptions {}
+
+/// A boolean that also accepts string and number representations.
+///
+/// Some clients send booleans as strings or numbers, this scalar accepts
+/// `true`, `false`, the numbers `1` and `0`, and the strings returned by
+/// [`LenientBoolOptions`]. Other strings, such as `"yes"`, are rejected unless
+/// the options accept them. It's always output as a boolean.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct YesNo;
+///
+/// impl LenientBoolOptions for YesNo {
+///     fn true_strings() -> &'static [&'static str] {
+///         &["true", "1", "yes"]
+///     }
+///
+///     fn false_strings() -> &'static [&'static str] {
+///         &["false", "0", "no"]
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self, enabled: LenientBool<YesNo>) -> bool {
+///         *enabled
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let res = schema.execute(r#"{ value(enabled: "yes") }"#).await;
+/// assert_eq!(res.data, value!({ "value": true }));
+/// # });
+/// ```
+pub struct LenientBool<O = DefaultLenientBoolOptions> {
+    value: bool,
+    _mark: PhantomData<O>,
+}
+
+impl<O> LenientBool<O> {
+    /// Create a lenient boolean.
+    #[inline]
+    pub fn new(value: bool) -> Self {
+        Self {
+            value,
+            _mark: PhantomData,
+        }
+    }
+
+    /// Returns the boolean value.
+    #[inline]
+    pub fn into_inner(self) -> bool {
+        self.value
+    }
+}
+
+impl<O> Deref for LenientBool<O> {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<O> From<bool> for LenientBool<O> {
+    fn from(value: bool) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<O> Clone for LenientBool<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O> Copy for LenientBool<O> {}
+
+impl<O> PartialEq for LenientBool<O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<O> Eq for LenientBool<O> {}
+
+impl<O> Debug for LenientBool<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LenientBool").field(&self.value).finish()
+    }
+}
+
+impl<O: LenientBoolOptions> TypeName for LenientBool<O> {
+    fn type_name() -> Cow<'static, str> {
+        O::type_name()
+    }
+}
+
+impl<O: LenientBoolOptions> LenientBool<O> {
+    fn parse_value(value: &Value) -> Option<bool> {
+        match value {
+            Value::Boolean(b) => Some(*b),
+            Value::Number(n) if n.eq_value(&Number::from(1)) => Some(true),
+            Value::Number(n) if n.eq_value(&Number::from(0)) => Some(false),
+            Value::String(s) => {
+                let matches = |strings: &[&str]| strings.iter().any(|x| x.eq_ignore_ascii_case(s));
+                if matches(O::true_strings()) {
+                    Some(true)
+                } else if matches(O::false_strings()) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[Scalar(internal, name_type)]
+impl<O: LenientBoolOptions> ScalarType for LenientBool<O> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match Self::parse_value(&value) {
+            Some(b) => Ok(Self::new(b)),
+            None => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        Self::parse_value(value).is_some()
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Boolean(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Query;
+
+    #[Object(internal)]
+    impl Query {
+        async fn value(&self, input: LenientBool) -> LenientBool {
+            input
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lenient_bool() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{
+                    a: value(input: "true")
+                    b: value(input: "1")
+                    c: value(input: 1)
+                    d: value(input: "FALSE")
+                    e: value(input: "0")
+                    f: value(input: 0)
+                    g: value(input: true)
+                }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "a": true,
+                "b": true,
+                "c": true,
+                "d": false,
+                "e": false,
+                "f": false,
+                "g": true,
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new("query($v: LenientBoolean!) { value(input: $v) }")
+                        .variables(Variables::from_value(value!({ "v": 1.0 })))
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lenient_bool_rejected() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(r#"{ value(input: "maybe") }"#)
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Invalid value for argument "input", expected type "LenientBoolean""#
+        );
+        assert_eq!(
+            schema
+                .execute(r#"{ value(input: "yes") }"#)
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Invalid value for argument "input", expected type "LenientBoolean""#
+        );
+        assert_eq!(
+            schema
+                .execute("{ value(input: 2) }")
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Invalid value for argument "input", expected type "LenientBoolean""#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lenient_bool_options() {
+        struct YesNo;
+
+        impl LenientBoolOptions for YesNo {
+            fn type_name() -> std::borrow::Cow<'static, str> {
+                "YesNoBoolean".into()
+            }
+
+            fn true_strings() -> &'static [&'static str] {
+                &["yes"]
+            }
+
+            fn false_strings() -> &'static [&'static str] {
+                &["no"]
+            }
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, input: LenientBool<YesNo>) -> bool {
+                *input
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(r#"{ a: value(input: "Yes") b: value(input: "no") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "a": true, "b": false })
+        );
+        assert!(schema.execute(r#"{ value(input: "true") }"#).await.is_err());
+        assert!(schema.sdl().contains("scalar YesNoBoolean"));
+    }
+}
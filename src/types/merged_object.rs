@@ -19,6 +19,10 @@ where
     A: ContainerType,
     B: ContainerType,
 {
+    fn can_check_guards() -> bool {
+        A::can_check_guards() && B::can_check_guards()
+    }
+
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         match self.0.resolve_field(ctx).await {
             Ok(Some(value)) => Ok(Some(value)),
@@ -3,10 +3,14 @@
 pub mod connection;
 
 mod any;
+mod download;
 mod empty_mutation;
 mod empty_subscription;
 mod id;
 mod json;
+mod key_value_list;
+mod lazy;
+mod lenient_bool;
 mod maybe_undefined;
 mod merged_object;
 mod query_root;
@@ -17,13 +21,20 @@ mod upload;
 mod external;
 
 pub use any::Any;
+pub(crate) use download::take_downloads;
+pub use download::{Download, DownloadValue};
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
 pub use id::ID;
 pub use json::Json;
+pub use key_value_list::KeyValueList;
+pub use lazy::Lazy;
+pub use lenient_bool::{DefaultLenientBoolOptions, LenientBool, LenientBoolOptions};
 pub use maybe_undefined::MaybeUndefined;
 pub use merged_object::{MergedObject, MergedObjectTail};
-pub(crate) use query_root::QueryRoot;
+pub(crate) use query_root::{resolve_introspection_field, QueryRoot};
 #[cfg(feature = "string_number")]
 pub use string_number::StringNumber;
+#[cfg(feature = "tempfile")]
+pub(crate) use upload::spool_uploads;
 pub use upload::{Upload, UploadValue};
@@ -21,45 +21,55 @@ pub(crate) struct QueryRoot<T> {
     pub(crate) inner: T,
 }
 
+/// Resolves the `__schema` and `__type` fields of the query root, returns
+/// `None` for the other fields or if the introspection is disabled.
+pub(crate) async fn resolve_introspection_field(ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+    if matches!(
+        ctx.schema_env.registry.introspection_mode,
+        IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly
+    ) && matches!(
+        ctx.query_env.introspection_mode,
+        IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly,
+    ) {
+        if ctx.item.node.name.node == "__schema" {
+            let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+            ctx_obj.is_for_introspection = true;
+            let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
+            return OutputType::resolve(
+                &__Schema::new(&ctx.schema_env.registry, &visible_types),
+                &ctx_obj,
+                ctx.item,
+            )
+            .await
+            .map(Some);
+        } else if ctx.item.node.name.node == "__type" {
+            let (_, type_name) = ctx.param_value::<String>("name", None)?;
+            let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+            ctx_obj.is_for_introspection = true;
+            let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
+            return OutputType::resolve(
+                &ctx.schema_env
+                    .registry
+                    .types
+                    .get(&type_name)
+                    .filter(|_| visible_types.contains(type_name.as_str()))
+                    .map(|ty| __Type::new_simple(&ctx.schema_env.registry, &visible_types, ty)),
+                &ctx_obj,
+                ctx.item,
+            )
+            .await
+            .map(Some);
+        }
+    }
+
+    Ok(None)
+}
+
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
 impl<T: ObjectType> ContainerType for QueryRoot<T> {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
-        if matches!(
-            ctx.schema_env.registry.introspection_mode,
-            IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly
-        ) && matches!(
-            ctx.query_env.introspection_mode,
-            IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly,
-        ) {
-            if ctx.item.node.name.node == "__schema" {
-                let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                ctx_obj.is_for_introspection = true;
-                let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
-                return OutputType::resolve(
-                    &__Schema::new(&ctx.schema_env.registry, &visible_types),
-                    &ctx_obj,
-                    ctx.item,
-                )
-                .await
-                .map(Some);
-            } else if ctx.item.node.name.node == "__type" {
-                let (_, type_name) = ctx.param_value::<String>("name", None)?;
-                let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                ctx_obj.is_for_introspection = true;
-                let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
-                return OutputType::resolve(
-                    &ctx.schema_env
-                        .registry
-                        .types
-                        .get(&type_name)
-                        .filter(|_| visible_types.contains(type_name.as_str()))
-                        .map(|ty| __Type::new_simple(&ctx.schema_env.registry, &visible_types, ty)),
-                    &ctx_obj,
-                    ctx.item,
-                )
-                .await
-                .map(Some);
-            }
+        if let Some(value) = resolve_introspection_field(ctx).await? {
+            return Ok(Some(value));
         }
 
         if ctx.schema_env.registry.introspection_mode == IntrospectionMode::IntrospectionOnly
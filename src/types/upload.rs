@@ -139,6 +139,41 @@ impl Upload {
     pub fn value(&self, ctx: &Context<'_>) -> std::io::Result<UploadValue> {
         ctx.query_env.uploads[self.0].try_clone()
     }
+
+    /// Get the path of the temporary file the upload was moved to, if it's
+    /// larger than the threshold set with
+    /// [`SchemaBuilder::upload_spool_threshold`](crate::SchemaBuilder::upload_spool_threshold).
+    #[cfg(feature = "tempfile")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tempfile")))]
+    pub fn path<'a>(&self, ctx: &Context<'a>) -> Option<&'a std::path::Path> {
+        ctx.query_env.upload_paths.get(self.0)?.as_deref()
+    }
+}
+
+/// Moves the uploads larger than `threshold` bytes to named temporary files,
+/// the files are removed when the returned paths are dropped.
+#[cfg(feature = "tempfile")]
+pub(crate) fn spool_uploads(
+    uploads: &mut [UploadValue],
+    threshold: usize,
+) -> std::io::Result<Vec<Option<tempfile::TempPath>>> {
+    use std::io::Seek;
+
+    uploads
+        .iter_mut()
+        .map(|upload| {
+            if upload.size()? <= threshold as u64 {
+                return Ok(None);
+            }
+            let mut file = tempfile::NamedTempFile::new()?;
+            upload.content.rewind()?;
+            std::io::copy(&mut upload.content, &mut file)?;
+            let (mut file, path) = file.into_parts();
+            file.rewind()?;
+            upload.content = file;
+            Ok(Some(path))
+        })
+        .collect()
 }
 
 impl Deref for Upload {
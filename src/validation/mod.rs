@@ -5,14 +5,18 @@ mod test_harness;
 mod rules;
 mod suggestion;
 mod utils;
+mod variables;
 mod visitor;
 mod visitors;
 
+pub(crate) use utils::is_valid_input_value;
+pub(crate) use variables::coerce_variables;
 pub use visitor::VisitorContext;
 use visitor::{visit, VisitorNil};
 
 use crate::{
-    parser::types::ExecutableDocument, registry::Registry, CacheControl, ServerError, Variables,
+    parser::types::ExecutableDocument, registry::Registry, CacheControl, ErrorCode, ServerError,
+    Variables,
 };
 
 /// Validation results.
@@ -106,18 +110,23 @@ pub(crate) fn check_rules(
     // check limit
     if let Some(limit_complexity) = limit_complexity {
         if complexity > limit_complexity {
-            return Err(vec![ServerError::new("Query is too complex.", None)]);
+            return Err(vec![ServerError::new("Query is too complex.", None)
+                .with_code(ErrorCode::QueryTooComplex)]);
         }
     }
 
     if let Some(limit_depth) = limit_depth {
         if depth > limit_depth {
-            return Err(vec![ServerError::new("Query is nested too deep.", None)]);
+            return Err(vec![ServerError::new("Query is nested too deep.", None)
+                .with_code(ErrorCode::QueryTooDeep)]);
         }
     }
 
     if !errors.is_empty() {
-        return Err(errors.into_iter().map(Into::into).collect());
+        return Err(errors
+            .into_iter()
+            .map(|err| ServerError::from(err).with_code(ErrorCode::ValidationFailed))
+            .collect());
     }
 
     Ok(ValidationResult {
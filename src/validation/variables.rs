@@ -0,0 +1,132 @@
+use async_graphql_value::{ConstValue, Number};
+
+use crate::{
+    context::QueryPathNode,
+    parser::types::{DocumentOperations, ExecutableDocument, OperationDefinition},
+    registry::{MetaType, MetaTypeName, Registry},
+    validation::utils::is_valid_input_value,
+    ErrorCode, Positioned, QueryPathSegment, ServerError, Variables,
+};
+
+/// Coerces the variables of the executed operation to their declared types.
+///
+/// Integral floats, such as `1.0`, are converted to integers when the declared
+/// type is `Int`, because some JSON encoders can't tell them apart. The
+/// required variables must be provided and all the variables must be valid
+/// values of their declared types, even if they are only used in positions
+/// accepting other values.
+pub(crate) fn coerce_variables(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    operation_name: Option<&str>,
+    variables: &mut Variables,
+) -> Result<(), Vec<ServerError>> {
+    let Some(operation) = selected_operation(doc, operation_name) else {
+        return Ok(());
+    };
+
+    let mut errors = Vec::new();
+    for definition in &operation.node.variable_definitions {
+        let name = &definition.node.name.node;
+        let var_type = definition.node.var_type.node.to_string();
+        if !registry
+            .types
+            .contains_key(MetaTypeName::concrete_typename(&var_type))
+        {
+            // Reported by the `KnownTypeNames` rule.
+            continue;
+        }
+
+        match variables.get_mut(name) {
+            Some(value) => {
+                coerce_integral_floats(registry, &var_type, value);
+                let var_name = format!("${}", name);
+                if let Some(reason) = is_valid_input_value(
+                    registry,
+                    &var_type,
+                    value,
+                    QueryPathNode {
+                        parent: None,
+                        segment: QueryPathSegment::Name(&var_name),
+                    },
+                ) {
+                    errors.push(ServerError::new(
+                        format!("Invalid value for variable {}", reason),
+                        Some(definition.pos),
+                    ));
+                }
+            }
+            None if definition.node.default_value().is_none() => {
+                errors.push(ServerError::new(
+                    format!(
+                        r#"Variable "${}" of required type "{}" was not provided."#,
+                        name, var_type
+                    ),
+                    Some(definition.pos),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors
+            .into_iter()
+            .map(|err| err.with_code(ErrorCode::ValidationFailed))
+            .collect())
+    }
+}
+
+fn selected_operation<'a>(
+    doc: &'a ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Option<&'a Positioned<OperationDefinition>> {
+    match (&doc.operations, operation_name) {
+        (DocumentOperations::Single(operation), None) => Some(operation),
+        (DocumentOperations::Multiple(operations), Some(name)) => operations.get(name),
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+            operations.values().next()
+        }
+        _ => None,
+    }
+}
+
+fn coerce_integral_floats(registry: &Registry, type_name: &str, value: &mut ConstValue) {
+    match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => coerce_integral_floats(registry, type_name, value),
+        MetaTypeName::List(type_name) => match value {
+            ConstValue::List(values) => {
+                for value in values {
+                    coerce_integral_floats(registry, type_name, value);
+                }
+            }
+            value => coerce_integral_floats(registry, type_name, value),
+        },
+        MetaTypeName::Named("Int") => {
+            if let ConstValue::Number(n) = value {
+                // 2^63, the floats in `-2^63..2^63` fit in an `i64`.
+                const LIMIT: f64 = 9223372036854775808.0;
+
+                match n.as_f64() {
+                    Some(f) if n.is_f64() && f.fract() == 0.0 && (-LIMIT..LIMIT).contains(&f) => {
+                        *n = Number::from(f as i64)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        MetaTypeName::Named(type_name) => {
+            if let (Some(MetaType::InputObject { input_fields, .. }), ConstValue::Object(values)) =
+                (registry.types.get(type_name), value)
+            {
+                for (name, value) in values {
+                    if let Some(field) = input_fields.get(name.as_str()) {
+                        coerce_integral_floats(registry, &field.ty, value);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -92,6 +92,11 @@ impl<'a> VisitorContext<'a> {
         self.fragments.get(name)
     }
 
+    #[doc(hidden)]
+    pub fn naming_convention(&self) -> registry::NamingConvention {
+        self.registry.naming_convention
+    }
+
     #[doc(hidden)]
     pub fn param_value<T: InputType>(
         &self,
@@ -3,7 +3,7 @@ use async_graphql_value::Name;
 
 use crate::{
     parser::types::Field,
-    registry::{MetaType, MetaTypeName},
+    registry::{MetaField, MetaListSize, MetaType, MetaTypeName},
     validation::visitor::{VisitMode, Visitor, VisitorContext},
     Positioned,
 };
@@ -11,6 +11,7 @@ use crate::{
 pub struct ComplexityCalculate<'ctx, 'a> {
     pub complexity: &'a mut usize,
     pub complexity_stack: Vec<usize>,
+    pub list_size_stack: Vec<Option<(&'ctx [String], usize)>>,
     pub variable_definition: Option<&'ctx [Positioned<VariableDefinition>]>,
 }
 
@@ -19,11 +20,74 @@ impl<'a> ComplexityCalculate<'_, 'a> {
         Self {
             complexity,
             complexity_stack: Default::default(),
+            list_size_stack: Default::default(),
             variable_definition: None,
         }
     }
 }
 
+impl<'ctx> ComplexityCalculate<'ctx, '_> {
+    fn meta_field(ctx: &VisitorContext<'ctx>, field: &Field) -> Option<&'ctx MetaField> {
+        match ctx.parent_type() {
+            Some(MetaType::Object { fields, .. }) => {
+                fields.get(MetaTypeName::concrete_typename(field.name.node.as_str()))
+            }
+            _ => None,
+        }
+    }
+
+    fn list_size(
+        &self,
+        ctx: &VisitorContext<'ctx>,
+        meta_field: &MetaField,
+        field: &Field,
+        list_size: &MetaListSize,
+    ) -> Result<usize, String> {
+        let sizes = list_size
+            .slicing_arguments
+            .iter()
+            .filter_map(|name| {
+                ctx.param_value::<Option<usize>>(
+                    self.variable_definition.unwrap_or(&[]),
+                    field,
+                    name,
+                    None,
+                )
+                .ok()
+                .flatten()
+                .or_else(|| {
+                    meta_field
+                        .args
+                        .get(name)
+                        .and_then(|arg| arg.default_value.as_deref())
+                        .and_then(|value| value.parse().ok())
+                })
+            })
+            .collect::<Vec<usize>>();
+
+        if list_size.require_one_slicing_argument
+            && !list_size.slicing_arguments.is_empty()
+            && sizes.len() != 1
+        {
+            let names = list_size
+                .slicing_arguments
+                .iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect::<Vec<_>>();
+            return Err(format!(
+                "Exactly one of the slicing arguments {} must be provided.",
+                names.join(", ")
+            ));
+        }
+
+        Ok(sizes
+            .into_iter()
+            .max()
+            .or(list_size.assumed_size)
+            .unwrap_or(1))
+    }
+}
+
 impl<'ctx> Visitor<'ctx> for ComplexityCalculate<'ctx, '_> {
     fn mode(&self) -> VisitMode {
         VisitMode::Inline
@@ -46,32 +110,61 @@ impl<'ctx> Visitor<'ctx> for ComplexityCalculate<'ctx, '_> {
         self.variable_definition = Some(&operation_definition.node.variable_definitions);
     }
 
-    fn enter_field(&mut self, _ctx: &mut VisitorContext<'_>, _field: &Positioned<Field>) {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
         self.complexity_stack.push(0);
+
+        let list_size = Self::meta_field(ctx, &field.node).and_then(|meta_field| {
+            let list_size = meta_field.list_size.as_ref()?;
+            match self.list_size(ctx, meta_field, &field.node, list_size) {
+                Ok(size) => Some((list_size.sized_fields.as_slice(), size)),
+                Err(err) => {
+                    ctx.report_error(vec![field.pos], err);
+                    None
+                }
+            }
+        });
+        self.list_size_stack.push(list_size);
     }
 
     fn exit_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
-        let children_complex = self.complexity_stack.pop().unwrap();
-
-        if let Some(MetaType::Object { fields, .. }) = ctx.parent_type() {
-            if let Some(meta_field) = fields.get(MetaTypeName::concrete_typename(
-                field.node.name.node.as_str(),
-            )) {
-                if let Some(f) = &meta_field.compute_complexity {
-                    match f(
-                        ctx,
-                        self.variable_definition.unwrap_or(&[]),
-                        &field.node,
-                        children_complex,
-                    ) {
-                        Ok(n) => {
-                            *self.complexity_stack.last_mut().unwrap() += n;
-                        }
-                        Err(err) => ctx.report_error(vec![field.pos], err.to_string()),
+        let mut children_complex = self.complexity_stack.pop().unwrap();
+        let list_size = self.list_size_stack.pop().flatten();
+
+        if let Some(meta_field) = Self::meta_field(ctx, &field.node) {
+            if let Some(f) = &meta_field.compute_complexity {
+                match f(
+                    ctx,
+                    self.variable_definition.unwrap_or(&[]),
+                    &field.node,
+                    children_complex,
+                ) {
+                    Ok(n) => {
+                        *self.complexity_stack.last_mut().unwrap() += n;
                     }
-                    return;
+                    Err(err) => ctx.report_error(vec![field.pos], err.to_string()),
+                }
+                return;
+            }
+
+            // The list returned by this field
+            if let Some((sized_fields, size)) = list_size {
+                if sized_fields.is_empty() {
+                    children_complex = children_complex.saturating_mul(size);
                 }
             }
+
+            // The list nested in a field of the parent, e.g. the `edges` of a connection
+            if let Some(Some((sized_fields, size))) = self.list_size_stack.last() {
+                if sized_fields.contains(&meta_field.name) {
+                    children_complex = children_complex.saturating_mul(*size);
+                }
+            }
+
+            *self.complexity_stack.last_mut().unwrap() += meta_field
+                .cost
+                .unwrap_or(1)
+                .saturating_add(children_complex);
+            return;
         }
 
         *self.complexity_stack.last_mut().unwrap() += 1 + children_complex;
@@ -191,8 +284,10 @@ mod tests {
 
     #[track_caller]
     fn check_complexity(query: &str, expect_complexity: usize) {
-        let registry =
-            Schema::<Query, EmptyMutation, Subscription>::create_registry(Default::default());
+        let registry = Schema::<Query, EmptyMutation, Subscription>::create_registry(
+            Default::default(),
+            Default::default(),
+        );
         let doc = parse_query(query).unwrap();
         let mut ctx = VisitorContext::new(&registry, &doc, None);
         let mut complexity = 0;
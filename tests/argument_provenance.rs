@@ -0,0 +1,112 @@
+use async_graphql::*;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn items(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 10)] limit: i32,
+        offset: Option<i32>,
+    ) -> String {
+        format!(
+            "{limit} {:?} {:?} {:?}",
+            offset,
+            ctx.argument_provenance("limit"),
+            ctx.argument_provenance("offset")
+        )
+    }
+
+    async fn page(&self) -> Page {
+        Page
+    }
+}
+
+struct Page;
+
+#[Object]
+impl Page {
+    async fn items(&self, #[graphql(default = 10)] limit: i32) -> i32 {
+        limit
+    }
+}
+
+#[tokio::test]
+pub async fn test_argument_provenance() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let request = Request::new(
+        "query($limit: Int!) { a: items b: items(limit: 3, offset: 1) c: items(limit: $limit) }",
+    )
+    .variables(Variables::from_value(value!({ "limit": 5 })));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "a": "10 None Default Default",
+            "b": "3 Some(1) Client Client",
+            "c": "5 None Client Default",
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_argument_context_defaults() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(ArgumentDefaults::new().insert("Query.items(limit:)", 20))
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ a: items b: items(limit: 3) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "20 None ContextDefault Default",
+            "b": "3 None Client Default",
+        })
+    );
+
+    // the request data overrides the schema data
+    let request = Request::new("{ items }").data(
+        ArgumentDefaults::new()
+            .insert("Query.items(limit:)", 30)
+            .insert("Query.items(offset:)", 2),
+    );
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "items": "30 Some(2) ContextDefault ContextDefault" })
+    );
+
+    // the defaults only apply to the argument of their coordinate
+    let request = Request::new("{ page { items } }").data(
+        ArgumentDefaults::new()
+            .insert("Query.items(limit:)", 30)
+            .insert("Page.items(offset:)", 2)
+            .insert("Page.item(limit:)", 3),
+    );
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "page": { "items": 10 } })
+    );
+
+    let request = Request::new("{ page { items } }")
+        .data(ArgumentDefaults::new().insert("Page.items(limit:)", 40));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "page": { "items": 40 } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_argument_context_defaults_invalid() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let request = Request::new("{\n  items\n}")
+        .data(ArgumentDefaults::new().insert("Query.items(limit:)", "abc"));
+    let err = schema.execute(request).await.into_result().unwrap_err();
+    assert_eq!(
+        err[0].message,
+        r#"Invalid value for argument "limit", expected type "Int""#
+    );
+    assert_eq!(err[0].locations, vec![Pos { line: 2, column: 3 }]);
+}
@@ -26,7 +26,8 @@ pub async fn test_batch_request() {
             {"data": { "value": 70 }},
             {"data": null, "errors": [{
                 "message": r#"Unknown field "value1" on type "Query". Did you mean "value"?"#,
-                "locations": [{"line": 1, "column": 3}]
+                "locations": [{"line": 1, "column": 3}],
+                "extensions": {"code": "GRAPHQL_VALIDATION_FAILED"}
             }]},
         ])
     );
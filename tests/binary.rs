@@ -20,3 +20,78 @@ pub async fn test_batch_request() {
         })
     );
 }
+
+#[tokio::test]
+pub async fn test_binary_input() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len(&self, data: Bytes) -> usize {
+            data.len()
+        }
+
+        async fn echo(&self, data: Vec<Bytes>) -> Vec<Bytes> {
+            data
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    // Native binary values, as sent by transports such as CBOR.
+    let mut variables = Variables::default();
+    variables.insert(
+        Name::new("data"),
+        Value::Binary(Bytes::from_static(b"abcdef")),
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new("query($data: Bytes!) { len(data: $data) }").variables(variables))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 6 })
+    );
+
+    // Base64 strings, as sent by JSON transports.
+    let request = Request::new("query($data: [Bytes!]!) { echo(data: $data) }").variables(
+        Variables::from_json(serde_json::json!({ "data": ["YWJjZGVm", "AAEC"] })),
+    );
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "echo": [Bytes::from_static(b"abcdef"), Bytes::from_static(&[0, 1, 2])],
+        })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ len(data: "YWJjZGVm") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 6 })
+    );
+
+    // Invalid base64 strings are rejected by the validation.
+    let errors = schema
+        .execute(r#"{ len(data: "not base64!") }"#)
+        .await
+        .errors;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        r#"Invalid value for argument "data", expected type "Bytes""#
+    );
+
+    let request = Request::new("query($data: Bytes!) { len(data: $data) }").variables(
+        Variables::from_json(serde_json::json!({ "data": "not base64!" })),
+    );
+    let errors = schema.execute(request).await.errors;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        r#"Invalid value for variable "$data", expected type "Bytes""#
+    );
+}
@@ -0,0 +1,86 @@
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_set_cache_control() {
+    struct Query;
+
+    #[Object(cache_control(max_age = 60))]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn lower(&self, ctx: &Context<'_>, max_age: i32) -> i32 {
+            ctx.set_cache_control(CacheControl {
+                public: true,
+                max_age,
+            });
+            max_age
+        }
+
+        async fn private(&self, ctx: &Context<'_>, flag: bool) -> bool {
+            if flag {
+                ctx.set_cache_control(CacheControl {
+                    public: false,
+                    max_age: -1,
+                });
+            }
+            flag
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema.execute("{ value }").await.cache_control,
+        CacheControl {
+            public: true,
+            max_age: 60
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value lower(maxAge: 30) }")
+            .await
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 30
+        }
+    );
+
+    // the minimum wins
+    assert_eq!(
+        schema
+            .execute("{ a: lower(maxAge: 20) b: lower(maxAge: 5) c: lower(maxAge: 90) }")
+            .await
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 5
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ lower(maxAge: 30) private(flag: false) }")
+            .await
+            .cache_control,
+        CacheControl {
+            public: true,
+            max_age: 30
+        }
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ lower(maxAge: 30) private(flag: true) }")
+            .await
+            .cache_control,
+        CacheControl {
+            public: false,
+            max_age: -1
+        }
+    );
+}
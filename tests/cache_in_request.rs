@@ -0,0 +1,89 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use async_graphql::*;
+
+#[derive(Default)]
+struct Calls {
+    value: AtomicUsize,
+    add: AtomicUsize,
+    obj: AtomicUsize,
+}
+
+fn count(ctx: &Context<'_>, f: impl FnOnce(&Calls) -> &AtomicUsize) {
+    f(ctx.data_unchecked::<Arc<Calls>>()).fetch_add(1, Ordering::SeqCst);
+}
+
+struct Obj(i32);
+
+#[Object]
+impl Obj {
+    #[graphql(cache_in_request)]
+    async fn value(&self, ctx: &Context<'_>) -> i32 {
+        count(ctx, |calls| &calls.obj);
+        self.0
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    #[graphql(cache_in_request)]
+    async fn value(&self, ctx: &Context<'_>) -> i32 {
+        count(ctx, |calls| &calls.value);
+        10
+    }
+
+    #[graphql(cache_in_request)]
+    async fn add(&self, ctx: &Context<'_>, a: i32, b: i32) -> i32 {
+        count(ctx, |calls| &calls.add);
+        a + b
+    }
+
+    async fn objs(&self) -> Vec<Obj> {
+        vec![Obj(1), Obj(2)]
+    }
+}
+
+#[tokio::test]
+pub async fn test_cache_in_request() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let calls = Arc::new(Calls::default());
+    let query = r#"{
+        a: value
+        b: value
+        ... on Query { c: value }
+        x: add(a: 1, b: 2)
+        y: add(a: 1, b: 2)
+        z: add(a: 2, b: 2)
+        objs { a: value b: value }
+    }"#;
+    let resp = schema
+        .execute(Request::new(query).data(calls.clone()))
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({
+            "a": 10,
+            "b": 10,
+            "c": 10,
+            "x": 3,
+            "y": 3,
+            "z": 4,
+            "objs": [{ "a": 1, "b": 1 }, { "a": 2, "b": 2 }],
+        })
+    );
+    assert_eq!(calls.value.load(Ordering::SeqCst), 1);
+    assert_eq!(calls.add.load(Ordering::SeqCst), 2);
+    assert_eq!(calls.obj.load(Ordering::SeqCst), 2);
+
+    // the cache does not outlive the request
+    schema
+        .execute(Request::new("{ a: value b: value }").data(calls.clone()))
+        .await;
+    assert_eq!(calls.value.load(Ordering::SeqCst), 2);
+}
@@ -536,3 +536,97 @@ async fn test_flatten_with_result() {
         })
     );
 }
+
+#[tokio::test]
+async fn test_prepare() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    struct Stats {
+        followers: i32,
+        following: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(complex)]
+    struct User {
+        id: i32,
+    }
+
+    #[ComplexObject]
+    impl User {
+        #[graphql(prepare)]
+        async fn load_stats(&self, ctx: &Context<'_>) -> Result<Stats> {
+            ctx.data_unchecked::<Arc<AtomicUsize>>()
+                .fetch_add(1, Ordering::SeqCst);
+            Ok(Stats {
+                followers: self.id * 10,
+                following: self.id * 20,
+            })
+        }
+
+        async fn followers(&self, #[graphql(prepared)] stats: &Stats) -> i32 {
+            stats.followers
+        }
+
+        async fn following(&self, #[graphql(prepared)] stats: &Stats) -> i32 {
+            stats.following
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            User { id: 1 }
+        }
+
+        async fn users(&self) -> Vec<User> {
+            vec![User { id: 1 }, User { id: 2 }]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let execute = |query: &'static str| {
+        let schema = schema.clone();
+        async move {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let data = schema
+                .execute(Request::new(query).data(calls.clone()))
+                .await
+                .into_result()
+                .unwrap()
+                .data;
+            (data, calls.load(Ordering::SeqCst))
+        }
+    };
+
+    assert_eq!(
+        execute("{ user { followers following } }").await,
+        (value!({ "user": { "followers": 10, "following": 20 } }), 1)
+    );
+    assert_eq!(
+        execute("{ user { id } }").await,
+        (value!({ "user": { "id": 1 } }), 0)
+    );
+    assert_eq!(
+        execute("{ user { ... on User { followers } ...Following } } fragment Following on User { following }").await,
+        (
+            value!({ "user": { "followers": 10, "following": 20 } }),
+            1
+        )
+    );
+    assert_eq!(
+        execute("{ users { followers following } }").await,
+        (
+            value!({ "users": [
+                { "followers": 10, "following": 20 },
+                { "followers": 20, "following": 40 },
+            ] }),
+            2
+        )
+    );
+}
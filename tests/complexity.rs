@@ -0,0 +1,154 @@
+use async_graphql::{extensions::Analyzer, *};
+
+#[derive(SimpleObject)]
+struct Obj {
+    a: i32,
+    b: i32,
+}
+
+struct Query;
+
+#[Object]
+#[allow(unused_variables)]
+impl Query {
+    #[graphql(complexity = "count.clamp(1, 10) * child_complexity")]
+    async fn clamped(&self, count: usize) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(complexity = "first.min(last).max(1) * child_complexity + 1")]
+    async fn min_max(&self, first: usize, last: usize) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(complexity = "child_complexity * count / per_page")]
+    async fn paged(&self, count: usize, per_page: usize) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(complexity = "(count as f64).sqrt() * child_complexity as f64")]
+    async fn sqrt(&self, count: usize) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(complexity = "child_complexity as i64 - discount")]
+    async fn discounted(&self, discount: i64) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(complexity = "count as f64 * child_complexity as f64")]
+    async fn saturated(&self, count: usize) -> Vec<Obj> {
+        Vec::new()
+    }
+}
+
+async fn complexity(
+    schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+    query: &str,
+) -> ServerResult<Value> {
+    match schema.execute(query).await.into_result() {
+        Ok(resp) => match &resp.extensions["analyzer"] {
+            Value::Object(analyzer) => Ok(analyzer["complexity"].clone()),
+            _ => unreachable!(),
+        },
+        Err(mut errors) => Err(errors.remove(0)),
+    }
+}
+
+#[tokio::test]
+pub async fn test_complexity_clamp() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    assert_eq!(
+        complexity(&schema, "{ clamped(count: 5) { a b } }").await,
+        Ok(value!(10))
+    );
+    assert_eq!(
+        complexity(&schema, "{ clamped(count: 0) { a b } }").await,
+        Ok(value!(2))
+    );
+    assert_eq!(
+        complexity(&schema, "{ clamped(count: 1000000) { a b } }").await,
+        Ok(value!(20))
+    );
+}
+
+#[tokio::test]
+pub async fn test_complexity_min_max() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    assert_eq!(
+        complexity(&schema, "{ minMax(first: 3, last: 5) { a b } }").await,
+        Ok(value!(7))
+    );
+    assert_eq!(
+        complexity(&schema, "{ minMax(first: 0, last: 5) { a } }").await,
+        Ok(value!(2))
+    );
+}
+
+#[tokio::test]
+pub async fn test_complexity_division_by_zero() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    assert_eq!(
+        complexity(&schema, "{ paged(count: 100, perPage: 10) { a b } }").await,
+        Ok(value!(20))
+    );
+    assert_eq!(
+        complexity(&schema, "{ paged(count: 100, perPage: 0) { a b } }")
+            .await
+            .unwrap_err()
+            .message,
+        "Failed to evaluate the complexity expression."
+    );
+}
+
+#[tokio::test]
+pub async fn test_complexity_float() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    assert_eq!(
+        complexity(&schema, "{ sqrt(count: 100) { a b } }").await,
+        Ok(value!(20))
+    );
+    assert_eq!(
+        complexity(&schema, "{ sqrt(count: 10) { a } }").await,
+        Ok(value!(3))
+    );
+}
+
+#[tokio::test]
+pub async fn test_complexity_saturate() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .limit_complexity(100)
+        .finish();
+
+    assert_eq!(
+        complexity(&schema, "{ discounted(discount: 10) { a b } }").await,
+        Ok(value!(0))
+    );
+    assert_eq!(
+        complexity(&schema, "{ discounted(discount: -10) { a b } }").await,
+        Ok(value!(12))
+    );
+
+    let query = format!("{{ saturated(count: {}) {{ a b }} }}", usize::MAX);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err()[0].message,
+        "Query is too complex."
+    );
+    assert_eq!(
+        complexity(&schema, "{ saturated(count: 0) { a b } }").await,
+        Ok(value!(0))
+    );
+}
@@ -95,6 +95,69 @@ pub async fn test_connection_additional_fields() {
     );
 }
 
+#[tokio::test]
+pub async fn test_connection_edge_fields() {
+    #[derive(SimpleObject)]
+    struct EdgeScore {
+        score: f64,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn numbers(&self) -> Connection<usize, i32, EmptyFields, EdgeScore> {
+            let mut connection = Connection::new(false, false);
+            connection.edges.extend(
+                (0..2).map(|n| Edge::with_additional_fields(n, n as i32, EdgeScore { score: 0.0 })),
+            );
+            for edge in &mut connection.edges {
+                edge.additional_fields.score = edge.node as f64 / 2.0;
+            }
+            connection
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ numbers { edges { cursor node score } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "0", "node": 0, "score": 0.0},
+                    {"cursor": "1", "node": 1, "score": 0.5},
+                ],
+            },
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ __type(name: "IntEdge") { fields { name type { kind ofType { name } } } } }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "fields": [
+                    {"name": "node", "type": {"kind": "NON_NULL", "ofType": {"name": "Int"}}},
+                    {"name": "score", "type": {"kind": "NON_NULL", "ofType": {"name": "Float"}}},
+                    {"name": "cursor", "type": {"kind": "NON_NULL", "ofType": {"name": "String"}}},
+                ],
+            },
+        })
+    );
+}
+
 #[tokio::test]
 pub async fn test_connection_nodes() {
     struct Query;
@@ -225,3 +288,410 @@ pub async fn test_connection_nodes_disabled() {
         "Unknown field \"nodes\" on type \"IntConnection\"."
     );
 }
+
+#[test]
+pub fn test_offset_limit() {
+    let offset_limit = |offset, limit| OffsetLimit { offset, limit };
+
+    assert_eq!(
+        OffsetLimit::new(None, None, None, None),
+        offset_limit(0, None)
+    );
+    assert_eq!(
+        OffsetLimit::new(None, None, Some(3), None),
+        offset_limit(0, Some(3))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(4), None, Some(3), None),
+        offset_limit(5, Some(3))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(4), Some(7), Some(3), None),
+        offset_limit(5, Some(2))
+    );
+    assert_eq!(
+        OffsetLimit::new(None, Some(10), None, Some(3)),
+        offset_limit(7, Some(3))
+    );
+    assert_eq!(
+        OffsetLimit::new(None, Some(2), None, Some(3)),
+        offset_limit(0, Some(2))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(4), Some(10), None, Some(3)),
+        offset_limit(7, Some(3))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(8), Some(10), None, Some(3)),
+        offset_limit(9, Some(1))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(4), None, None, Some(3)),
+        offset_limit(5, None)
+    );
+    assert_eq!(
+        OffsetLimit::new(None, None, Some(5), Some(2)),
+        offset_limit(3, Some(2))
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(6), Some(3), None, None),
+        offset_limit(7, Some(0))
+    );
+
+    // The offsets saturate instead of overflowing.
+    assert_eq!(
+        OffsetLimit::new(Some(usize::MAX), None, None, None),
+        offset_limit(usize::MAX, None)
+    );
+    assert_eq!(
+        OffsetLimit::new(Some(usize::MAX - 5), None, Some(10), None),
+        offset_limit(usize::MAX - 4, Some(4))
+    );
+    assert_eq!(
+        OffsetLimit::new(None, None, Some(usize::MAX), None),
+        offset_limit(0, Some(usize::MAX))
+    );
+}
+
+#[tokio::test]
+pub async fn test_offset_query() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn numbers(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> Result<Connection<usize, i32>> {
+            connection::offset_query(after, before, first, last, |offset, limit| async move {
+                let numbers = (offset..10).map(|n| n as i32);
+                Ok::<_, Error>(match limit {
+                    Some(limit) => numbers.take(limit).collect::<Vec<_>>(),
+                    None => numbers.collect(),
+                })
+            })
+            .await
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let execute = |args: &str| {
+        let query = format!(
+            "{{ numbers({}) {{ edges {{ cursor node }} pageInfo {{ hasPreviousPage hasNextPage startCursor endCursor }} }} }}",
+            args
+        );
+        let schema = schema.clone();
+        async move { schema.execute(query).await.into_result().unwrap().data }
+    };
+
+    assert_eq!(
+        execute(r#"first: 2, after: "3""#).await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "4", "node": 4},
+                    {"cursor": "5", "node": 5},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": true,
+                    "startCursor": "4",
+                    "endCursor": "5",
+                },
+            },
+        })
+    );
+
+    assert_eq!(
+        execute(r#"last: 2, before: "3""#).await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "1", "node": 1},
+                    {"cursor": "2", "node": 2},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": true,
+                    "startCursor": "1",
+                    "endCursor": "2",
+                },
+            },
+        })
+    );
+
+    assert_eq!(
+        execute("last: 2").await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "8", "node": 8},
+                    {"cursor": "9", "node": 9},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "startCursor": "8",
+                    "endCursor": "9",
+                },
+            },
+        })
+    );
+
+    assert_eq!(
+        execute("first: 3").await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "0", "node": 0},
+                    {"cursor": "1", "node": 1},
+                    {"cursor": "2", "node": 2},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": false,
+                    "hasNextPage": true,
+                    "startCursor": "0",
+                    "endCursor": "2",
+                },
+            },
+        })
+    );
+
+    assert_eq!(
+        execute(r#"first: 5, last: 2, after: "1""#).await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "5", "node": 5},
+                    {"cursor": "6", "node": 6},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": true,
+                    "startCursor": "5",
+                    "endCursor": "6",
+                },
+            },
+        })
+    );
+
+    assert_eq!(
+        execute(r#"first: 5, after: "7""#).await,
+        value!({
+            "numbers": {
+                "edges": [
+                    {"cursor": "8", "node": 8},
+                    {"cursor": "9", "node": 9},
+                ],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "startCursor": "8",
+                    "endCursor": "9",
+                },
+            },
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_connection_invalid_arguments() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn numbers(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> Result<Connection<usize, i32>> {
+            connection::query(
+                after,
+                before,
+                first,
+                last,
+                |_after, _before, _first, _last| async move {
+                    Ok::<_, Error>(Connection::new(false, false))
+                },
+            )
+            .await
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let error = |args: &str| {
+        let query = format!("{{ numbers({}) {{ edges {{ node }} }} }}", args);
+        let schema = schema.clone();
+        async move {
+            schema
+                .execute(query)
+                .await
+                .into_result()
+                .unwrap_err()
+                .remove(0)
+                .message
+        }
+    };
+
+    assert_eq!(
+        error("first: -1").await,
+        r#"The "first" parameter must be a non-negative number"#
+    );
+    assert_eq!(
+        error("last: -1").await,
+        r#"The "last" parameter must be a non-negative number"#
+    );
+
+    // All the combinations of the parameters are allowed.
+    for args in [
+        "first: 1, last: 1",
+        r#"first: 1, before: "3""#,
+        r#"last: 1, after: "3""#,
+        r#"first: 1, last: 1, after: "1", before: "3""#,
+    ] {
+        let query = format!("{{ numbers({}) {{ edges {{ node }} }} }}", args);
+        assert!(schema.execute(query).await.is_ok());
+    }
+}
+
+async fn execute_strict_query(args: &str) -> Result<Value, Vec<ServerError>> {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn numbers(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> Result<Connection<usize, i32>> {
+            connection::query_strict(
+                after,
+                before,
+                first,
+                last,
+                |_after, _before, _first, _last| async move {
+                    Ok::<_, Error>(Connection::new(false, false))
+                },
+            )
+            .await
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = format!("{{ numbers({}) {{ edges {{ node }} }} }}", args);
+    schema
+        .execute(query)
+        .await
+        .into_result()
+        .map(|res| res.data)
+}
+
+#[tokio::test]
+pub async fn test_connection_strict_first_and_last() {
+    assert_eq!(
+        execute_strict_query("first: 1, last: 1").await.unwrap_err()[0].message,
+        r#"The "first" and "last" parameters cannot be used together"#
+    );
+}
+
+#[tokio::test]
+pub async fn test_connection_strict_first_and_before() {
+    assert_eq!(
+        execute_strict_query(r#"first: 1, before: "3""#)
+            .await
+            .unwrap_err()[0]
+            .message,
+        r#"The "first" parameter cannot be used with "before", use "last" to paginate backwards"#
+    );
+    assert!(execute_strict_query(r#"first: 1, after: "3""#)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+pub async fn test_connection_strict_last_and_after() {
+    assert_eq!(
+        execute_strict_query(r#"last: 1, after: "3""#)
+            .await
+            .unwrap_err()[0]
+            .message,
+        r#"The "last" parameter cannot be used with "after", use "first" to paginate forwards"#
+    );
+    assert!(execute_strict_query(r#"last: 1, before: "3""#)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+pub async fn test_connection_with_page_info() {
+    #[derive(SimpleObject)]
+    struct Total {
+        total: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn numbers(
+            &self,
+            end_cursor: Option<String>,
+        ) -> Result<Connection<usize, i32, Total>> {
+            let mut connection =
+                Connection::with_additional_fields(false, true, Total { total: 10 });
+            connection
+                .edges
+                .extend((3..5).map(|n| Edge::new(n, n as i32)));
+            connection.with_page_info(PageInfo {
+                has_previous_page: true,
+                has_next_page: false,
+                start_cursor: None,
+                end_cursor,
+            })
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ numbers(endCursor: "4") { nodes total pageInfo { hasPreviousPage hasNextPage startCursor endCursor } } }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "numbers": {
+                "nodes": [3, 4],
+                "total": 10,
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "startCursor": "3",
+                    "endCursor": "4",
+                },
+            },
+        })
+    );
+
+    // The cursors are checked when the page info is set, even if the page
+    // info is not queried.
+    assert_eq!(
+        schema
+            .execute(r#"{ numbers(endCursor: "5") { nodes } }"#)
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "The end cursor of the page info does not match the cursor of the last edge"
+    );
+}
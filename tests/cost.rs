@@ -0,0 +1,202 @@
+use async_graphql::{extensions::Analyzer, *};
+
+#[derive(SimpleObject)]
+struct Obj {
+    #[graphql(cost = 3)]
+    a: i32,
+    b: i32,
+}
+
+#[derive(SimpleObject)]
+struct Edge {
+    node: Obj,
+}
+
+#[derive(SimpleObject)]
+struct Connection {
+    edges: Vec<Edge>,
+    total_count: i32,
+}
+
+struct Query;
+
+#[Object]
+#[allow(unused_variables)]
+impl Query {
+    #[graphql(cost = 10)]
+    async fn obj(&self) -> Obj {
+        Obj { a: 1, b: 2 }
+    }
+
+    #[graphql(list_size(assumed_size = 20))]
+    async fn objs(&self) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(list_size(slicing_argument = "first", slicing_argument = "last"))]
+    async fn sliced(&self, first: Option<usize>, last: Option<usize>) -> Vec<Obj> {
+        Vec::new()
+    }
+
+    #[graphql(
+        cost = 2,
+        list_size(
+            slicing_argument = "first",
+            sized_field = "edges",
+            require_one_slicing_argument = false
+        )
+    )]
+    async fn connection(&self, #[graphql(default = 10)] first: usize) -> Connection {
+        Connection {
+            edges: Vec::new(),
+            total_count: 0,
+        }
+    }
+}
+
+async fn complexity(
+    schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+    query: &str,
+    variables: Value,
+) -> ServerResult<Value> {
+    let request = Request::new(query).variables(Variables::from_value(variables));
+    match schema.execute(request).await.into_result() {
+        Ok(resp) => match &resp.extensions["analyzer"] {
+            Value::Object(analyzer) => Ok(analyzer["complexity"].clone()),
+            _ => unreachable!(),
+        },
+        Err(mut errors) => Err(errors.remove(0)),
+    }
+}
+
+#[tokio::test]
+pub async fn test_cost() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    // 10 + (3 + 1)
+    assert_eq!(
+        complexity(&schema, "{ obj { a b } }", value!({})).await,
+        Ok(value!(14))
+    );
+
+    // 1 + 20 * 3
+    assert_eq!(
+        complexity(&schema, "{ objs { a } }", value!({})).await,
+        Ok(value!(61))
+    );
+}
+
+#[tokio::test]
+pub async fn test_list_size_slicing_arguments() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    // 1 + 5 * (3 + 1)
+    assert_eq!(
+        complexity(&schema, "{ sliced(first: 5) { a b } }", value!({})).await,
+        Ok(value!(21))
+    );
+    assert_eq!(
+        complexity(
+            &schema,
+            "query($n: Int) { sliced(last: $n) { a b } }",
+            value!({ "n": 7 })
+        )
+        .await,
+        Ok(value!(29))
+    );
+
+    assert_eq!(
+        complexity(&schema, "{ sliced { a b } }", value!({}))
+            .await
+            .unwrap_err()
+            .message,
+        r#"Exactly one of the slicing arguments "first", "last" must be provided."#
+    );
+    assert_eq!(
+        complexity(&schema, "{ sliced(first: 1, last: 1) { a b } }", value!({}))
+            .await
+            .unwrap_err()
+            .message,
+        r#"Exactly one of the slicing arguments "first", "last" must be provided."#
+    );
+}
+
+#[tokio::test]
+pub async fn test_list_size_sized_fields() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Analyzer)
+        .finish();
+
+    // 2 + (1 + (1 + 5 * (1 + 1)))
+    assert_eq!(
+        complexity(
+            &schema,
+            "{ connection(first: 5) { totalCount edges { node { b } } } }",
+            value!({})
+        )
+        .await,
+        Ok(value!(14))
+    );
+
+    // the default value of `first` is used, 2 + (1 + 10 * (1 + 1))
+    assert_eq!(
+        complexity(
+            &schema,
+            "{ connection { edges { node { b } } } }",
+            value!({})
+        )
+        .await,
+        Ok(value!(23))
+    );
+}
+
+#[tokio::test]
+pub async fn test_cost_limit_complexity() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(20)
+        .finish();
+
+    assert!(schema.execute("{ obj { a b } }").await.is_ok());
+    assert_eq!(
+        schema
+            .execute("{ objs { a } }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Query is too complex."
+    );
+}
+
+#[test]
+pub fn test_cost_sdl() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+
+    assert!(sdl.contains(r#"a: Int! @cost(weight: "3")"#));
+    assert!(sdl.contains(r#"obj: Obj! @cost(weight: "10")"#));
+    assert!(sdl.contains("objs: [Obj!]! @listSize(assumedSize: 20)"));
+    assert!(sdl.contains(
+        r#"sliced(first: Int, last: Int): [Obj!]! @listSize(slicingArguments: ["first", "last"])"#
+    ));
+    assert!(sdl.contains(
+        r#"connection(first: Int! = 10): Connection! @cost(weight: "2") @listSize(slicingArguments: ["first"], sizedFields: ["edges"], requireOneSlicingArgument: false)"#
+    ));
+    assert!(sdl.contains("directive @cost(weight: String!) on FIELD_DEFINITION"));
+    assert!(sdl.contains(
+        "directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION"
+    ));
+
+    #[derive(SimpleObject)]
+    struct PlainQuery {
+        value: i32,
+    }
+
+    let sdl = Schema::new(PlainQuery { value: 1 }, EmptyMutation, EmptySubscription).sdl();
+    assert!(!sdl.contains("@cost"));
+    assert!(!sdl.contains("@listSize"));
+}
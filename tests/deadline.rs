@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_deadline() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn has_deadline(&self, ctx: &Context<'_>) -> bool {
+            ctx.deadline().is_some()
+        }
+
+        async fn remaining_ms(&self, ctx: &Context<'_>) -> Option<u64> {
+            ctx.deadline().map(|deadline| {
+                deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as u64
+            })
+        }
+
+        async fn child(&self, ctx: &Context<'_>) -> Json<Value> {
+            Json(ctx.execute_child("{ hasDeadline }").await.data)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ hasDeadline remainingMs child }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "hasDeadline": false,
+            "remainingMs": null,
+            "child": { "hasDeadline": false },
+        })
+    );
+
+    let request = Request::new("{ hasDeadline remainingMs child }")
+        .deadline(Instant::now() + Duration::from_secs(60));
+    let data = schema.execute(request).await.into_result().unwrap().data;
+    let Value::Object(data) = data else {
+        unreachable!()
+    };
+    assert_eq!(data["hasDeadline"], value!(true));
+    assert!(matches!(
+        &data["remainingMs"],
+        Value::Number(n) if n.as_u64().unwrap() > 0 && n.as_u64().unwrap() <= 60000
+    ));
+    assert_eq!(data["child"], value!({ "hasDeadline": true }));
+}
@@ -114,3 +114,97 @@ pub async fn test_default_value_inputobject() {
         })
     );
 }
+
+#[tokio::test]
+pub async fn test_default_value_sdl() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        LightBlue,
+    }
+
+    struct Name(String);
+
+    #[Scalar]
+    impl ScalarType for Name {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(Name(s)),
+                value => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    #[derive(InputObject)]
+    struct Point {
+        x: i32,
+        #[graphql(default_with = "vec![Color::Red]")]
+        colors: Vec<Color>,
+        #[graphql(default_with = "Name(\"a\\u{1f}\\\"b\".to_string())")]
+        name: Name,
+    }
+
+    #[derive(InputObject)]
+    struct Shape {
+        #[graphql(
+            default_with = "vec![Point { x: 1, colors: vec![Color::LightBlue], name: Name(\"p\".to_string()) }]"
+        )]
+        points: Vec<Point>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(default_with = "vec![1, 2, 3]")] ids: Vec<i32>,
+            #[graphql(default_with = "Color::LightBlue")] color: Color,
+            shape: Option<Shape>,
+        ) -> bool {
+            let _ = (ids, color, shape);
+            true
+        }
+    }
+
+    let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+    assert!(sdl.contains(
+        "\tvalue(ids: [Int!]! = [1, 2, 3], color: Color! = LIGHT_BLUE, shape: Shape): Boolean!\n"
+    ));
+    assert!(sdl.contains(
+        r#"input Point {
+	x: Int!
+	colors: [Color!]! = [RED]
+	name: Name! = "a\u001F\"b"
+}"#
+    ));
+    assert!(sdl.contains(
+        r#"input Shape {
+	points: [Point!]! = [{x: 1, colors: [LIGHT_BLUE], name: "p"}]
+}"#
+    ));
+
+    // The defaults are valid GraphQL values.
+    let doc = parser::parse_schema(&sdl).unwrap();
+    let input_fields = doc
+        .definitions
+        .iter()
+        .find_map(|definition| match definition {
+            parser::types::TypeSystemDefinition::Type(ty) if ty.node.name.node == "Point" => {
+                match &ty.node.kind {
+                    parser::types::TypeKind::InputObject(input) => Some(&input.fields),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        input_fields[2].node.default_value.as_ref().unwrap().node,
+        value!("a\u{1f}\"b")
+    );
+}
@@ -155,3 +155,243 @@ pub async fn test_fields() {
         })
     );
 }
+
+#[tokio::test]
+pub async fn test_external_field_descriptions() {
+    /// Input
+    #[derive(InputObject)]
+    struct MyInput {
+        /// The value
+        value: i32,
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        /// A
+        A,
+        B,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        /// A value
+        async fn value(&self, input: MyInput) -> i32 {
+            input.value
+        }
+
+        async fn e(&self) -> MyEnum {
+            MyEnum::A
+        }
+    }
+
+    let (schema, warnings) = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .descriptions(
+            [
+                ("Query.value", "Une valeur"),
+                ("Query.e", "Une énumération"),
+                ("MyInput.value", "La valeur"),
+                ("MyEnum.A", "Un A"),
+                ("Query.unknown", "Inconnu"),
+                ("Query", "La requête"),
+            ]
+            .into_iter()
+            .map(|(key, desc)| (key.to_string(), desc.to_string()))
+            .collect(),
+        )
+        .finish_with_warnings();
+
+    let mut unknown = warnings
+        .iter()
+        .filter(|warning| warning.kind == SchemaWarningKind::UnknownDescription)
+        .map(|warning| warning.path.as_str())
+        .collect::<Vec<_>>();
+    unknown.sort_unstable();
+    assert_eq!(unknown, vec!["Query", "Query.unknown"]);
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains(
+        r#""""
+	Une valeur
+	"""
+	value(input: MyInput!): Int!"#
+    ));
+    assert!(sdl.contains(
+        r#""""
+	La valeur
+	"""
+	value: Int!"#
+    ));
+    assert!(sdl.contains(
+        r#""""
+	Un A
+	"""
+	A"#
+    ));
+    assert!(!sdl.contains("A value"));
+    assert!(!sdl.contains("The value"));
+    assert!(!sdl.contains("Inconnu"));
+    assert!(!sdl.contains("La requête"));
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Query") { fields { name description } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "fields": [
+                    { "name": "value", "description": "Une valeur" },
+                    { "name": "e", "description": "Une énumération" },
+                ]
+            }
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_localized_descriptions() {
+    #[derive(InputObject)]
+    struct MyInput {
+        /// The value
+        value: i32,
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        /// A
+        A,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        /// A value
+        async fn value(&self, #[graphql(desc = "The input")] input: MyInput) -> i32 {
+            input.value
+        }
+
+        /// An enum
+        async fn e(&self) -> MyEnum {
+            MyEnum::A
+        }
+    }
+
+    fn descriptions(items: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
+        items
+            .iter()
+            .map(|(key, desc)| (key.to_string(), desc.to_string()))
+            .collect()
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .localized_descriptions(
+            "fr",
+            descriptions(&[
+                ("Query.value", "Une valeur"),
+                ("Query.value(input:)", "L'entrée"),
+                ("MyInput.value", "La valeur"),
+                ("MyEnum.A", "Un A"),
+            ]),
+        )
+        .localized_descriptions("de", descriptions(&[("Query.value", "Ein Wert")]))
+        .finish();
+
+    let query = r#"{
+        query: __type(name: "Query") { fields { description args { description } } }
+        input: __type(name: "MyInput") { inputFields { description } }
+        enum: __type(name: "MyEnum") { enumValues { description } }
+    }"#;
+    let execute = |locale: Option<&str>| {
+        let mut request = Request::new(query);
+        if let Some(locale) = locale {
+            request = request.data(Locale::new(locale));
+        }
+        let schema = schema.clone();
+        async move { schema.execute(request).await.into_result().unwrap().data }
+    };
+
+    assert_eq!(
+        execute(Some("fr")).await,
+        value!({
+            "query": { "fields": [
+                { "description": "Une valeur", "args": [{ "description": "L'entrée" }] },
+                { "description": "An enum", "args": [] },
+            ] },
+            "input": { "inputFields": [{ "description": "La valeur" }] },
+            "enum": { "enumValues": [{ "description": "Un A" }] },
+        })
+    );
+    assert_eq!(
+        execute(Some("de")).await,
+        value!({
+            "query": { "fields": [
+                { "description": "Ein Wert", "args": [{ "description": "The input" }] },
+                { "description": "An enum", "args": [] },
+            ] },
+            "input": { "inputFields": [{ "description": "The value" }] },
+            "enum": { "enumValues": [{ "description": "A" }] },
+        })
+    );
+
+    let default = value!({
+        "query": { "fields": [
+            { "description": "A value", "args": [{ "description": "The input" }] },
+            { "description": "An enum", "args": [] },
+        ] },
+        "input": { "inputFields": [{ "description": "The value" }] },
+        "enum": { "enumValues": [{ "description": "A" }] },
+    });
+    assert_eq!(execute(Some("es")).await, default);
+    assert_eq!(execute(None).await, default);
+    assert!(schema.sdl().contains("A value"));
+}
+
+#[test]
+pub fn test_localized_descriptions_unknown() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        /// A value
+        async fn value(&self, a: i32) -> i32 {
+            a
+        }
+    }
+
+    let (_, warnings) = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .localized_descriptions(
+            "fr",
+            [
+                ("Query.value", "Une valeur"),
+                ("Query.value(a:)", "A"),
+                ("Query.value(b:)", "B"),
+                ("Query.unknown", "Inconnu"),
+                ("Query", "La requête"),
+            ]
+            .into_iter()
+            .map(|(key, desc)| (key.to_string(), desc.to_string()))
+            .collect(),
+        )
+        .finish_with_warnings();
+
+    let mut unknown = warnings
+        .iter()
+        .filter(|warning| warning.kind == SchemaWarningKind::UnknownDescription)
+        .map(|warning| (warning.path.as_str(), warning.message.as_str()))
+        .collect::<Vec<_>>();
+    unknown.sort_unstable();
+    let message = "The description for the locale `fr` does not match any field.";
+    assert_eq!(
+        unknown,
+        vec![
+            ("Query", message),
+            ("Query.unknown", message),
+            ("Query.value(b:)", message),
+        ]
+    );
+}
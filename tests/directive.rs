@@ -1,4 +1,7 @@
-use async_graphql::*;
+use async_graphql::{
+    registry::{__DirectiveLocation, MetaDirective, MetaInputValue},
+    *,
+};
 use serde::{Deserialize, Serialize};
 
 #[tokio::test]
@@ -220,3 +223,123 @@ pub async fn test_includes_one_of_directive() {
 
     assert!(schema.sdl().contains(r#"directive @oneOf on INPUT_OBJECT"#))
 }
+
+#[tokio::test]
+pub async fn test_register_directive() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn a(&self) -> String {
+            "a".into()
+        }
+    }
+
+    let max_age = MetaInputValue {
+        name: "maxAge".to_string(),
+        description: None,
+        ty: "Int!".to_string(),
+        deprecation: Default::default(),
+        default_value: None,
+        visible: None,
+        inaccessible: false,
+        tags: vec![],
+        is_secret: false,
+        directive_invocations: vec![],
+    };
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_directive(
+            MetaDirective::new(
+                "cached",
+                vec![
+                    __DirectiveLocation::OBJECT,
+                    __DirectiveLocation::FIELD_DEFINITION,
+                ],
+            )
+            .description("Cache the type in the gateway.")
+            .argument(max_age)
+            .repeatable(true),
+        )
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    __schema {
+                        directives {
+                            name description locations isRepeatable
+                            args { name type { kind ofType { name } } }
+                        }
+                    }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data
+            .into_json()
+            .unwrap()["__schema"]["directives"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|directive| directive["name"] == "cached")
+            .unwrap(),
+        &serde_json::json!({
+            "name": "cached",
+            "description": "Cache the type in the gateway.",
+            "locations": ["OBJECT", "FIELD_DEFINITION"],
+            "isRepeatable": true,
+            "args": [{
+                "name": "maxAge",
+                "type": { "kind": "NON_NULL", "ofType": { "name": "Int" } },
+            }],
+        })
+    );
+    assert!(schema
+        .sdl()
+        .contains("directive @cached(maxAge: Int!) repeatable on OBJECT | FIELD_DEFINITION"));
+}
+
+#[test]
+fn test_register_directive_repeatable_sdl() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn a(&self) -> String {
+            "a".into()
+        }
+    }
+
+    let directive = |name: &str, is_repeatable| {
+        MetaDirective::new(name, vec![__DirectiveLocation::OBJECT]).repeatable(is_repeatable)
+    };
+    let sdl = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_directive(directive("once", false))
+        .register_directive(directive("many", true))
+        .finish()
+        .sdl();
+    assert!(sdl.contains("directive @once on OBJECT\n"));
+    assert!(sdl.contains("directive @many repeatable on OBJECT\n"));
+}
+
+#[test]
+#[should_panic(expected = "Directive `cached` can't be used on `FIELD`")]
+fn test_register_directive_executable_location() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn a(&self) -> String {
+            "a".into()
+        }
+    }
+
+    let _ = Schema::build(Query, EmptyMutation, EmptySubscription).register_directive(
+        MetaDirective::new(
+            "cached",
+            vec![__DirectiveLocation::OBJECT, __DirectiveLocation::FIELD],
+        ),
+    );
+}
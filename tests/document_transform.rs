@@ -0,0 +1,185 @@
+use async_graphql::{
+    parser::types::{DocumentOperations, ExecutableDocument, Selection, SelectionSet},
+    *,
+};
+
+fn inject_argument(doc: &mut ExecutableDocument, field_name: &str, name: &str, value: &str) {
+    fn visit_selection_set(
+        selection_set: &mut SelectionSet,
+        field_name: &str,
+        name: &str,
+        value: &str,
+    ) {
+        for selection in &mut selection_set.items {
+            match &mut selection.node {
+                Selection::Field(field) => {
+                    if field.node.name.node == field_name {
+                        field.node.arguments.push((
+                            Positioned::new(Name::new(name), Pos::default()),
+                            Positioned::new(
+                                async_graphql_value::Value::String(value.to_string()),
+                                Pos::default(),
+                            ),
+                        ));
+                    }
+                    visit_selection_set(
+                        &mut field.node.selection_set.node,
+                        field_name,
+                        name,
+                        value,
+                    );
+                }
+                Selection::InlineFragment(fragment) => visit_selection_set(
+                    &mut fragment.node.selection_set.node,
+                    field_name,
+                    name,
+                    value,
+                ),
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    match &mut doc.operations {
+        DocumentOperations::Single(operation) => visit_selection_set(
+            &mut operation.node.selection_set.node,
+            field_name,
+            name,
+            value,
+        ),
+        DocumentOperations::Multiple(operations) => {
+            for operation in operations.values_mut() {
+                visit_selection_set(
+                    &mut operation.node.selection_set.node,
+                    field_name,
+                    name,
+                    value,
+                );
+            }
+        }
+    }
+    for fragment in doc.fragments.values_mut() {
+        visit_selection_set(
+            &mut fragment.node.selection_set.node,
+            field_name,
+            name,
+            value,
+        );
+    }
+}
+
+#[derive(SimpleObject)]
+struct Item {
+    tenant: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn items(&self, tenant: Option<String>) -> Vec<Item> {
+        vec![Item {
+            tenant: tenant.unwrap_or_default(),
+        }]
+    }
+
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[tokio::test]
+pub async fn test_document_transform_inject_argument() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_document_transform(|doc| inject_argument(doc, "items", "tenant", "abc"))
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ items { tenant } ... A } fragment A on Query { other: items { tenant } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "items": [{ "tenant": "abc" }],
+            "other": [{ "tenant": "abc" }],
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_document_transform_invalid_document() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_document_transform(|doc| inject_argument(doc, "value", "tenant", "abc"))
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ value }").await.into_result().unwrap_err(),
+        vec![ServerError::new(
+            r#"Unknown argument "tenant" on field "value" of type "Query"."#,
+            Some(Pos::default()),
+        )
+        .with_code(ErrorCode::ValidationFailed)]
+    );
+}
+
+struct IncludeFields;
+
+impl DocumentVisitor for IncludeFields {
+    fn enter_field(&self, field: &mut Positioned<parser::types::Field>) {
+        field.node.directives.push(Positioned::new(
+            parser::types::Directive {
+                name: Positioned::new(Name::new("include"), Pos::default()),
+                arguments: vec![(
+                    Positioned::new(Name::new("if"), Pos::default()),
+                    Positioned::new(async_graphql_value::Value::Boolean(true), Pos::default()),
+                )],
+            },
+            Pos::default(),
+        ));
+    }
+}
+
+#[tokio::test]
+pub async fn test_document_visitor() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_document_visitor(IncludeFields)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value ... on Query { items { tenant } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "value": 10,
+            "items": [{ "tenant": "" }],
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_document_transform_before_limits() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_document_visitor(IncludeFields)
+        .limit_directives(1)
+        .finish();
+    assert!(schema.execute("{ value }").await.is_ok());
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .add_document_visitor(IncludeFields)
+        .add_document_visitor(IncludeFields)
+        .limit_directives(1)
+        .finish();
+    assert_eq!(
+        schema.execute("{ value }").await.into_result().unwrap_err(),
+        vec![ServerError::new(
+            "The number of directives on the field `value` cannot be greater than `1`",
+            Some(Pos { line: 1, column: 3 }),
+        )
+        .with_code(ErrorCode::TooManyDirectives)]
+    );
+}
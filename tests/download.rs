@@ -0,0 +1,185 @@
+use async_graphql::*;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
+
+const CHUNKS: usize = 1024;
+const CHUNK_SIZE: usize = 4096;
+
+fn payload() -> impl Stream<Item = std::io::Result<Bytes>> + Send + 'static {
+    stream::iter((0..CHUNKS).map(|n| Ok(Bytes::from(vec![(n % 256) as u8; CHUNK_SIZE]))))
+}
+
+#[tokio::test]
+pub async fn test_download() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn file(&self) -> Download {
+            Download::new(payload())
+                .filename("large.bin")
+                .content_type("application/octet-stream")
+                .content_length((CHUNKS * CHUNK_SIZE) as u64)
+        }
+
+        async fn files(&self) -> Vec<Download> {
+            vec![
+                Download::from_bytes("abc"),
+                Download::from_async_read(futures_util::io::Cursor::new(vec![1u8; 10000])),
+            ]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let mut resp = schema
+        .execute("{ file { id filename contentType contentLength } }")
+        .await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "file": {
+                "id": 0,
+                "filename": "large.bin",
+                "contentType": "application/octet-stream",
+                "contentLength": CHUNKS * CHUNK_SIZE,
+            }
+        })
+    );
+    assert_eq!(resp.downloads.len(), 1);
+
+    let download = resp.downloads.remove(0);
+    assert_eq!(download.content_length, Some((CHUNKS * CHUNK_SIZE) as u64));
+    let mut stream = Box::pin(download.into_stream());
+    let mut count = 0;
+    let mut size = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.unwrap();
+        assert!(chunk.iter().all(|b| *b == (count % 256) as u8));
+        count += 1;
+        size += chunk.len();
+    }
+    assert_eq!(count, CHUNKS);
+    assert_eq!(size, CHUNKS * CHUNK_SIZE);
+
+    let mut resp = schema
+        .execute(
+            "{ a: files { id contentLength } b: file { id filename contentType contentLength } }",
+        )
+        .await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "a": [
+                { "id": 0, "contentLength": 3 },
+                { "id": 1, "contentLength": null },
+            ],
+            "b": {
+                "id": 2,
+                "filename": "large.bin",
+                "contentType": "application/octet-stream",
+                "contentLength": CHUNKS * CHUNK_SIZE,
+            }
+        })
+    );
+    let downloads = std::mem::take(&mut resp.downloads);
+    let mut downloads = downloads.into_iter();
+    assert_eq!(downloads.next().unwrap().into_bytes().await.unwrap(), "abc");
+    assert_eq!(
+        downloads.next().unwrap().into_bytes().await.unwrap(),
+        vec![1u8; 10000]
+    );
+}
+
+#[tokio::test]
+pub async fn test_download_stream() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn files(&self) -> impl Stream<Item = Download> {
+            stream::iter(0..3).map(|n| Download::from_bytes(format!("file{}", n)))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, Subscription);
+    let mut stream = schema.execute_stream("subscription { files { id contentLength } }");
+    for n in 0..3 {
+        let mut resp = stream.next().await.unwrap();
+        assert_eq!(
+            resp.data,
+            value!({ "files": { "id": n, "contentLength": 5 } })
+        );
+        assert_eq!(resp.downloads.len(), 1);
+        assert_eq!(
+            resp.downloads.remove(0).into_bytes().await.unwrap(),
+            format!("file{}", n)
+        );
+    }
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+pub async fn test_download_nulled_field() {
+    struct Obj;
+
+    #[Object]
+    impl Obj {
+        async fn file(&self) -> Download {
+            Download::from_bytes("abc")
+        }
+
+        async fn fail(&self) -> Result<i32> {
+            Err("failed".into())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> Option<Obj> {
+            Some(Obj)
+        }
+
+        async fn file(&self) -> Download {
+            Download::from_bytes("def")
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let mut resp = schema
+        .execute("{ obj { file { id } fail } file { id } }")
+        .await;
+    assert_eq!(resp.data, value!({ "obj": null, "file": { "id": 1 } }));
+    assert_eq!(resp.downloads.len(), 1);
+    let download = resp.downloads.remove(0);
+    assert_eq!(download.id, 1);
+    assert_eq!(download.into_bytes().await.unwrap(), "def");
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Download") { kind fields { name } } }"#)
+            .await
+            .data,
+        value!({
+            "__type": {
+                "kind": "OBJECT",
+                "fields": [
+                    { "name": "id" },
+                    { "name": "filename" },
+                    { "name": "contentType" },
+                    { "name": "contentLength" },
+                ],
+            }
+        })
+    );
+}
@@ -0,0 +1,189 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_dry_run() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Email(String);
+
+    #[Scalar]
+    impl ScalarType for Email {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(Email(s)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Role {
+        Admin,
+        Member,
+    }
+
+    #[derive(SimpleObject)]
+    struct User {
+        id: ID,
+        name: String,
+        age: i32,
+        role: Role,
+        email: Email,
+        nickname: Option<String>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn users(&self, limit: i32) -> Vec<User> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            (0..limit)
+                .map(|i| User {
+                    id: i.into(),
+                    name: "a".to_string(),
+                    age: 30,
+                    role: Role::Member,
+                    email: Email("a@example.com".to_string()),
+                    nickname: None,
+                })
+                .collect()
+        }
+    }
+
+    let query = "{ users(limit: 2) { __typename id name age role nickname ...Contact } }
+        fragment Contact on User { mail: email }";
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(Request::new(query).dry_run(true))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"No placeholder for the scalar "Email" in a dry run."#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 2,
+                column: 36
+            }],
+            path: vec![],
+            extensions: None,
+        }]
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .dry_run_placeholder("Email", "user@example.com")
+        .finish();
+    assert_eq!(
+        schema
+            .execute(Request::new(query).dry_run(true))
+            .await
+            .into_result()
+            .unwrap()
+            .data
+            .into_json()
+            .unwrap(),
+        serde_json::json!({
+            "users": [{
+                "__typename": "User",
+                "id": "",
+                "name": "",
+                "age": 0,
+                "role": "ADMIN",
+                "nickname": "",
+                "mail": "user@example.com",
+            }]
+        })
+    );
+
+    // arguments are still validated
+    assert!(schema
+        .execute(Request::new("{ users(limit: \"2\") { id } }").dry_run(true))
+        .await
+        .is_err());
+
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    schema.execute(query).await.into_result().unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+pub async fn test_dry_run_directives_and_introspection() {
+    struct Concat {
+        prefix: String,
+    }
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Concat {
+        async fn resolve_field(
+            &self,
+            _ctx: &Context<'_>,
+            resolve: ResolveFut<'_>,
+        ) -> ServerResult<Option<Value>> {
+            resolve.await.map(|value| {
+                value.map(|value| match value {
+                    Value::String(str) => Value::String(self.prefix.clone() + &str),
+                    _ => value,
+                })
+            })
+        }
+    }
+
+    #[Directive(location = "Field")]
+    fn concat(prefix: String) -> impl CustomDirective {
+        Concat { prefix }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn a(&self) -> String {
+            unreachable!()
+        }
+
+        async fn b(&self) -> i32 {
+            unreachable!()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .directive(concat)
+        .finish();
+    let query = r#"query($skip: Boolean!) {
+        a @concat(prefix: "&")
+        b @skip(if: true)
+        c: b @include(if: $skip)
+        ... @skip(if: $skip) { d: a }
+        __type(name: "Query") { name fields { name } }
+    }"#;
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .variables(Variables::from_value(value!({ "skip": false })))
+                    .dry_run(true)
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "&",
+            "d": "",
+            "__type": {
+                "name": "Query",
+                "fields": [{ "name": "a" }, { "name": "b" }],
+            },
+        })
+    );
+}
@@ -0,0 +1,95 @@
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_error_code() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn error(&self) -> Result<i32> {
+            Err(Error::new("my error").extend_with(|_, e| e.set("code", "MY_ERROR")))
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(2)
+        .finish();
+
+    let errors = schema.execute("{ value").await.into_result().unwrap_err();
+    assert_eq!(errors[0].code(), Some(ErrorCode::ParseFailed));
+
+    let errors = schema.execute("{ abc }").await.into_result().unwrap_err();
+    assert_eq!(
+        errors,
+        vec![ServerError::new(
+            r#"Unknown field "abc" on type "Query"."#,
+            Some(Pos { line: 1, column: 3 })
+        )
+        .with_code(ErrorCode::ValidationFailed)]
+    );
+    assert_eq!(
+        errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("GRAPHQL_VALIDATION_FAILED"))
+    );
+
+    let errors = schema
+        .execute("{ a: value b: value c: value }")
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![ServerError::new("Query is too complex.", None).with_code(ErrorCode::QueryTooComplex)]
+    );
+    assert_eq!(
+        errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("QUERY_TOO_COMPLEX"))
+    );
+
+    let errors = schema.execute("{ error }").await.into_result().unwrap_err();
+    assert_eq!(errors[0].code(), None);
+    assert_eq!(
+        errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("MY_ERROR"))
+    );
+    assert_eq!(
+        errors[0]
+            .clone()
+            .with_code(ErrorCode::ValidationFailed)
+            .extensions
+            .unwrap()
+            .get("code"),
+        Some(&value!("MY_ERROR"))
+    );
+}
+
+#[tokio::test]
+pub async fn test_error_code_depth() {
+    #[derive(SimpleObject)]
+    struct Obj {
+        value: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> Obj {
+            Obj { value: 10 }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(1)
+        .finish();
+    let errors = schema
+        .execute("{ obj { value } }")
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(errors[0].code(), Some(ErrorCode::QueryTooDeep));
+}
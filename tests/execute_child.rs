@@ -0,0 +1,64 @@
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_execute_child() {
+    struct Token(&'static str);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, ctx: &Context<'_>) -> &'static str {
+            ctx.data_unchecked::<Token>().0
+        }
+
+        async fn child(&self, ctx: &Context<'_>) -> Result<Json<Value>> {
+            let resp = ctx
+                .execute_child("{ value }")
+                .await
+                .into_result()
+                .map_err(|errors| Error::new(errors[0].message.clone()))?;
+            Ok(Json(resp.data))
+        }
+
+        async fn nested(&self, ctx: &Context<'_>) -> Result<Json<Value>> {
+            let resp = ctx
+                .execute_child("{ nested }")
+                .await
+                .into_result()
+                .map_err(|errors| Error::new(errors[0].message.clone()))?;
+            Ok(Json(resp.data))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(Request::new("{ child }").data(Token("abc")))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "child": { "value": "abc" },
+        })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_child_depth(3)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ nested }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The child request is nested too deeply, the limit is 3.".to_owned(),
+            source: None,
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("nested".to_owned())],
+            extensions: None,
+        }]
+    );
+}
@@ -0,0 +1,104 @@
+use async_graphql::*;
+use serde::Deserialize;
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+struct Obj {
+    a: i32,
+}
+
+#[ComplexObject]
+impl Obj {
+    async fn fail(&self) -> Result<i32> {
+        Err("failed".into())
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    async fn obj(&self) -> Option<Obj> {
+        Some(Obj { a: 1 })
+    }
+
+    async fn objs(&self) -> Vec<Option<Obj>> {
+        vec![Some(Obj { a: 1 }), Some(Obj { a: 2 })]
+    }
+
+    async fn fail(&self) -> Result<i32> {
+        Err("root failed".into())
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct ObjData {
+    a: i32,
+    fail: i32,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+    value: i32,
+    obj: Option<ObjData>,
+    objs: Vec<Option<ObjData>>,
+}
+
+#[tokio::test]
+pub async fn test_execute_partial() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let (data, errors) = schema
+        .execute_partial::<Data>("{ value obj { a fail } objs { a fail } }")
+        .await;
+    assert_eq!(
+        data,
+        Some(Data {
+            value: 10,
+            obj: None,
+            objs: vec![None, None],
+        })
+    );
+    assert_eq!(errors.len(), 3);
+    assert!(errors.iter().all(|err| err.message == "failed"));
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct ValueData {
+        value: i32,
+    }
+
+    let (data, errors) = schema.execute_partial::<ValueData>("{ value }").await;
+    assert_eq!(data, Some(ValueData { value: 10 }));
+    assert!(errors.is_empty());
+}
+
+#[tokio::test]
+pub async fn test_execute_partial_no_data() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let (data, errors) = schema.execute_partial::<Data>("{ value fail }").await;
+    assert_eq!(data, None);
+    assert_eq!(errors[0].message, "root failed");
+
+    // `obj` is nullified, but not deserialized into an `Option`.
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct RequiredData {
+        obj: ObjData,
+    }
+
+    let (data, errors) = schema
+        .execute_partial::<RequiredData>("{ obj { a fail } }")
+        .await;
+    assert!(data.is_none());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].message, "failed");
+    assert!(errors[1]
+        .message
+        .starts_with("Failed to deserialize the response data:"));
+}
@@ -333,6 +333,122 @@ pub async fn test_extension_call_order() {
     }
 }
 
+#[tokio::test]
+pub async fn test_extension_order() {
+    type Calls = Arc<Mutex<Vec<String>>>;
+
+    struct OrderExtensionImpl {
+        name: &'static str,
+        calls: Calls,
+    }
+
+    impl OrderExtensionImpl {
+        async fn push(&self, hook: &str) {
+            self.calls
+                .lock()
+                .await
+                .push(format!("{}:{}", self.name, hook));
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Extension for OrderExtensionImpl {
+        async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+            self.push("request_start").await;
+            let res = next.run(ctx).await;
+            self.push("request_end").await;
+            res
+        }
+
+        async fn validation(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            next: NextValidation<'_>,
+        ) -> Result<ValidationResult, Vec<ServerError>> {
+            self.push("validation_start").await;
+            let res = next.run(ctx).await;
+            self.push("validation_end").await;
+            res
+        }
+
+        async fn resolve(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            info: ResolveInfo<'_>,
+            next: NextResolve<'_>,
+        ) -> ServerResult<Option<ConstValue>> {
+            self.push("resolve_start").await;
+            let res = next.run(ctx, info).await;
+            self.push("resolve_end").await;
+            res
+        }
+    }
+
+    struct Auth(Calls);
+
+    impl ExtensionFactory for Auth {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(OrderExtensionImpl {
+                name: "auth",
+                calls: self.0.clone(),
+            })
+        }
+
+        fn name(&self) -> &'static str {
+            "auth"
+        }
+    }
+
+    struct RateLimit(Calls);
+
+    impl ExtensionFactory for RateLimit {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(OrderExtensionImpl {
+                name: "rate_limit",
+                calls: self.0.clone(),
+            })
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let calls: Calls = Default::default();
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Auth(calls.clone()))
+        .extension(RateLimit(calls.clone()));
+    let names = builder.extensions_list();
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[0], "auth");
+    assert!(names[1].ends_with("RateLimit"));
+
+    let schema = builder.finish();
+    schema.execute("{ value }").await.into_result().unwrap();
+    assert_eq!(
+        &*calls.lock().await,
+        &[
+            "auth:request_start",
+            "rate_limit:request_start",
+            "auth:validation_start",
+            "rate_limit:validation_start",
+            "rate_limit:validation_end",
+            "auth:validation_end",
+            "auth:resolve_start",
+            "rate_limit:resolve_start",
+            "rate_limit:resolve_end",
+            "auth:resolve_end",
+            "rate_limit:request_end",
+            "auth:request_end",
+        ]
+    );
+}
+
 #[tokio::test]
 pub async fn query_execute_with_data() {
     struct MyExtensionImpl<T>(T);
@@ -387,6 +503,80 @@ pub async fn query_execute_with_data() {
     );
 }
 
+#[tokio::test]
+pub async fn query_request_extensions() {
+    struct MyExtensionImpl;
+
+    #[async_trait::async_trait]
+    impl Extension for MyExtensionImpl {
+        async fn execute(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            operation_name: Option<&str>,
+            next: NextExecute<'_>,
+        ) -> Response {
+            let mut data = Data::default();
+            if let Some(ConstValue::String(client)) = ctx
+                .request_extensions
+                .and_then(|extensions| extensions.get("client"))
+            {
+                data.insert(client.clone());
+            }
+            next.run_with_data(ctx, operation_name, data).await
+        }
+    }
+
+    struct MyExtension;
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(MyExtensionImpl)
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn client(&self, ctx: &Context<'_>) -> Option<String> {
+            ctx.data_opt::<String>().cloned()
+        }
+
+        async fn version(&self, ctx: &Context<'_>) -> Option<i32> {
+            match ctx.request_extensions().get("version") {
+                Some(ConstValue::Number(version)) => version.as_i64().map(|n| n as i32),
+                _ => None,
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension)
+        .finish();
+
+    let request: Request = serde_json::from_str(
+        r#"{"query": "{ client version }", "extensions": {"client": "web", "version": 2}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "client": "web",
+            "version": 2,
+        })
+    );
+
+    let request: Request =
+        serde_json::from_str(r#"{"query": "{ client version }", "extensions": "abc"}"#).unwrap();
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "client": null,
+            "version": null,
+        })
+    );
+}
+
 #[tokio::test]
 pub async fn subscription_execute_with_data() {
     type Logs = Arc<Mutex<Vec<LogElement>>>;
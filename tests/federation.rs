@@ -992,3 +992,61 @@ pub async fn test_unresolvable_entity() {
         })
     );
 }
+
+#[tokio::test]
+pub async fn test_auth_directives() {
+    #[derive(SimpleObject)]
+    struct User {
+        id: ID,
+        #[graphql(authenticated)]
+        email: String,
+        #[graphql(requires_scopes("read:user read:email", "admin"))]
+        phone: String,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "secret", ty = "String", requires_scopes("read:secret")))]
+    enum HasSecret {
+        Account(Account),
+    }
+
+    struct Account;
+
+    #[Object]
+    impl Account {
+        #[graphql(authenticated, requires_scopes("read:secret"))]
+        async fn secret(&self) -> String {
+            todo!()
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            todo!()
+        }
+
+        async fn has_secret(&self) -> HasSecret {
+            todo!()
+        }
+    }
+
+    let schema_sdl = Schema::new(Query, EmptyMutation, EmptySubscription)
+        .sdl_with_options(SDLExportOptions::new().federation());
+    assert!(schema_sdl.contains("email: String! @authenticated"));
+    assert!(schema_sdl.contains(
+        r#"phone: String! @requiresScopes(scopes: [["read:user", "read:email"], ["admin"]])"#
+    ));
+    assert!(schema_sdl
+        .contains(r#"secret: String! @authenticated @requiresScopes(scopes: [["read:secret"]])"#));
+    assert!(schema_sdl.contains(r#"secret: String! @requiresScopes(scopes: [["read:secret"]])"#));
+    assert!(schema_sdl.contains(r#"url: "https://specs.apollo.dev/federation/v2.5""#));
+    assert!(schema_sdl.contains(r#""@authenticated", "@requiresScopes"]"#));
+
+    // The directives are only exported with federation.
+    let schema_sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+    assert!(!schema_sdl.contains("@authenticated"));
+    assert!(!schema_sdl.contains("@requiresScopes"));
+}
@@ -83,14 +83,11 @@ pub async fn test_field_features() {
     let query = "{ valueAbc }";
     assert_eq!(
         schema.execute(query).await.into_result().unwrap_err(),
-        vec![ServerError {
-            message: r#"Unknown field "valueAbc" on type "Query". Did you mean "value"?"#
-                .to_owned(),
-            source: None,
-            locations: vec![Pos { column: 3, line: 1 }],
-            path: Vec::new(),
-            extensions: None,
-        }]
+        vec![ServerError::new(
+            r#"Unknown field "valueAbc" on type "Query". Did you mean "value"?"#,
+            Some(Pos { column: 3, line: 1 })
+        )
+        .with_code(ErrorCode::ValidationFailed)]
     );
 
     let query = "{ obj { value } }";
@@ -112,14 +109,11 @@ pub async fn test_field_features() {
     let query = "{ obj { valueAbc } }";
     assert_eq!(
         schema.execute(query).await.into_result().unwrap_err(),
-        vec![ServerError {
-            message: r#"Unknown field "valueAbc" on type "MyObj". Did you mean "value"?"#
-                .to_owned(),
-            source: None,
-            locations: vec![Pos { column: 9, line: 1 }],
-            path: Vec::new(),
-            extensions: None,
-        }]
+        vec![ServerError::new(
+            r#"Unknown field "valueAbc" on type "MyObj". Did you mean "value"?"#,
+            Some(Pos { column: 9, line: 1 })
+        )
+        .with_code(ErrorCode::ValidationFailed)]
     );
 
     let mut stream = schema.execute_stream("subscription { values }");
@@ -149,15 +143,10 @@ pub async fn test_field_features() {
             .await
             .unwrap()
             .errors,
-        vec![ServerError {
-            message: r#"Unknown field "valuesAbc" on type "Subscription". Did you mean "values", "valuesBson"?"#.to_owned(),
-            source: None,
-            locations: vec![Pos {
-                column: 16,
-                line: 1
-            }],
-            path: Vec::new(),
-            extensions: None,
-        }]
+        vec![ServerError::new(
+            r#"Unknown field "valuesAbc" on type "Subscription". Did you mean "values", "valuesBson"?"#,
+            Some(Pos { column: 16, line: 1 })
+        )
+        .with_code(ErrorCode::ValidationFailed)]
     );
 }
@@ -1,4 +1,15 @@
-use async_graphql::*;
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex},
+};
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo},
+    parser::types::Field,
+    registry::Registry,
+    resolver_utils::ContainerType,
+    *,
+};
 use futures_util::stream::{Stream, StreamExt};
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -626,3 +637,291 @@ pub async fn test_guard_with_fn() {
         }]
     );
 }
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+struct LogGuard {
+    name: &'static str,
+    allow: bool,
+}
+
+impl LogGuard {
+    fn new(name: &'static str, allow: bool) -> Self {
+        Self { name, allow }
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl Guard for LogGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let log = ctx.data_unchecked::<Log>();
+        log.lock().unwrap().push(format!("guard {}", self.name));
+        if self.allow {
+            Ok(())
+        } else {
+            Err("Forbidden".into())
+        }
+    }
+}
+
+/// Logs the top-level fields when they are executed.
+struct LogExecute;
+
+impl ExtensionFactory for LogExecute {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(LogExecute)
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for LogExecute {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if info.path_node.parent.is_none() {
+            let log = ctx.data_unchecked::<Log>();
+            log.lock()
+                .unwrap()
+                .push(format!("execute {}", info.path_node.field_name()));
+        }
+        next.run(ctx, info).await
+    }
+}
+
+/// Executes a mutation of any mutation root with its guards checked first,
+/// returns the response and the log of the guards and of the executed
+/// top-level fields.
+async fn execute_guards_first<M: ObjectType + 'static>(
+    mutation: M,
+    query: &str,
+) -> (Response, Vec<String>) {
+    #[derive(SimpleObject)]
+    struct Query {
+        value: i32,
+    }
+
+    let schema = Schema::build(Query { value: 1 }, mutation, EmptySubscription)
+        .check_mutation_guards_first()
+        .extension(LogExecute)
+        .finish();
+    let log = Log::default();
+    let resp = schema.execute(Request::new(query).data(log.clone())).await;
+    let log = log.lock().unwrap().clone();
+    (resp, log)
+}
+
+#[tokio::test]
+pub async fn test_check_mutation_guards_first() {
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        #[graphql(guard = "LogGuard::new(\"first\", true)")]
+        async fn first(&self, ctx: &Context<'_>) -> i32 {
+            ctx.data_unchecked::<Log>()
+                .lock()
+                .unwrap()
+                .push("execute first".to_string());
+            1
+        }
+
+        #[graphql(guard = "LogGuard::new(\"second\", true)")]
+        async fn second(&self, ctx: &Context<'_>) -> i32 {
+            ctx.data_unchecked::<Log>()
+                .lock()
+                .unwrap()
+                .push("execute second".to_string());
+            2
+        }
+
+        #[graphql(guard = "LogGuard::new(\"forbidden\", false)")]
+        async fn forbidden(&self, ctx: &Context<'_>) -> i32 {
+            ctx.data_unchecked::<Log>()
+                .lock()
+                .unwrap()
+                .push("execute forbidden".to_string());
+            3
+        }
+    }
+
+    async fn execute(
+        schema: &Schema<Query, Mutation, EmptySubscription>,
+        query: &str,
+    ) -> (Response, Vec<String>) {
+        let log = Log::default();
+        let resp = schema.execute(Request::new(query).data(log.clone())).await;
+        let log = log.lock().unwrap().clone();
+        (resp, log)
+    }
+
+    #[derive(SimpleObject)]
+    struct Query {
+        value: i32,
+    }
+
+    let schema = Schema::new(Query { value: 1 }, Mutation, EmptySubscription);
+    let (resp, log) = execute(&schema, "mutation { first second }").await;
+    assert_eq!(resp.data, value!({ "first": 1, "second": 2 }));
+    assert_eq!(
+        log,
+        vec![
+            "guard first",
+            "execute first",
+            "guard second",
+            "execute second"
+        ]
+    );
+
+    let schema = Schema::build(Query { value: 1 }, Mutation, EmptySubscription)
+        .check_mutation_guards_first()
+        .finish();
+    let (resp, log) = execute(&schema, "mutation { first second }").await;
+    assert_eq!(resp.data, value!({ "first": 1, "second": 2 }));
+    assert_eq!(
+        log,
+        vec![
+            "guard first",
+            "guard second",
+            "execute first",
+            "execute second"
+        ]
+    );
+
+    // The fields before the forbidden one are executed.
+    let (resp, log) = execute(&schema, "mutation { first forbidden second }").await;
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 18
+            }],
+            path: vec![PathSegment::Field("forbidden".to_owned())],
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        log,
+        vec![
+            "guard first",
+            "guard forbidden",
+            "guard second",
+            "execute first"
+        ]
+    );
+}
+
+#[tokio::test]
+pub async fn test_check_mutation_guards_first_roots() {
+    struct Child;
+
+    #[Object]
+    impl Child {
+        async fn value(&self, ctx: &Context<'_>) -> i32 {
+            let log = ctx.data_unchecked::<Log>();
+            log.lock().unwrap().push("resolve child".to_string());
+            1
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct SimpleMutation {
+        #[graphql(guard = "LogGuard::new(\"a\", true)")]
+        a: i32,
+        #[graphql(guard = "LogGuard::new(\"b\", true)")]
+        b: Child,
+    }
+
+    // The child object is only resolved when the field is executed.
+    let (resp, log) = execute_guards_first(
+        SimpleMutation { a: 1, b: Child },
+        "mutation { a b { value } }",
+    )
+    .await;
+    assert_eq!(resp.data, value!({ "a": 1, "b": { "value": 1 } }));
+    assert_eq!(
+        log,
+        vec![
+            "guard a",
+            "guard b",
+            "execute a",
+            "execute b",
+            "resolve child"
+        ]
+    );
+
+    struct ObjectMutation;
+
+    #[Object]
+    impl ObjectMutation {
+        #[graphql(guard = "LogGuard::new(\"c\", true)")]
+        async fn c(&self) -> i32 {
+            3
+        }
+    }
+
+    #[derive(MergedObject)]
+    struct MergedMutation(ObjectMutation, SimpleMutation);
+
+    let (resp, log) = execute_guards_first(
+        MergedMutation(ObjectMutation, SimpleMutation { a: 1, b: Child }),
+        "mutation { c a }",
+    )
+    .await;
+    assert_eq!(resp.data, value!({ "c": 3, "a": 1 }));
+    assert_eq!(log, vec!["guard c", "guard a", "execute c", "execute a"]);
+
+    /// A mutation root implemented by hand, its guards can not be checked
+    /// without executing its fields.
+    struct HandWritten(SimpleMutation);
+
+    #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    impl ContainerType for HandWritten {
+        async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+            self.0.resolve_field(ctx).await
+        }
+    }
+
+    #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    impl OutputType for HandWritten {
+        fn type_name() -> Cow<'static, str> {
+            SimpleMutation::type_name()
+        }
+
+        fn create_type_info(registry: &mut Registry) -> String {
+            SimpleMutation::create_type_info(registry)
+        }
+
+        async fn resolve(
+            &self,
+            ctx: &ContextSelectionSet<'_>,
+            field: &Positioned<Field>,
+        ) -> ServerResult<Value> {
+            self.0.resolve(ctx, field).await
+        }
+    }
+
+    impl ObjectType for HandWritten {}
+
+    let (resp, log) = execute_guards_first(
+        HandWritten(SimpleMutation { a: 1, b: Child }),
+        "mutation { a }",
+    )
+    .await;
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: r#"The guards of the fields of "SimpleMutation" can not be checked before executing them, only the types defined with `#[Object]`, `#[derive(SimpleObject)]` or `#[derive(MergedObject)]` support it."#.to_string(),
+            source: None,
+            locations: vec![Pos { line: 1, column: 10 }],
+            path: vec![],
+            extensions: None,
+        }]
+    );
+    assert!(log.is_empty());
+}
@@ -925,3 +925,93 @@ pub async fn test_custom_validator_with_extensions_input() {
         }]
     );
 }
+
+#[tokio::test]
+pub async fn test_input_object_default_from_default_impl() {
+    #[derive(InputObject)]
+    #[graphql(default)]
+    struct MyInput {
+        a: i32,
+        #[graphql(default = 10)]
+        b: i32,
+        c: String,
+        d: Vec<i32>,
+    }
+
+    impl Default for MyInput {
+        fn default() -> Self {
+            Self {
+                a: 1,
+                b: 2,
+                c: "abc".to_string(),
+                d: vec![1, 2],
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput) -> String {
+            format!("{} {} {} {:?}", input.a, input.b, input.c, input.d)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains("a: Int! = 1"));
+    assert!(sdl.contains("b: Int! = 10"));
+    assert!(sdl.contains(r#"c: String! = "abc""#));
+    assert!(sdl.contains("d: [Int!]! = [1, 2]"));
+
+    assert_eq!(
+        schema
+            .execute("{ value(input: {}) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "1 10 abc [1, 2]" })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ value(input: { a: 5, c: "def" }) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "5 10 def [1, 2]" })
+    );
+}
+
+#[tokio::test]
+pub async fn test_input_object_default_from_default_impl_generic() {
+    #[derive(InputObject, Default)]
+    #[graphql(default, concrete(name = "MyInputInt", params(i32)))]
+    struct MyInput<T: InputType + Default> {
+        value: T,
+        other: Option<T>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput<i32>) -> i32 {
+            input.value + input.other.unwrap_or(100)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema.sdl().contains("value: Int! = 0"));
+    assert_eq!(
+        schema
+            .execute("{ value(input: {}) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 100 })
+    );
+}
@@ -0,0 +1,198 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_graphql::*;
+
+struct Obj {
+    computed: Arc<AtomicUsize>,
+}
+
+#[Object]
+impl Obj {
+    async fn lazy(&self) -> Lazy<i32> {
+        let computed = self.computed.clone();
+        Lazy::new(move || {
+            computed.fetch_add(1, Ordering::SeqCst);
+            10
+        })
+    }
+
+    async fn lazy_async(&self) -> Lazy<i32> {
+        let computed = self.computed.clone();
+        Lazy::new_async(move || {
+            let computed = computed.clone();
+            async move {
+                computed.fetch_add(1, Ordering::SeqCst);
+                20
+            }
+        })
+    }
+
+    async fn lazy_nested(&self) -> Lazy<Option<Lazy<i32>>> {
+        let computed = self.computed.clone();
+        Lazy::new(move || {
+            computed.fetch_add(1, Ordering::SeqCst);
+            let computed = computed.clone();
+            Some(Lazy::new(move || {
+                computed.fetch_add(1, Ordering::SeqCst);
+                30
+            }))
+        })
+    }
+
+    async fn lazy_list(&self) -> Vec<Lazy<i32>> {
+        (0..3).map(|i| Lazy::new(move || i)).collect()
+    }
+
+    async fn value(&self) -> i32 {
+        1
+    }
+
+    async fn fail(&self) -> Result<i32> {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        Err("failed".into())
+    }
+}
+
+struct Query {
+    computed: Arc<AtomicUsize>,
+}
+
+#[Object]
+impl Query {
+    async fn obj(&self) -> Option<Obj> {
+        Some(Obj {
+            computed: self.computed.clone(),
+        })
+    }
+}
+
+#[tokio::test]
+pub async fn test_lazy() {
+    let computed = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::new(
+        Query {
+            computed: computed.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ obj { lazy lazyAsync lazyList value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "obj": {
+                "lazy": 10,
+                "lazyAsync": 20,
+                "lazyList": [0, 1, 2],
+                "value": 1,
+            }
+        })
+    );
+    assert_eq!(computed.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+pub async fn test_lazy_not_computed_when_nullified() {
+    let computed = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::new(
+        Query {
+            computed: computed.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    let resp = schema.execute("{ obj { lazy lazyAsync fail } }").await;
+    assert_eq!(resp.data, value!({ "obj": null }));
+    assert_eq!(resp.errors[0].message, "failed");
+    assert_eq!(computed.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+pub async fn test_lazy_nested() {
+    let computed = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::new(
+        Query {
+            computed: computed.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ obj { lazyNested value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "obj": { "lazyNested": 30, "value": 1 } })
+    );
+    assert_eq!(computed.load(Ordering::SeqCst), 2);
+
+    let resp = schema.execute("{ obj { lazyNested lazy fail } }").await;
+    assert_eq!(resp.data, value!({ "obj": null }));
+    assert_eq!(computed.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+pub async fn test_lazy_merged_object() {
+    struct LazyQuery {
+        computed: Arc<AtomicUsize>,
+    }
+
+    #[Object]
+    impl LazyQuery {
+        async fn lazy(&self) -> Lazy<i32> {
+            let computed = self.computed.clone();
+            Lazy::new(move || {
+                computed.fetch_add(1, Ordering::SeqCst);
+                10
+            })
+        }
+    }
+
+    struct FailQuery;
+
+    #[Object]
+    impl FailQuery {
+        async fn fail(&self) -> Result<i32> {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Err("failed".into())
+        }
+    }
+
+    #[derive(MergedObject)]
+    struct MergedQuery(LazyQuery, FailQuery);
+
+    let computed = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::new(
+        MergedQuery(
+            LazyQuery {
+                computed: computed.clone(),
+            },
+            FailQuery,
+        ),
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    let resp = schema.execute("{ lazy fail }").await;
+    assert_eq!(resp.errors[0].message, "failed");
+    assert_eq!(computed.load(Ordering::SeqCst), 0);
+
+    let resp = schema.execute("{ lazy }").await.into_result().unwrap();
+    assert_eq!(resp.data, value!({ "lazy": 10 }));
+    assert_eq!(computed.load(Ordering::SeqCst), 1);
+}
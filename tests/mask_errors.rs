@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+
+use async_graphql::{extensions::MaskErrors, *};
+use futures_util::stream::{Stream, StreamExt};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn internal(&self) -> Result<i32> {
+        Err("relation \"users\" does not exist".into())
+    }
+
+    async fn safe(&self) -> Result<i32> {
+        Err(Error::new("Invalid email").extend_with(|_, e| e.set("code", "BAD_USER_INPUT")))
+    }
+}
+
+struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    async fn values(&self) -> impl Stream<Item = Result<i32>> {
+        futures_util::stream::iter(vec![Ok(1), Err("SQL syntax error".into())])
+    }
+}
+
+fn mask_errors(logged: Arc<Mutex<Vec<String>>>) -> MaskErrors {
+    MaskErrors::new(move |err| {
+        logged.lock().unwrap().push(err.message.clone());
+        "Internal server error".to_string()
+    })
+}
+
+#[tokio::test]
+pub async fn test_mask_errors() {
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .extension(mask_errors(logged.clone()))
+        .finish();
+
+    let resp = schema.execute("{ internal }").await;
+    assert_eq!(resp.errors[0].message, "Internal server error");
+    assert_eq!(
+        resp.errors[0].path,
+        vec![PathSegment::Field("internal".to_string())]
+    );
+    let resp = schema.execute("{ safe }").await;
+    assert_eq!(resp.errors[0].message, "Invalid email");
+    assert_eq!(
+        resp.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("BAD_USER_INPUT"))
+    );
+    assert_eq!(
+        *logged.lock().unwrap(),
+        vec!["relation \"users\" does not exist".to_string()]
+    );
+
+    // the errors of async-graphql have a code
+    let resp = schema.execute("{ unknown }").await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Unknown field "unknown" on type "Query"."#
+    );
+
+    let mut stream = schema.execute_stream("subscription { values }");
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "values": 1 })
+    );
+    assert_eq!(
+        stream.next().await.unwrap().errors[0].message,
+        "Internal server error"
+    );
+}
+
+#[tokio::test]
+pub async fn test_mask_errors_safe() {
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .extension(mask_errors(logged.clone()).safe(|err| err.message.starts_with("relation")))
+        .finish();
+
+    let resp = schema.execute("{ internal }").await;
+    assert_eq!(resp.errors[0].message, "relation \"users\" does not exist");
+    let resp = schema.execute("{ safe }").await;
+    assert_eq!(resp.errors[0].message, "Internal server error");
+    assert_eq!(*logged.lock().unwrap(), vec!["Invalid email".to_string()]);
+}
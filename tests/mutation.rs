@@ -251,3 +251,76 @@ pub async fn test_serial_merged_object() {
         .await;
     assert_eq!(&*list.lock().await, &[1, 2, 3, 4]);
 }
+
+#[tokio::test]
+pub async fn test_serial_field() {
+    type List = Arc<Mutex<Vec<i32>>>;
+
+    struct MyObj(i32);
+
+    #[Object]
+    impl MyObj {
+        async fn append1(&self, ctx: &Context<'_>) -> bool {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            ctx.data_unchecked::<List>().lock().await.push(self.0 + 1);
+            true
+        }
+
+        async fn append2(&self, ctx: &Context<'_>) -> bool {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            ctx.data_unchecked::<List>().lock().await.push(self.0 + 2);
+            true
+        }
+
+        #[graphql(serial)]
+        async fn nested(&self) -> MyObj {
+            MyObj(self.0 + 10)
+        }
+
+        async fn items(&self) -> Vec<MyObj> {
+            vec![MyObj(self.0 + 20), MyObj(self.0 + 30)]
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(serial)]
+        async fn serial(&self) -> MyObj {
+            MyObj(0)
+        }
+
+        async fn concurrent(&self) -> MyObj {
+            MyObj(0)
+        }
+    }
+
+    let list = List::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(list.clone())
+        .finish();
+
+    schema.execute("{ serial { append1 append2 } }").await;
+    assert_eq!(std::mem::take(&mut *list.lock().await), vec![1, 2]);
+
+    schema.execute("{ concurrent { append1 append2 } }").await;
+    assert_eq!(std::mem::take(&mut *list.lock().await), vec![2, 1]);
+
+    // The children of a serial field stay serial, and serial fields nest.
+    schema
+        .execute("{ serial { items { append1 append2 } nested { append1 append2 } } }")
+        .await;
+    assert_eq!(
+        std::mem::take(&mut *list.lock().await),
+        vec![21, 22, 31, 32, 11, 12]
+    );
+
+    schema
+        .execute("{ concurrent { nested { append1 append2 nested { append1 append2 } } } }")
+        .await;
+    assert_eq!(
+        std::mem::take(&mut *list.lock().await),
+        vec![11, 12, 21, 22]
+    );
+}
@@ -0,0 +1,234 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct MyObj {
+    field_value: i32,
+    #[graphql(name = "explicitName")]
+    explicit_name: i32,
+}
+
+#[derive(SimpleObject)]
+#[graphql(rename_fields = "PascalCase")]
+struct RenamedObj {
+    field_value: i32,
+}
+
+#[derive(InputObject)]
+struct MyInput {
+    field_value: i32,
+    #[graphql(name = "explicitName")]
+    explicit_name: i32,
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "field_value", ty = "&i32"))]
+enum MyInterface {
+    MyObj(MyObj),
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn my_obj(&self) -> MyObj {
+        MyObj {
+            field_value: 1,
+            explicit_name: 2,
+        }
+    }
+
+    async fn renamed_obj(&self) -> RenamedObj {
+        RenamedObj { field_value: 3 }
+    }
+
+    async fn my_interface(&self) -> MyInterface {
+        MyObj {
+            field_value: 4,
+            explicit_name: 5,
+        }
+        .into()
+    }
+
+    #[graphql(complexity = "page_size as usize")]
+    async fn add_values(
+        &self,
+        page_size: i32,
+        #[graphql(name = "otherValue")] other_value: i32,
+    ) -> i32 {
+        page_size + other_value
+    }
+
+    async fn sum_input(
+        &self,
+        #[graphql(default_with = "MyInput { field_value: 1, explicit_name: 2 }")] input: MyInput,
+    ) -> i32 {
+        input.field_value + input.explicit_name
+    }
+}
+
+fn schema(naming_convention: NamingConvention) -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build_with_naming_convention(Query, EmptyMutation, EmptySubscription, naming_convention)
+        .limit_complexity(25)
+        .finish()
+}
+
+#[tokio::test]
+pub async fn test_snake_case_introspection() {
+    let schema = schema(NamingConvention::SnakeCase);
+    let res = schema
+        .execute(
+            r#"{
+                myObj: __type(name: "MyObj") { fields { name } }
+                renamedObj: __type(name: "RenamedObj") { fields { name } }
+                query: __type(name: "Query") { fields { name args { name defaultValue } } }
+                myInput: __type(name: "MyInput") { inputFields { name } }
+                myInterface: __type(name: "MyInterface") { possibleTypes { name } }
+            }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        res,
+        value!({
+            "myObj": { "fields": [{ "name": "field_value" }, { "name": "explicitName" }] },
+            "renamedObj": { "fields": [{ "name": "FieldValue" }] },
+            "query": {
+                "fields": [
+                    { "name": "my_obj", "args": [] },
+                    { "name": "renamed_obj", "args": [] },
+                    { "name": "my_interface", "args": [] },
+                    {
+                        "name": "add_values",
+                        "args": [
+                            { "name": "page_size", "defaultValue": null },
+                            { "name": "otherValue", "defaultValue": null },
+                        ],
+                    },
+                    {
+                        "name": "sum_input",
+                        "args": [{
+                            "name": "input",
+                            "defaultValue": "{fieldValue: 1, explicitName: 2}",
+                        }],
+                    },
+                ]
+            },
+            // the fields of input objects keep their names
+            "myInput": { "inputFields": [{ "name": "fieldValue" }, { "name": "explicitName" }] },
+            "myInterface": { "possibleTypes": [{ "name": "MyObj" }] },
+        })
+    );
+    assert!(schema
+        .sdl()
+        .contains("add_values(page_size: Int!, otherValue: Int!): Int!"));
+}
+
+#[tokio::test]
+pub async fn test_snake_case_execution() {
+    let schema = schema(NamingConvention::SnakeCase);
+    let res = schema
+        .execute(
+            r#"{
+                my_obj { field_value explicitName }
+                renamed_obj { FieldValue }
+                my_interface { field_value ... on MyObj { v: field_value } ... F }
+                add_values(page_size: 10, otherValue: 2)
+                a: sum_input
+                b: sum_input(input: { fieldValue: 3, explicitName: 4 })
+            }
+            fragment F on MyObj { explicitName }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        res,
+        value!({
+            "my_obj": { "field_value": 1, "explicitName": 2 },
+            "renamed_obj": { "FieldValue": 3 },
+            "my_interface": { "field_value": 4, "v": 4, "explicitName": 5 },
+            "add_values": 12,
+            "a": 3,
+            "b": 7,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ add_values(page_size: 30, otherValue: 1) }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Query is too complex."
+    );
+
+    // the generated names are not part of the schema
+    assert_eq!(
+        schema
+            .execute("{ myObj { fieldValue } }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Unknown field "myObj" on type "Query". Did you mean "my_obj"?"#
+    );
+}
+
+#[tokio::test]
+pub async fn test_default_naming_convention() {
+    let schema = schema(NamingConvention::CamelCase);
+    let res = schema
+        .execute("{ myObj { fieldValue } addValues(pageSize: 1, otherValue: 2) }")
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        res,
+        value!({ "myObj": { "fieldValue": 1 }, "addValues": 3 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_naming_convention_in_spawned_task() {
+    let schema = schema(NamingConvention::SnakeCase);
+    let res = tokio::spawn(async move {
+        schema
+            .execute("{ my_obj { field_value } add_values(page_size: 1, otherValue: 2) }")
+            .await
+    })
+    .await
+    .unwrap()
+    .into_result()
+    .unwrap()
+    .data;
+    assert_eq!(
+        res,
+        value!({ "my_obj": { "field_value": 1 }, "add_values": 3 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_naming_convention_keeps_builder_options() {
+    let schema = Schema::build_with_naming_convention(
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+        NamingConvention::SnakeCase,
+    )
+    .disable_introspection()
+    .finish();
+    assert_eq!(
+        schema
+            .execute("{ __schema { queryType { name } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__schema": null })
+    );
+}
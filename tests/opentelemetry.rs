@@ -0,0 +1,73 @@
+#[cfg(feature = "opentelemetry")]
+mod tests {
+    use async_graphql::{extensions::OpenTelemetry, *};
+    use opentelemetry::{
+        trace::{noop::NoopTracer, TraceContextExt},
+        Context as OpenTelemetryContext,
+    };
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn trace_id(&self) -> String {
+            OpenTelemetryContext::current()
+                .span()
+                .span_context()
+                .trace_id()
+                .to_string()
+        }
+    }
+
+    async fn trace_id(traceparent: Option<&str>) -> Value {
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(OpenTelemetry::new(NoopTracer::new()))
+            .finish();
+        let mut request = Request::new("{ traceId }");
+        if let Some(traceparent) = traceparent {
+            let mut headers = ::http::HeaderMap::new();
+            headers.insert("traceparent", traceparent.parse().unwrap());
+            request = request.data(headers);
+        }
+        schema.execute(request).await.into_result().unwrap().data
+    }
+
+    #[tokio::test]
+    pub async fn test_traceparent() {
+        assert_eq!(
+            trace_id(Some(
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+            ))
+            .await,
+            value!({ "traceId": "4bf92f3577b34da6a3ce929d0e0e4736" })
+        );
+
+        // future versions may have more fields
+        assert_eq!(
+            trace_id(Some(
+                "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00-abc"
+            ))
+            .await,
+            value!({ "traceId": "4bf92f3577b34da6a3ce929d0e0e4736" })
+        );
+    }
+
+    #[tokio::test]
+    pub async fn test_malformed_traceparent() {
+        let fresh = value!({ "traceId": "00000000000000000000000000000000" });
+        assert_eq!(trace_id(None).await, fresh);
+        for traceparent in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-00",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4bf92f3577b34da6-00f067aa0ba902b7-01",
+            "00-+bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(trace_id(Some(traceparent)).await, fresh, "{traceparent}");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct MyObj {
+    a: i32,
+    b: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    async fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    async fn obj(&self) -> MyObj {
+        MyObj {
+            a: 1,
+            b: "abc".to_string(),
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_override_field() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .override_field("Query.value", |_| 100)
+        .override_field("Query.add", |ctx| {
+            let (_, a) = ctx.param_value::<i32>("a", None).unwrap();
+            let (_, b) = ctx.param_value::<i32>("b", None).unwrap();
+            a * b
+        })
+        .override_field("MyObj.b", |_| "mocked")
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value add(a: 3, b: 4) obj { a b } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "value": 100,
+            "add": 12,
+            "obj": { "a": 1, "b": "mocked" },
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The field `Query.value` is of type `Int!`, but the resolver returns `String!`"
+)]
+pub fn test_override_field_type_mismatch() {
+    let _ = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .override_field("Query.value", |_| "abc")
+        .finish();
+}
+
+#[test]
+#[should_panic(expected = "The field `Query.abc` is not defined")]
+pub fn test_override_field_not_defined() {
+    let _ = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .override_field("Query.abc", |_| 10)
+        .finish();
+}
+
+#[tokio::test]
+pub async fn test_override_field_through_interface_and_union() {
+    #[derive(Interface)]
+    #[graphql(field(name = "b", ty = "&String"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    #[derive(Union)]
+    enum Item {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn node(&self) -> Node {
+            MyObj {
+                a: 1,
+                b: "abc".to_string(),
+            }
+            .into()
+        }
+
+        async fn item(&self) -> Item {
+            Item::MyObj(MyObj {
+                a: 1,
+                b: "abc".to_string(),
+            })
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .override_field("MyObj.b", |_| "mocked")
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ node { b ... on MyObj { b2: b } } item { ... on MyObj { b } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "node": { "b": "mocked", "b2": "mocked" },
+            "item": { "b": "mocked" },
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_override_resolver() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(5i32)
+        .override_resolver("Query.value", |ctx| {
+            let n = *ctx.data_unchecked::<i32>();
+            async move {
+                tokio::task::yield_now().await;
+                Ok(n * 10)
+            }
+        })
+        .override_resolver("Query.add", |_| async move {
+            Err::<i32, _>(Error::new("mocked error"))
+        })
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 50 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ add(a: 1, b: 2) }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "mocked error"
+    );
+}
@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_range_type() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len(&self, range: Range<i32>) -> i32 {
+            range.end - range.start
+        }
+
+        async fn shift(&self, range: Range<i32>, n: i32) -> Range<i32> {
+            range.start + n..range.end + n
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ len(range: { start: 3, end: 10 }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 7 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ shift(range: { start: 3, end: 10 }, n: 2) { start end } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "shift": { "start": 5, "end": 12 } })
+    );
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("input IntRangeInput {\n\tstart: Int!\n\tend: Int!\n}"));
+    assert!(sdl.contains("type IntRange {\n\tstart: Int!\n\tend: Int!\n}"));
+
+    assert_eq!(
+        schema
+            .execute("{ len(range: { start: 3 }) }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Invalid value for argument "range", field "end" of type "Int!" is required but not provided"#
+    );
+}
+
+#[tokio::test]
+pub async fn test_range_validator() {
+    struct OrderedRange;
+
+    impl CustomValidator<Range<i32>> for OrderedRange {
+        fn check(&self, value: &Range<i32>) -> Result<(), InputValueError<Range<i32>>> {
+            if value.start <= value.end {
+                Ok(())
+            } else {
+                Err(InputValueError::custom(format!(
+                    "inverted range: {} > {}",
+                    value.start, value.end
+                )))
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len(
+            &self,
+            #[graphql(validator(custom = "OrderedRange"))] range: Range<i32>,
+        ) -> i32 {
+            range.end - range.start
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ len(range: { start: 1, end: 1 }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 0 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ len(range: { start: 10, end: 3 }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "IntRangeInput": inverted range: 10 > 3"#.to_owned(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 14
+            }],
+            path: vec![PathSegment::Field("len".to_owned())],
+            extensions: None,
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_range_types_sharing_a_name() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len32(&self, range: Range<i32>) -> Range<i32> {
+            0..range.end - range.start
+        }
+
+        async fn len64(&self, range: Range<i64>) -> Range<i64> {
+            0..range.end - range.start
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    len32(range: { start: 3, end: 10 }) { start end }
+                    len64(range: { start: 1, end: 5000000000 }) { start end }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "len32": { "start": 0, "end": 7 },
+            "len64": { "start": 0, "end": 4999999999i64 },
+        })
+    );
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("len32(range: IntRangeInput!): IntRange!"));
+    assert!(sdl.contains("len64(range: IntRangeInput!): IntRange!"));
+}
@@ -32,6 +32,64 @@ pub async fn test_enum() {
     );
 }
 
+#[tokio::test]
+pub async fn test_enum_verbatim() {
+    #[derive(Enum, Eq, PartialEq, Copy, Clone, Debug)]
+    #[graphql(rename_items = "verbatim")]
+    enum MyEnum {
+        CreateObject,
+        #[graphql(name = "DELETE")]
+        DeleteObject,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyEnum) -> MyEnum {
+            input
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyEnum") { enumValues { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": { "enumValues": [{ "name": "CreateObject" }, { "name": "DELETE" }] }
+        })
+    );
+    assert_eq!(
+        schema
+            .execute("{ a: value(input: CreateObject) b: value(input: DELETE) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "a": "CreateObject", "b": "DELETE" })
+    );
+    assert!(schema
+        .execute("{ value(input: CREATE_OBJECT) }")
+        .await
+        .is_err());
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query($v: MyEnum!) { value(input: $v) }")
+                    .variables(Variables::from_value(value!({ "v": "CreateObject" })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "CreateObject" })
+    );
+}
+
 #[tokio::test]
 pub async fn test_simple_object() {
     #[derive(SimpleObject)]
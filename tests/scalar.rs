@@ -70,3 +70,180 @@ pub async fn test_float_inf() {
         value!({ "value": null })
     );
 }
+
+#[tokio::test]
+pub async fn test_scalar_from_try_from() {
+    /// An email address
+    #[derive(ScalarFromTryFrom, Clone)]
+    #[graphql(try_from = "String")]
+    struct Email(String);
+
+    impl TryFrom<String> for Email {
+        type Error = &'static str;
+
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            if value.contains('@') {
+                Ok(Email(value))
+            } else {
+                Err("missing @")
+            }
+        }
+    }
+
+    impl From<Email> for String {
+        fn from(email: Email) -> Self {
+            email.0
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(&self, email: Email) -> Email {
+            email
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ echo(email: "a@b.c") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "echo": "a@b.c" })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Email") { kind description } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": { "kind": "SCALAR", "description": "An email address" } })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ echo(email: "abc") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Email": missing @"#.to_owned(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 15,
+            }],
+            path: vec![PathSegment::Field("echo".to_owned())],
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ echo(email: 10) }"#)
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Invalid value for argument "email", expected type "Email""#
+    );
+}
+
+#[tokio::test]
+pub async fn test_string_enum() {
+    #[derive(StringEnum, Debug, PartialEq)]
+    #[graphql(rename_items = "lowercase")]
+    enum Color {
+        Red,
+        #[graphql(name = "GREEN")]
+        Green,
+        Other(String),
+    }
+
+    #[derive(StringEnum, Debug)]
+    enum Size {
+        Small,
+        Large,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn color(&self, color: Color) -> Color {
+            color
+        }
+
+        async fn is_other(&self, color: Color) -> bool {
+            matches!(color, Color::Other(_))
+        }
+
+        async fn size(&self, size: Size) -> Size {
+            size
+        }
+    }
+
+    assert_eq!(
+        <Color as ScalarType>::parse(Value::String("red".to_string())).unwrap(),
+        Color::Red
+    );
+    assert_eq!(
+        <Color as ScalarType>::parse(Value::String("blue".to_string())).unwrap(),
+        Color::Other("blue".to_string())
+    );
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    a: color(color: "red")
+                    b: color(color: "GREEN")
+                    c: color(color: "blue")
+                    d: isOther(color: "red")
+                    e: isOther(color: "blue")
+                    f: size(size: "LARGE")
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "red",
+            "b": "GREEN",
+            "c": "blue",
+            "d": false,
+            "e": true,
+            "f": "LARGE",
+        })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Color") { kind } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": { "kind": "SCALAR" } })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ size(size: "MEDIUM") }"#)
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Invalid value for argument "size", expected type "Size""#
+    );
+    assert_eq!(
+        <Size as ScalarType>::parse(Value::String("MEDIUM".to_string()))
+            .unwrap_err()
+            .into_server_error(Default::default())
+            .message,
+        r#"Failed to parse "Size": unknown value "MEDIUM""#
+    );
+}
@@ -0,0 +1,100 @@
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_deprecated_required_input_field() {
+    /// Input.
+    #[derive(InputObject)]
+    struct MyInput {
+        /// Required.
+        #[graphql(deprecation = "use b")]
+        a: i32,
+        /// Optional.
+        #[graphql(deprecation = "use c")]
+        b: Option<i32>,
+        /// With a default value.
+        #[graphql(deprecation, default = 1)]
+        c: i32,
+    }
+
+    struct Query;
+
+    /// The root query.
+    #[Object]
+    impl Query {
+        /// Returns the value.
+        async fn value(&self, input: MyInput) -> i32 {
+            input.a
+        }
+    }
+
+    let (schema, warnings) =
+        Schema::build(Query, EmptyMutation, EmptySubscription).finish_with_warnings();
+    assert_eq!(
+        warnings,
+        vec![SchemaWarning {
+            kind: SchemaWarningKind::Deprecation,
+            path: "MyInput.a".to_string(),
+            message: "The input field is deprecated but required.".to_string(),
+        }]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "MyInput.a: The input field is deprecated but required."
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(input: { a: 10 }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 10 })
+    );
+}
+
+#[test]
+pub fn test_warning_kinds() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    #[graphql(rename_items = "camelCase")]
+    enum MyEnum {
+        ValueA,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(name = "my_obj", rename_fields = "snake_case")]
+    struct MyObj {
+        my_value: MyEnum,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self, #[graphql(name = "obj_id")] _id: i32) -> MyObj {
+            MyObj {
+                my_value: MyEnum::ValueA,
+            }
+        }
+    }
+
+    let (_, warnings) =
+        Schema::build(Query, EmptyMutation, EmptySubscription).finish_with_warnings();
+    let warnings = warnings
+        .iter()
+        .map(|warning| (warning.kind, warning.path.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            (SchemaWarningKind::MissingDescription, "MyEnum"),
+            (SchemaWarningKind::Naming, "MyEnum.valueA"),
+            (SchemaWarningKind::MissingDescription, "Query"),
+            (SchemaWarningKind::MissingDescription, "Query.obj"),
+            (SchemaWarningKind::Naming, "Query.obj(obj_id:)"),
+            (SchemaWarningKind::Naming, "my_obj"),
+            (SchemaWarningKind::MissingDescription, "my_obj"),
+            (SchemaWarningKind::Naming, "my_obj.my_value"),
+            (SchemaWarningKind::MissingDescription, "my_obj.my_value"),
+        ]
+    );
+}
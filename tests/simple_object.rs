@@ -104,3 +104,112 @@ async fn recursive_fragment_definition_nested() {
     let query = "fragment f on Query { a { ...f a { ...f } } } { __typename }";
     assert!(schema.execute(query).await.into_result().is_err());
 }
+
+#[tokio::test]
+async fn test_external_resolver() {
+    #[derive(SimpleObject)]
+    struct Author {
+        name: String,
+    }
+
+    // The fields only declare the fields of the object, they are never read.
+    #[allow(dead_code)]
+    #[derive(SimpleObject)]
+    #[graphql(external_resolver)]
+    struct Plugin {
+        #[graphql(skip)]
+        id: i32,
+        name: Option<String>,
+        author: Option<Author>,
+        version: Option<i32>,
+        downloads: i32,
+    }
+
+    impl Plugin {
+        fn new(id: i32) -> Self {
+            Self {
+                id,
+                name: None,
+                author: None,
+                version: None,
+                downloads: 0,
+            }
+        }
+    }
+
+    struct PluginResolver;
+
+    #[async_trait::async_trait]
+    impl FieldResolver<Plugin> for PluginResolver {
+        async fn resolve_field(
+            &self,
+            parent: &Plugin,
+            ctx: &Context<'_>,
+        ) -> ServerResult<Option<Value>> {
+            match ctx.item.node.name.node.as_str() {
+                "name" => Ok(Some(Value::from(format!("plugin{}", parent.id)))),
+                "author" => {
+                    let author = Author {
+                        name: "sunli".to_string(),
+                    };
+                    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                    OutputType::resolve(&author, &ctx_obj, ctx.item)
+                        .await
+                        .map(Some)
+                }
+                "downloads" => Ok(Some(Value::from("many"))),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn plugins(&self) -> Vec<Plugin> {
+            vec![Plugin::new(1), Plugin::new(2)]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .field_resolver(PluginResolver)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ plugins { name author { name } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "plugins": [
+                { "name": "plugin1", "author": { "name": "sunli" } },
+                { "name": "plugin2", "author": { "name": "sunli" } },
+            ]
+        })
+    );
+
+    // The resolver doesn't know `version`.
+    let res = schema.execute("{ plugins { name version } }").await;
+    assert_eq!(
+        res.errors
+            .iter()
+            .map(|err| err.message.as_str())
+            .collect::<Vec<_>>(),
+        vec![r#"Unknown field "version" on type "Plugin"."#]
+    );
+
+    // The value doesn't match the type of the field.
+    let res = schema.execute("{ plugins { downloads } }").await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for field "downloads" on type "Plugin", expected type "Int!"."#
+    );
+
+    // No resolver is registered.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema.execute("{ plugins { name } }").await;
+    assert_eq!(res.errors.len(), 1);
+    assert!(res.errors[0].message.contains("does not exist"));
+}
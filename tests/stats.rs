@@ -0,0 +1,109 @@
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use async_graphql::{
+    dataloader::{DataLoader, HashMapCache, Loader},
+    extensions::Stats,
+    *,
+};
+
+#[derive(SimpleObject, Clone)]
+struct MyObj {
+    a: i32,
+    b: i32,
+}
+
+struct MyLoader;
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl Loader<i32> for MyLoader {
+    type Value = MyObj;
+    type Error = Infallible;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+        Ok(keys.iter().map(|n| (*n, MyObj { a: *n, b: 0 })).collect())
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    async fn obj(&self) -> MyObj {
+        MyObj { a: 1, b: 2 }
+    }
+
+    async fn list(&self) -> Vec<MyObj> {
+        vec![MyObj { a: 1, b: 2 }, MyObj { a: 3, b: 4 }]
+    }
+
+    async fn load(&self, ctx: &Context<'_>, n: i32) -> Option<MyObj> {
+        ctx.data_unchecked::<DataLoader<MyLoader, HashMapCache>>()
+            .load_one(n)
+            .await
+            .unwrap()
+    }
+}
+
+#[tokio::test]
+pub async fn test_stats_fields_resolved() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Stats::new())
+        .finish();
+
+    // `value`, `obj`, `obj.a`, `obj.b`, `list` and the two `list.a`.
+    let resp = schema.execute("{ value obj { a b } list { a } }").await;
+    assert!(resp.is_ok());
+    assert_eq!(
+        resp.extensions["stats"],
+        value!({ "fieldsResolved": 7, "dataloaderBatches": 0, "cacheHits": 0 })
+    );
+
+    let resp = schema.execute("{ value }").await;
+    assert_eq!(
+        resp.extensions["stats"],
+        value!({ "fieldsResolved": 1, "dataloaderBatches": 0, "cacheHits": 0 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_stats_dataloader() {
+    let stats = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(DataLoader::with_cache(
+            MyLoader,
+            tokio::spawn,
+            HashMapCache::default(),
+        ))
+        .extension(Stats::with_callback({
+            let stats = stats.clone();
+            move |s| {
+                stats.lock().unwrap().push((
+                    s.fields_resolved(),
+                    s.dataloader_batches(),
+                    s.cache_hits(),
+                ))
+            }
+        }))
+        .finish();
+
+    let resp = schema
+        .execute("{ x: load(n: 1) { a } y: load(n: 2) { a } z: load(n: 1) { a } }")
+        .await;
+    assert!(resp.is_ok());
+    assert!(resp.extensions.is_empty());
+
+    let resp = schema
+        .execute("{ x: load(n: 1) { a } y: load(n: 3) { a } }")
+        .await;
+    assert!(resp.is_ok());
+
+    assert_eq!(*stats.lock().unwrap(), vec![(6, 1, 0), (4, 1, 1)]);
+}
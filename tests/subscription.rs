@@ -420,3 +420,158 @@ pub async fn test_subscription_fieldresult() {
 
     assert!(stream.next().await.is_none());
 }
+
+#[tokio::test]
+pub async fn test_simple_broker() {
+    #[derive(Clone)]
+    struct Event(i32);
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+            ctx.data_unchecked::<SimpleBroker<Event>>()
+                .subscribe()
+                .map(|event| event.0)
+        }
+    }
+
+    let broker = SimpleBroker::<Event>::new();
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(broker.clone())
+        .finish();
+
+    // Not received by the subscription, which starts later.
+    broker.publish(Event(1));
+
+    let mut stream = schema.execute_stream("subscription { events }");
+    assert!(futures_util::poll!(stream.next()).is_pending());
+    assert_eq!(broker.subscriber_count(), 1);
+
+    broker.publish(Event(2));
+    broker.publish(Event(3));
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "events": 2 })
+    );
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "events": 3 })
+    );
+
+    drop(stream);
+    assert_eq!(broker.subscriber_count(), 0);
+}
+
+#[tokio::test]
+pub async fn test_simple_broker_replay() {
+    let broker = SimpleBroker::with_replay(2);
+    broker.publish(1);
+    broker.publish(2);
+    broker.publish(3);
+
+    let mut stream = broker.subscribe();
+    broker.publish(4);
+    assert_eq!(
+        (&mut stream).take(3).collect::<Vec<_>>().await,
+        vec![2, 3, 4]
+    );
+    assert!(futures_util::poll!(stream.next()).is_pending());
+}
+
+#[tokio::test]
+pub async fn test_merge_streams() {
+    #[derive(Union)]
+    enum Event {
+        Created(Created),
+        Deleted(Deleted),
+    }
+
+    #[derive(SimpleObject, Clone)]
+    struct Created {
+        id: i32,
+    }
+
+    #[derive(SimpleObject, Clone)]
+    struct Deleted {
+        id: i32,
+    }
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = Result<Event>> {
+            merge_streams()
+                .source(
+                    ctx.data_unchecked::<SimpleBroker<Created>>().subscribe(),
+                    Event::Created,
+                )
+                .source(
+                    ctx.data_unchecked::<SimpleBroker<Deleted>>().subscribe(),
+                    Event::Deleted,
+                )
+        }
+    }
+
+    let created = SimpleBroker::<Created>::new();
+    let deleted = SimpleBroker::<Deleted>::new();
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(created.clone())
+        .data(deleted.clone())
+        .finish();
+    let mut stream = schema.execute_stream(
+        r#"subscription {
+            events {
+                __typename
+                ... on Created { id }
+                ... on Deleted { id }
+            }
+        }"#,
+    );
+
+    assert!(futures_util::poll!(stream.next()).is_pending());
+    created.publish(Created { id: 1 });
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "events": { "__typename": "Created", "id": 1 } })
+    );
+    deleted.publish(Deleted { id: 1 });
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "events": { "__typename": "Deleted", "id": 1 } })
+    );
+    created.publish(Created { id: 2 });
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "events": { "__typename": "Created", "id": 2 } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_merge_streams_error_policy() {
+    let source = || futures_util::stream::iter(vec![Ok(1), Err("failed"), Ok(2)]);
+
+    let items = merge_streams()
+        .try_source(source(), |n| n * 10)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(items, vec![Ok(10), Err("failed")]);
+
+    let items = merge_streams()
+        .try_source(source(), |n| n * 10)
+        .on_error(MergeErrorPolicy::Continue)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(items, vec![Ok(10), Err("failed"), Ok(20)]);
+
+    let items = merge_streams::<_, &str>()
+        .source(futures_util::stream::iter(vec![1, 2]), |n| n)
+        .source(futures_util::stream::iter(vec![3]), |n| n)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(items.iter().sum::<i32>(), 6);
+    assert_eq!(items.len(), 3);
+}
@@ -983,3 +983,160 @@ pub async fn test_on_ping() {
         }),
     );
 }
+
+#[tokio::test]
+pub async fn test_max_subscriptions_per_connection() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+            ctx.data_unchecked::<SimpleBroker<i32>>().subscribe()
+        }
+
+        async fn once(&self) -> impl Stream<Item = i32> {
+            futures_util::stream::once(async { 1 })
+        }
+    }
+
+    let broker = SimpleBroker::<i32>::new();
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(broker.clone())
+        .max_subscriptions_per_connection(2)
+        .finish();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx, WebSocketProtocols::GraphQLWS);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "connection_ack",
+        }),
+    );
+
+    for id in ["1", "2", "3"] {
+        tx.send(
+            serde_json::to_string(&value!({
+                "type": "start",
+                "id": id,
+                "payload": {
+                    "query": "subscription { events }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    }
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "error",
+            "id": "3",
+            "payload": [{
+                "message": "Too many subscriptions, the limit is 2 per connection.",
+                "extensions": { "code": "TOO_MANY_SUBSCRIPTIONS" },
+            }],
+        }),
+    );
+    assert!(futures_util::poll!(stream.next()).is_pending());
+    assert_eq!(broker.subscriber_count(), 2);
+
+    // A stopped subscription frees its slot.
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "stop",
+            "id": "1",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "complete",
+            "id": "1",
+        }),
+    );
+
+    // So does a completed subscription.
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "4",
+            "payload": {
+                "query": "subscription { once }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "next",
+            "id": "4",
+            "payload": { "data": { "once": 1 } },
+        }),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "complete",
+            "id": "4",
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "5",
+            "payload": {
+                "query": "subscription { events }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert!(futures_util::poll!(stream.next()).is_pending());
+    assert_eq!(broker.subscriber_count(), 2);
+    broker.publish(7);
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let msg =
+            serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+                .unwrap();
+        assert_eq!(
+            msg["payload"],
+            serde_json::json!({ "data": { "events": 7 } })
+        );
+        ids.push(msg["id"].as_str().unwrap().to_string());
+    }
+    ids.sort();
+    assert_eq!(ids, vec!["2", "5"]);
+}
@@ -1,4 +1,6 @@
-use async_graphql::{EmptyMutation, EmptySubscription, SDLExportOptions, Schema, Subscription};
+use async_graphql::{
+    value, EmptyMutation, EmptySubscription, SDLExportOptions, Schema, Subscription,
+};
 use async_graphql_derive::{
     ComplexObject, Enum, InputObject, Interface, Object, OneofObject, SimpleObject, TypeDirective,
 };
@@ -252,3 +254,153 @@ fn test_type_directive_2() {
     let expected = include_str!("schemas/test_fed2_compose_2.schema.graphql");
     assert_eq!(expected, sdl);
 }
+
+#[tokio::test]
+pub async fn test_applied_directives_introspection() {
+    #[TypeDirective(location = "FieldDefinition", introspectable)]
+    fn cached(max_age: i32, scope: Option<String>) {}
+
+    #[TypeDirective(location = "FieldDefinition")]
+    fn internal(reason: String) {}
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(
+            directive = cached::apply(60, Some("PUBLIC".to_string())),
+            directive = internal::apply("hidden".to_string())
+        )]
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn other(&self) -> i32 {
+            20
+        }
+    }
+
+    let query = r#"{
+        __type(name: "Query") {
+            fields {
+                name
+                appliedDirectives { name args { name value } }
+            }
+        }
+    }"#;
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_applied_directives_introspection()
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "__type": {
+                "fields": [
+                    {
+                        "name": "value",
+                        "appliedDirectives": [{
+                            "name": "cached",
+                            "args": [
+                                { "name": "maxAge", "value": "60" },
+                                { "name": "scope", "value": "\"PUBLIC\"" },
+                            ],
+                        }],
+                    },
+                    { "name": "other", "appliedDirectives": [] },
+                ]
+            }
+        })
+    );
+
+    // The field does not exist unless enabled.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema.execute(query).await.is_err());
+}
+
+#[tokio::test]
+pub async fn test_applied_directives_introspection_locations() {
+    #[TypeDirective(
+        location = "Object",
+        location = "ArgumentDefinition",
+        location = "Enum",
+        location = "EnumValue",
+        introspectable
+    )]
+    fn tagged(name: String) {}
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    #[graphql(directive = tagged::apply("enum".to_string()))]
+    enum Color {
+        #[graphql(directive = tagged::apply("value".to_string()))]
+        Red,
+        Green,
+    }
+
+    struct Query;
+
+    #[Object(directive = tagged::apply("object".to_string()))]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(directive = tagged::apply("argument".to_string()))] color: Color,
+        ) -> Color {
+            color
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_applied_directives_introspection()
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    query: __type(name: "Query") {
+                        appliedDirectives { name args { value } }
+                        fields {
+                            args { name appliedDirectives { args { value } } }
+                            type { appliedDirectives { name } }
+                        }
+                    }
+                    color: __type(name: "Color") {
+                        appliedDirectives { args { value } }
+                        enumValues { name appliedDirectives { args { value } } }
+                    }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "query": {
+                "appliedDirectives": [{ "name": "tagged", "args": [{ "value": "\"object\"" }] }],
+                "fields": [{
+                    "args": [{
+                        "name": "color",
+                        "appliedDirectives": [{ "args": [{ "value": "\"argument\"" }] }],
+                    }],
+                    "type": { "appliedDirectives": null },
+                }],
+            },
+            "color": {
+                "appliedDirectives": [{ "args": [{ "value": "\"enum\"" }] }],
+                "enumValues": [
+                    { "name": "RED", "appliedDirectives": [{ "args": [{ "value": "\"value\"" }] }] },
+                    { "name": "GREEN", "appliedDirectives": [] },
+                ],
+            },
+        })
+    );
+
+    // The fields do not exist unless enabled.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    for query in [
+        "{ __type(name: \"Query\") { appliedDirectives { name } } }",
+        "{ __type(name: \"Color\") { enumValues { appliedDirectives { name } } } }",
+        "{ __type(name: \"Query\") { fields { args { appliedDirectives { name } } } } }",
+    ] {
+        assert!(schema.execute(query).await.is_err());
+    }
+}
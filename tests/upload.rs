@@ -0,0 +1,96 @@
+#![cfg(feature = "tempfile")]
+
+use std::{
+    io::Read,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use async_graphql::{http::MultipartOptions, *};
+
+type SpooledPath = Arc<Mutex<Option<PathBuf>>>;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn upload(&self, ctx: &Context<'_>, file: Upload) -> String {
+        if let Some(path) = file.path(ctx) {
+            assert!(path.exists());
+            *ctx.data_unchecked::<SpooledPath>().lock().unwrap() = Some(path.to_path_buf());
+        }
+        let mut content = String::new();
+        file.value(ctx)
+            .unwrap()
+            .into_read()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+}
+
+fn multipart_body(content: &str) -> String {
+    format!(
+        "--xxx\r\n\
+         Content-Disposition: form-data; name=\"operations\"\r\n\r\n\
+         {{ \"query\": \"mutation($file: Upload!) {{ upload(file: $file) }}\", \"variables\": {{ \"file\": null }} }}\r\n\
+         --xxx\r\n\
+         Content-Disposition: form-data; name=\"map\"\r\n\r\n\
+         {{ \"0\": [\"variables.file\"] }}\r\n\
+         --xxx\r\n\
+         Content-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\n\
+         {content}\r\n\
+         --xxx--\r\n"
+    )
+}
+
+async fn receive(content: &str) -> Request {
+    http::receive_body(
+        Some("multipart/form-data; boundary=xxx"),
+        multipart_body(content).as_bytes(),
+        MultipartOptions::default(),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+pub async fn test_upload_spool_threshold() {
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .upload_spool_threshold(16)
+        .finish();
+
+    let path = SpooledPath::default();
+    let request = receive("small").await.data(path.clone());
+    let resp = schema.execute(request).await.into_result().unwrap();
+    assert_eq!(resp.data, value!({ "upload": "small" }));
+    assert_eq!(*path.lock().unwrap(), None);
+
+    let large = "a".repeat(10000);
+    let request = receive(&large).await.data(path.clone());
+    let resp = schema.execute(request).await.into_result().unwrap();
+    assert_eq!(resp.data, value!({ "upload": large }));
+    let path = path.lock().unwrap().take().unwrap();
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+pub async fn test_upload_without_spool_threshold() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let path = SpooledPath::default();
+    let request = receive(&"a".repeat(10000)).await.data(path.clone());
+    assert_eq!(request.uploads[0].size().unwrap(), 10000);
+    schema.execute(request).await.into_result().unwrap();
+    assert_eq!(*path.lock().unwrap(), None);
+}
@@ -179,7 +179,7 @@ pub async fn test_required_variable_with_default() {
         let resp = schema.execute(query).await;
         assert_eq!(
             resp.errors.first().map(|v| v.message.as_str()),
-            Some("Invalid value for argument \"value\", expected type \"Int\"")
+            Some("Invalid value for variable \"$intVal\", expected type \"Int\"")
         );
     }
 }
@@ -409,3 +409,123 @@ pub async fn test_variables_invalid_type_with_value() {
         Some("Unknown type \"invalid\"")
     );
 }
+
+#[tokio::test]
+pub async fn test_variables_strict_coercion() {
+    #[derive(InputObject)]
+    struct MyInput {
+        values: Vec<i32>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int_val(&self, value: Option<i32>) -> Option<i32> {
+            value
+        }
+
+        async fn float_val(&self, value: f64) -> f64 {
+            value
+        }
+
+        async fn input(&self, input: MyInput) -> Vec<i32> {
+            input.values
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let execute = |query: &'static str, variables: Value| {
+        let schema = schema.clone();
+        async move {
+            schema
+                .execute(Request::new(query).variables(Variables::from_value(variables)))
+                .await
+        }
+    };
+
+    // Integral floats are accepted as `Int`.
+    assert_eq!(
+        execute(
+            "query($v: Int!) { intVal(value: $v) }",
+            value!({ "v": 1.0 })
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data,
+        value!({ "intVal": 1 })
+    );
+    assert_eq!(
+        execute(
+            "query($v: MyInput!) { input(input: $v) }",
+            value!({ "v": { "values": [1.0, 2, -3.0] } })
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data,
+        value!({ "input": [1, 2, -3] })
+    );
+    assert_eq!(
+        execute(
+            "query($v: Float!) { floatVal(value: $v) }",
+            value!({ "v": 1 })
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data,
+        value!({ "floatVal": 1.0 })
+    );
+
+    // Fractional floats and other types are rejected.
+    for (query, variables, message) in [
+        (
+            "query($v: Int!) { intVal(value: $v) }",
+            value!({ "v": 1.5 }),
+            r#"Invalid value for variable "$v", expected type "Int""#,
+        ),
+        (
+            "query($v: Int) { intVal(value: $v) }",
+            value!({ "v": "1" }),
+            r#"Invalid value for variable "$v", expected type "Int""#,
+        ),
+        (
+            "query($v: MyInput!) { input(input: $v) }",
+            value!({ "v": { "values": [1, 2.5] } }),
+            r#"Invalid value for variable "$v.values.1", expected type "Int""#,
+        ),
+        (
+            "query($v: Int!) { intVal(value: $v) }",
+            value!({ "v": null }),
+            r#"Invalid value for variable "$v", expected type "Int""#,
+        ),
+        (
+            "query($v: Boolean!) { intVal @skip(if: $v) }",
+            value!({}),
+            r#"Variable "$v" of required type "Boolean!" was not provided."#,
+        ),
+    ] {
+        let errors = execute(query, variables).await.into_result().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, message);
+        assert_eq!(errors[0].code(), Some(ErrorCode::ValidationFailed));
+    }
+
+    // Only the variables of the executed operation are checked.
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(
+                    "query A($v: Int!) { intVal(value: $v) } query B { intVal(value: 2) }"
+                )
+                .operation_name("B")
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "intVal": 2 })
+    );
+}
@@ -12,9 +12,15 @@ pub struct Extensions(pub HashMap<String, crate::ConstValue>);
 
 impl<'de> Deserialize<'de> for Extensions {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Ok(Self(
-            <Option<HashMap<_, _>>>::deserialize(deserializer)?.unwrap_or_default(),
-        ))
+        // Malformed extensions are ignored instead of failing the whole request.
+        match <Option<crate::ConstValue>>::deserialize(deserializer)? {
+            Some(crate::ConstValue::Object(map)) => Ok(Self(
+                map.into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect(),
+            )),
+            _ => Ok(Self::default()),
+        }
     }
 }
 
@@ -7,6 +7,7 @@
 mod deserializer;
 mod extensions;
 mod macros;
+mod number;
 mod serializer;
 mod value_serde;
 mod variables;
@@ -24,6 +25,7 @@ pub use extensions::Extensions;
 #[doc(hidden)]
 pub use indexmap;
 use indexmap::IndexMap;
+pub use number::NumberExt;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub use serde_json::Number;
 pub use serializer::{to_value, SerializerError};
@@ -328,6 +330,29 @@ impl ConstValue {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Returns the value as pretty-printed JSON with the keys of the objects
+    /// sorted, for example to compare responses in snapshot tests.
+    ///
+    /// The integral floats, such as `1.0`, are written as integers, so the
+    /// output doesn't depend on how a number was produced. The other numbers
+    /// and the strings, such as big numbers serialized as strings, are
+    /// written as they are.
+    ///
+    /// ```
+    /// use async_graphql_value::*;
+    ///
+    /// let value = value!({ "b": 1.0, "a": [2, 3.5] });
+    /// assert_eq!(
+    ///     value.to_pretty_sorted_string(),
+    ///     "{\n  \"a\": [\n    2,\n    3.5\n  ],\n  \"b\": 1\n}"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_pretty_sorted_string(&self) -> String {
+        serde_json::to_string_pretty(&value_serde::SortedValue(self))
+            .expect("a value can always be serialized to JSON")
+    }
 }
 
 impl Default for ConstValue {
@@ -512,7 +537,7 @@ fn write_quoted(s: &str, f: &mut Formatter<'_>) -> fmt::Result {
             '\t' => f.write_str("\\t"),
             '"' => f.write_str("\\\""),
             '\\' => f.write_str("\\\\"),
-            c if c.is_control() => write!(f, "\\u{:04}", c as u32),
+            c if c.is_control() => write!(f, "\\u{:04X}", c as u32),
             c => f.write_char(c),
         }?
     }
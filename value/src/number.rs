@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+
+use serde_json::Number;
+
+/// Compares [`Number`]s by their numeric value instead of their
+/// representation.
+///
+/// `Number` considers `1` and `1.0` to be different numbers because one is an
+/// integer and the other a float, these methods consider them equal. Integers
+/// and floats are compared exactly, without converting the integer to a float.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use async_graphql_value::{Number, NumberExt};
+///
+/// let a = Number::from(1);
+/// let b = Number::from_f64(1.0).unwrap();
+/// assert_ne!(a, b);
+/// assert!(a.eq_value(&b));
+/// assert_eq!(a.cmp_value(&Number::from_f64(1.5).unwrap()), Ordering::Less);
+/// ```
+pub trait NumberExt {
+    /// Returns `true` if both numbers have the same numeric value.
+    fn eq_value(&self, other: &Number) -> bool;
+
+    /// Compares the numeric values of two numbers.
+    fn cmp_value(&self, other: &Number) -> Ordering;
+}
+
+impl NumberExt for Number {
+    fn eq_value(&self, other: &Number) -> bool {
+        self.cmp_value(other) == Ordering::Equal
+    }
+
+    fn cmp_value(&self, other: &Number) -> Ordering {
+        match (NumberValue::new(self), NumberValue::new(other)) {
+            (NumberValue::Int(a), NumberValue::Int(b)) => a.cmp(&b),
+            (NumberValue::Int(a), NumberValue::Float(b)) => cmp_int_float(a, b),
+            (NumberValue::Float(a), NumberValue::Int(b)) => cmp_int_float(b, a).reverse(),
+            // A `Number` is never NaN.
+            (NumberValue::Float(a), NumberValue::Float(b)) => {
+                a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+            }
+        }
+    }
+}
+
+enum NumberValue {
+    Int(i128),
+    Float(f64),
+}
+
+impl NumberValue {
+    fn new(n: &Number) -> Self {
+        if let Some(n) = n.as_u64() {
+            NumberValue::Int(n as i128)
+        } else if let Some(n) = n.as_i64() {
+            NumberValue::Int(n as i128)
+        } else {
+            NumberValue::Float(n.as_f64().unwrap_or_default())
+        }
+    }
+}
+
+fn cmp_int_float(a: i128, b: f64) -> Ordering {
+    // 2^127, the integers of a `Number` are far inside this range.
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0;
+
+    if b >= LIMIT {
+        return Ordering::Less;
+    }
+    if b < -LIMIT {
+        return Ordering::Greater;
+    }
+
+    // The integral part is exactly representable as an `i128`.
+    let trunc = b.trunc();
+    match a.cmp(&(trunc as i128)) {
+        Ordering::Equal => trunc.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        ordering => ordering,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float(n: f64) -> Number {
+        Number::from_f64(n).unwrap()
+    }
+
+    #[test]
+    fn test_eq_value() {
+        assert!(Number::from(1).eq_value(&float(1.0)));
+        assert!(float(1.0).eq_value(&Number::from(1)));
+        assert!(Number::from(-1).eq_value(&float(-1.0)));
+        assert!(Number::from(0).eq_value(&float(-0.0)));
+        assert!(!Number::from(1).eq_value(&float(1.5)));
+        assert!(Number::from(1u64).eq_value(&Number::from(1i64)));
+        assert!(float(0.0).eq_value(&float(-0.0)));
+    }
+
+    #[test]
+    fn test_cmp_value() {
+        assert_eq!(Number::from(1).cmp_value(&float(1.5)), Ordering::Less);
+        assert_eq!(float(1.5).cmp_value(&Number::from(1)), Ordering::Greater);
+        assert_eq!(Number::from(-2).cmp_value(&float(-1.5)), Ordering::Less);
+        assert_eq!(Number::from(-1).cmp_value(&float(-1.5)), Ordering::Greater);
+        assert_eq!(
+            Number::from(u64::MAX).cmp_value(&Number::from(i64::MIN)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            float(1e300).cmp_value(&Number::from(u64::MAX)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            float(-1e300).cmp_value(&Number::from(i64::MIN)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_large_integers() {
+        // 2^53 + 1 is not representable as a float, it's rounded to 2^53.
+        let n = (1u64 << 53) + 1;
+        assert_eq!(n as f64, (1u64 << 53) as f64);
+        assert!(!Number::from(n).eq_value(&float(n as f64)));
+        assert_eq!(
+            Number::from(n).cmp_value(&float(n as f64)),
+            Ordering::Greater
+        );
+        assert!(Number::from(1u64 << 53).eq_value(&float((1u64 << 53) as f64)));
+
+        // u64::MAX is rounded up to 2^64.
+        assert_eq!(
+            Number::from(u64::MAX).cmp_value(&float(u64::MAX as f64)),
+            Ordering::Less
+        );
+        assert!(Number::from(i64::MIN).eq_value(&float(i64::MIN as f64)));
+    }
+}
@@ -41,6 +41,48 @@ impl Serialize for ConstValue {
     }
 }
 
+/// Serializes a [`ConstValue`] with the keys of the objects sorted and the
+/// integral floats written as integers, see
+/// [`ConstValue::to_pretty_sorted_string`].
+pub(crate) struct SortedValue<'a>(pub(crate) &'a ConstValue);
+
+impl Serialize for SortedValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // 2^53, the integers in `-2^53..=2^53` are exactly represented as floats.
+        const MAX_EXACT_INT: f64 = 9007199254740992.0;
+
+        match self.0 {
+            ConstValue::Number(n) => match n.as_f64() {
+                Some(f) if n.is_f64() && f.fract() == 0.0 && f.abs() <= MAX_EXACT_INT => {
+                    serializer.serialize_i64(f as i64)
+                }
+                _ => n.serialize(serializer),
+            },
+            ConstValue::List(items) => serializer.collect_seq(items.iter().map(SortedValue)),
+            ConstValue::Object(map) if !is_raw_value(map) => {
+                let mut entries = map.iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(name, _)| *name);
+                serializer.collect_map(
+                    entries
+                        .into_iter()
+                        .map(|(name, value)| (name, SortedValue(value))),
+                )
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "raw_value")]
+fn is_raw_value(map: &IndexMap<Name, ConstValue>) -> bool {
+    map.len() == 1 && map.contains_key(RAW_VALUE_TOKEN)
+}
+
+#[cfg(not(feature = "raw_value"))]
+fn is_raw_value(_map: &IndexMap<Name, ConstValue>) -> bool {
+    false
+}
+
 impl<'de> Deserialize<'de> for ConstValue {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct ValueVisitor;
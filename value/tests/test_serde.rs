@@ -105,3 +105,38 @@ fn test_raw_value() {
     let value = serde_json::to_string(&value).unwrap();
     assert_eq!(value, r#"{"field":[0, 1, 2]}"#);
 }
+
+#[test]
+fn test_pretty_sorted_string() {
+    let a = value!({
+        "name": "Ferris",
+        "id": 1.0,
+        "balance": "123456789012345678901234567890.5",
+        "nested": { "z": [1, 2.5, 3.0], "a": null },
+        "max": u64::MAX,
+    });
+    let b = value!({
+        "max": u64::MAX,
+        "nested": { "a": null, "z": [1.0, 2.5, 3] },
+        "balance": "123456789012345678901234567890.5",
+        "id": 1,
+        "name": "Ferris",
+    });
+    let expected = r#"{
+  "balance": "123456789012345678901234567890.5",
+  "id": 1,
+  "max": 18446744073709551615,
+  "name": "Ferris",
+  "nested": {
+    "a": null,
+    "z": [
+      1,
+      2.5,
+      3
+    ]
+  }
+}"#;
+    assert_eq!(a.to_pretty_sorted_string(), expected);
+    assert_eq!(b.to_pretty_sorted_string(), expected);
+    assert_eq!(a.to_pretty_sorted_string(), a.to_pretty_sorted_string());
+}